@@ -1,4 +1,3 @@
-use std::cmp::Ordering;
 use std::cmp::{min, max};
 use std::iter::repeat;
 
@@ -122,32 +121,34 @@ pub fn jaro_winkler<T:ToString + ?Sized>(a: &T, b: &T) -> f64 {
 ///
 /// ```
 pub fn levenshtein(a: &str, b: &str) -> usize {
-    match a.cmp(&b) {
-        Ordering::Equal => 0,
-        _ => match a.len() {
-            0 => b.len(),
-            _ => match b.len() {
-                0 => a.len(),
-                _ => {
-                    let mut prev_distances = (0..(b.len() as u16 + 1)).collect::<Vec<u16>>();
-                    let mut curr_distances = repeat(0).take(b.len()+1).collect::<Vec<u16>>();
-
-                    for (i, a_char) in a.chars().enumerate() {
-                        curr_distances[0] = i as u16 + 1;
-
-                        for (j, b_char) in b.chars().enumerate() {
-                            curr_distances[j + 1]  = min(curr_distances[j] + 1,
-                                                         min(prev_distances[j + 1] + 1, prev_distances[j] + (a_char != b_char) as u16));
-                        }
-
-                        prev_distances.clone_from(&curr_distances);
-                    }
-
-                    curr_distances[b.len()] as usize
-                }
-            }
+    if a == b {
+        return 0;
+    }
+
+    let b_chars = b.chars().collect::<Vec<_>>();
+    let b_len = b_chars.len();
+    if a.is_empty() {
+        return b_len;
+    }
+    if b_len == 0 {
+        return a.chars().count();
+    }
+
+    let mut prev_distances = (0..b_len + 1).collect::<Vec<usize>>();
+    let mut curr_distances = vec![0; b_len + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        curr_distances[0] = i + 1;
+
+        for (j, &b_char) in b_chars.iter().enumerate() {
+            curr_distances[j + 1]  = min(curr_distances[j] + 1,
+                                         min(prev_distances[j + 1] + 1, prev_distances[j] + (a_char != b_char) as usize));
         }
+
+        prev_distances.clone_from(&curr_distances);
     }
+
+    curr_distances[b_len]
 }
 
 /// Calculates the levenshtein distance between a string and each string in a vector. Returns a
@@ -172,6 +173,69 @@ pub fn levenshtein_against_vec(a: &str, v: &[&str]) -> Vec<usize> {
     r
 }
 
+/// Levenshtein distance normalized by the length (in chars) of the longest string. The returned
+/// value is between 0.0 and 1.0 (higher value means more similar).
+///
+/// Examples:
+///
+/// ```
+/// use nlp::distance::levenshtein_similarity;
+///
+/// assert_eq!(0.5, levenshtein_similarity("puit", "pute"));
+/// assert_eq!(1.0, levenshtein_similarity("", ""));
+/// ```
+pub fn levenshtein_similarity(a: &str, b: &str) -> f64 {
+    let length = max(a.chars().count(), b.chars().count());
+    if length == 0 {
+        return 1.0;
+    }
+
+    let similarity = 1.0 - (levenshtein(a, b) as f64 / length as f64);
+    similarity.clamp(0.0, 1.0)
+}
+
+/// Similarity measure between two strings, between 0.0 and 1.0 (higher value means more similar)
+pub trait StringSimilarity {
+    /// Similarity between two strings
+    fn similarity(&self, a: &str, b: &str) -> f64;
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Jaro similarity
+pub struct Jaro;
+
+impl StringSimilarity for Jaro {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        jaro(a, b)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Jaro-Winkler similarity
+pub struct JaroWinkler;
+
+impl StringSimilarity for JaroWinkler {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        jaro_winkler(a, b)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Normalized Levenshtein similarity, case insensitive
+pub struct Levenshtein;
+
+impl StringSimilarity for Levenshtein {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        levenshtein_similarity(&a.to_lowercase(), &b.to_lowercase())
+    }
+}
+
+impl<S: StringSimilarity + ?Sized> StringSimilarity for Box<S> {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        (**self).similarity(a, b)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,6 +279,13 @@ mod tests {
         assert_eq!(6, levenshtein("kitten", ""))
     }
 
+    #[test]
+    fn levenshtein_counts_chars() {
+        assert_eq!(1, levenshtein("a", "é"));
+        assert_eq!(1, levenshtein("é", ""));
+        assert_eq!(2, levenshtein("resume", "résumé"));
+    }
+
     // Jaro
     #[test]
     fn jaro_empty_string() {
@@ -262,6 +333,22 @@ mod tests {
         assert!((0.411 - jaro(&"Friedrich Nietzsche".to_owned(), &"Jean-Paul Sartre".to_owned())).abs() < 0.001)
     }
 
+    #[test]
+    fn levenshtein_similarity_bounds() {
+        assert!((0.571 - levenshtein_similarity("kitten", "sitting")).abs() < 0.001);
+        assert_eq!(0.0, levenshtein_similarity("", "abc"));
+        assert_eq!(1.0, levenshtein_similarity("abc", "abc"));
+    }
+
+    #[test]
+    fn string_similarity_scorers() {
+        let scorers: Vec<Box<dyn StringSimilarity>> = vec![Box::new(Jaro), Box::new(JaroWinkler), Box::new(Levenshtein)];
+        for scorer in &scorers {
+            assert_eq!(1.0, scorer.similarity("Martha", "martha"));
+            assert!(scorer.similarity("martha", "marhta") > scorer.similarity("martha", "dixon"));
+        }
+    }
+
     #[test]
     fn levenshtein_only_strings() {
         let vec: Vec<String> = vec!["test".to_owned(), "bibi".to_owned()];
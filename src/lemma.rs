@@ -0,0 +1,341 @@
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufRead};
+
+// Detachment rules of WordNet's morphy, tried against the known lemmas
+static NOUN_RULES: &[(&str, &str)] = &[
+    ("s", ""), ("ses", "s"), ("xes", "x"), ("zes", "z"), ("ches", "ch"), ("shes", "sh"), ("men", "man"),
+    ("ies", "y"),
+];
+static VERB_RULES: &[(&str, &str)] = &[
+    ("s", ""), ("ies", "y"), ("es", "e"), ("es", ""), ("ed", "e"), ("ed", ""), ("ing", "e"), ("ing", ""),
+];
+static ADJECTIVE_RULES: &[(&str, &str)] = &[("er", ""), ("est", ""), ("er", "e"), ("est", "e")];
+
+static DOUBLES: &[&str] = &["bb", "dd", "gg", "mm", "nn", "pp", "rr", "tt"];
+// Endings of a stem which lost its final e ("located", "caused", "larger")
+static VERB_E_ENDINGS: &[&str] = &["at", "bl", "as", "is", "os", "us", "ur", "c", "v", "z", "rg", "dg"];
+static ADJECTIVE_E_ENDINGS: &[&str] = &["bl", "pl", "tl", "gl", "kl", "rg", "ns", "rs", "ur", "c", "v"];
+// Stems keeping these endings without an e ("heated", "poured")
+static E_EXCEPTIONS: &[&str] = &["eat", "oat", "our"];
+static SIBILANTS: &[&str] = &["sses", "xes", "zes", "ches", "shes"];
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+/// Part of speech of a word, as far as lemmatization is concerned
+pub enum Pos {
+    /// Noun, plural in -s, -es or -ies
+    Noun,
+    /// Verb, in -s, -ed or -ing
+    Verb,
+    /// Adjective, comparative in -er and superlative in -est
+    Adjective,
+    /// Adverb, only found in the dictionary
+    Adverb,
+}
+
+impl Pos {
+    /// Part of speech of a tag: WordNet (`n`, `v`, `a`, `s`, `r`), Penn Treebank (`NNS`, `VBD`,
+    /// `JJR`, `RB`...), Universal Dependencies (`NOUN`, `VERB`, `ADJ`, `ADV`) or the name itself
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use nlp::lemma::Pos;
+    ///
+    /// assert_eq!(Some(Pos::Verb), Pos::from_tag("VBD"));
+    /// assert_eq!(Some(Pos::Adjective), Pos::from_tag("s"));
+    /// assert_eq!(None, Pos::from_tag("DT"));
+    /// ```
+    pub fn from_tag(tag: &str) -> Option<Pos> {
+        let tag = tag.to_lowercase();
+        match &tag[..] {
+            "n" | "noun" | "propn"                 => Some(Pos::Noun),
+            "v" | "verb" | "aux"                   => Some(Pos::Verb),
+            "a" | "s" | "adj" | "adjective"        => Some(Pos::Adjective),
+            "r" | "adv" | "adverb"                 => Some(Pos::Adverb),
+            tag if tag.starts_with("nn")           => Some(Pos::Noun),
+            tag if tag.starts_with("vb")           => Some(Pos::Verb),
+            tag if tag.starts_with("jj")           => Some(Pos::Adjective),
+            tag if tag.starts_with("rb")           => Some(Pos::Adverb),
+            _                                      => None,
+        }
+    }
+
+    fn rules(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Pos::Noun      => NOUN_RULES,
+            Pos::Verb      => VERB_RULES,
+            Pos::Adjective => ADJECTIVE_RULES,
+            Pos::Adverb    => &[],
+        }
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    "aeiouy".contains(c)
+}
+
+fn has_vowel(word: &str) -> bool {
+    word.chars().any(is_vowel)
+}
+
+/// Whether the stem is a single short syllable ("hop", "us"), which lost a final e
+fn is_short(stem: &str) -> bool {
+    let chars = stem.chars().collect::<Vec<_>>();
+    let vowel = |index: usize| is_vowel(chars[index]);
+    let len = chars.len();
+
+    let short_syllable = match len {
+        0 | 1 => false,
+        2     => vowel(0) && !vowel(1),
+        _     => !vowel(len - 1) && !"wxy".contains(chars[len - 1]) && vowel(len - 2) && !vowel(len - 3),
+    };
+    // Nothing but the last syllable: no vowel followed by a consonant before it
+    short_syllable && !(1..len - 1).any(|index| vowel(index - 1) && !vowel(index))
+}
+
+/// Stem of an -ed, -ing, -er or -est form with its final e or without its doubled consonant
+fn restore(stem: &str, e_endings: &[&str]) -> String {
+    let e_ending = e_endings.iter().any(|ending| stem.ends_with(ending)) &&
+        !E_EXCEPTIONS.iter().any(|exception| stem.ends_with(exception));
+
+    if DOUBLES.iter().any(|double| stem.ends_with(double)) {
+        stem[..stem.len() - 1].to_owned()
+    } else if e_ending || is_short(stem) {
+        format!("{}e", stem)
+    } else {
+        stem.to_owned()
+    }
+}
+
+/// -ies and -ied are -y after two letters or more, "ties" and "tied" are "tie"
+fn ies(word: &str, suffix: &str) -> Option<String> {
+    let stem = word.strip_suffix(suffix)?;
+    Some(if stem.chars().count() > 1 { format!("{}y", stem) } else { word[..word.len() - 1].to_owned() })
+}
+
+/// Lemma of a word unknown to the dictionary, guessed from its suffix
+fn guess(word: &str, pos: Pos) -> Option<String> {
+    let without = |suffix: &str| word.strip_suffix(suffix).filter(|stem| has_vowel(stem));
+    let sibilant = || {
+        SIBILANTS.iter().find(|suffix| without(suffix).is_some()).map(|_| word[..word.len() - 2].to_owned())
+    };
+    let plural = || {
+        if ["ss", "us", "is"].iter().any(|suffix| word.ends_with(suffix)) { None } else { without("s").map(str::to_owned) }
+    };
+
+    match pos {
+        Pos::Noun      => ies(word, "ies").or_else(sibilant).or_else(plural),
+        Pos::Verb      => {
+            ies(word, "ies").or_else(|| ies(word, "ied")).or_else(sibilant).or_else(|| {
+                if let Some(stem) = without("eed") {
+                    Some(format!("{}ee", stem))
+                } else if let Some(stem) = word.strip_suffix("oes").filter(|stem| !stem.is_empty()) {
+                    Some(format!("{}o", stem))
+                } else if let Some(stem) = without("ed").or_else(|| without("ing")) {
+                    Some(if stem.ends_with('e') { stem.to_owned() } else { restore(stem, VERB_E_ENDINGS) })
+                } else {
+                    plural()
+                }
+            })
+        },
+        Pos::Adjective => {
+            if let Some(stem) = without("ier").or_else(|| without("iest")) {
+                Some(format!("{}y", stem))
+            } else {
+                without("est").or_else(|| without("er")).map(|stem| restore(stem, ADJECTIVE_E_ENDINGS))
+            }
+        },
+        Pos::Adverb    => None,
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+/// Dictionary lemmatizer, with suffix rules for the words out of the dictionary
+///
+/// A word is looked up in the dictionary of inflected forms first ("mice" is "mouse", "better" is
+/// "good"). Otherwise the rules of WordNet's morphy remove its suffix, and the first result that
+/// is a known lemma wins. Otherwise the lemma is guessed from the suffix, with the final e and the
+/// doubled consonants of English ("hoping" is "hope", "hopping" is "hop").
+///
+/// Without a part of speech, the entries of any part of speech apply, and the guesses of the nouns
+/// then the verbs: comparatives are only guessed for an adjective. The words are lowercased.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::lemma::{Lemmatizer, Pos};
+///
+/// let dictionary = "mice\tmouse\tnoun\nbetter\tgood\tadjective\nbetter\twell\tadverb\nbus\n";
+/// let lemmatizer = Lemmatizer::load(dictionary.as_bytes()).unwrap();
+/// assert_eq!("mouse", lemmatizer.lemmatize("Mice", None));
+/// assert_eq!("good", lemmatizer.lemmatize("better", Some(Pos::Adjective)));
+/// assert_eq!("well", lemmatizer.lemmatize("better", Some(Pos::Adverb)));
+/// assert_eq!("bus", lemmatizer.lemmatize("buses", None));
+/// assert_eq!("run", lemmatizer.lemmatize("running", Some(Pos::Verb)));
+/// assert_eq!("big", lemmatizer.lemmatize("biggest", Some(Pos::Adjective)));
+/// ```
+pub struct Lemmatizer {
+    forms: HashMap<String, Vec<(Option<Pos>, String)>>,
+    lemmas: HashSet<String>,
+}
+
+impl Lemmatizer {
+    /// Lemmatizer without a dictionary, only guessing from the suffixes
+    pub fn new() -> Lemmatizer {
+        Lemmatizer::default()
+    }
+
+    /// Lemmatizer with the dictionary of a reader, see `read_dictionary`
+    pub fn load<R: BufRead>(reader: R) -> io::Result<Lemmatizer> {
+        let mut lemmatizer = Lemmatizer::new();
+        lemmatizer.read_dictionary(reader)?;
+        Ok(lemmatizer)
+    }
+
+    /// Add the tab separated entries of a reader: an inflected form, its lemma, and optionally the
+    /// part of speech they apply to. A line with a single word adds a known lemma. The empty lines
+    /// and the lines starting with `#` are skipped.
+    pub fn read_dictionary<R: BufRead>(&mut self, reader: R) -> io::Result<()> {
+        let invalid = |line: &str| io::Error::new(io::ErrorKind::InvalidData, format!("invalid lemma dictionary line: {}", line));
+
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields = line.split('\t').map(str::trim).collect::<Vec<_>>();
+            match &fields[..] {
+                [lemma]            => self.add_lemma(lemma),
+                [form, lemma]      => self.add(form, lemma, None),
+                [form, lemma, pos] => self.add(form, lemma, Some(Pos::from_tag(pos).ok_or_else(|| invalid(&line))?)),
+                _                  => return Err(invalid(&line)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Add the entries of a WordNet exception file (`noun.exc`, `verb.exc`...): an inflected form
+    /// followed by its lemmas, separated by spaces. The first lemma is kept.
+    pub fn read_exceptions<R: BufRead>(&mut self, reader: R, pos: Pos) -> io::Result<()> {
+        for line in reader.lines() {
+            let line = line?;
+            let mut words = line.split_whitespace();
+            if let (Some(form), Some(lemma)) = (words.next(), words.next()) {
+                // WordNet joins the words of the collocations with underscores
+                self.add(&form.replace('_', " "), &lemma.replace('_', " "), Some(pos));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Add an inflected form and its lemma, for a part of speech or for all of them
+    pub fn add(&mut self, form: &str, lemma: &str, pos: Option<Pos>) {
+        let lemma = lemma.to_lowercase();
+        self.lemmas.insert(lemma.clone());
+        self.forms.entry(form.to_lowercase()).or_default().push((pos, lemma));
+    }
+
+    /// Add a known lemma, kept as is and preferred by the suffix rules
+    pub fn add_lemma(&mut self, lemma: &str) {
+        self.lemmas.insert(lemma.to_lowercase());
+    }
+
+    /// Whether the word is a known lemma
+    pub fn is_lemma(&self, word: &str) -> bool {
+        self.lemmas.contains(&word.to_lowercase())
+    }
+
+    /// Lemma of a word, for a part of speech when given
+    pub fn lemmatize(&self, word: &str, pos: Option<Pos>) -> String {
+        let word = word.to_lowercase();
+
+        let entry = self.forms.get(&word).and_then(|entries| {
+            entries.iter().find(|&&(entry_pos, _)| pos.is_none() || entry_pos.is_none() || entry_pos == pos)
+        });
+        if let Some((_, lemma)) = entry {
+            return lemma.clone();
+        }
+        if self.lemmas.contains(&word) {
+            return word;
+        }
+
+        let all = [Pos::Noun, Pos::Verb, Pos::Adjective, Pos::Adverb];
+        let hint = pos.map(|pos| vec![pos]);
+        let candidates = hint.clone().unwrap_or_else(|| all.to_vec());
+        let known = candidates.iter().flat_map(|pos| pos.rules()).find_map(|&(suffix, replacement)| {
+            let lemma = format!("{}{}", word.strip_suffix(suffix)?, replacement);
+            if self.lemmas.contains(&lemma) { Some(lemma) } else { None }
+        });
+        if let Some(lemma) = known {
+            return lemma;
+        }
+
+        hint.unwrap_or_else(|| vec![Pos::Noun, Pos::Verb])
+            .into_iter()
+            .find_map(|pos| guess(&word, pos))
+            .unwrap_or(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pos_from_tag() {
+        assert_eq!(Some(Pos::Noun), Pos::from_tag("NNPS"));
+        assert_eq!(Some(Pos::Noun), Pos::from_tag("n"));
+        assert_eq!(Some(Pos::Adverb), Pos::from_tag("RBR"));
+        assert_eq!(Some(Pos::Adjective), Pos::from_tag("ADJ"));
+        assert_eq!(None, Pos::from_tag(""));
+    }
+
+    #[test]
+    fn lemma_guess() {
+        let lemmatizer = Lemmatizer::new();
+        let verbs = [("hoping", "hope"), ("hopping", "hop"), ("located", "locate"), ("visiting", "visit"),
+                     ("agreed", "agree"), ("tried", "try"), ("fixes", "fix"), ("using", "use"), ("eating", "eat"),
+                     ("falling", "fall"), ("bring", "bring"), ("walks", "walk"), ("missed", "miss"),
+                     ("tied", "tie"), ("goes", "go"), ("heated", "heat"), ("caused", "cause"), ("having", "have")];
+        for &(word, lemma) in &verbs {
+            assert_eq!(lemma, lemmatizer.lemmatize(word, Some(Pos::Verb)), "lemma of {:?}", word);
+        }
+        let nouns = [("boxes", "box"), ("cities", "city"), ("houses", "house"), ("glass", "glass"),
+                     ("pies", "pie"), ("virus", "virus"), ("churches", "church")];
+        for &(word, lemma) in &nouns {
+            assert_eq!(lemma, lemmatizer.lemmatize(word, Some(Pos::Noun)), "lemma of {:?}", word);
+        }
+        let adjectives = [("bigger", "big"), ("nicest", "nice"), ("happier", "happy"), ("larger", "large"),
+                          ("taller", "tall"), ("simplest", "simple")];
+        for &(word, lemma) in &adjectives {
+            assert_eq!(lemma, lemmatizer.lemmatize(word, Some(Pos::Adjective)), "lemma of {:?}", word);
+        }
+    }
+
+    #[test]
+    fn lemma_without_pos() {
+        let lemmatizer = Lemmatizer::new();
+        assert_eq!("dog", lemmatizer.lemmatize("Dogs", None));
+        assert_eq!("run", lemmatizer.lemmatize("running", None));
+        assert_eq!("teacher", lemmatizer.lemmatize("teacher", None));
+        assert_eq!("quickly", lemmatizer.lemmatize("quickly", Some(Pos::Adverb)));
+    }
+
+    #[test]
+    fn lemma_read_exceptions() {
+        let mut lemmatizer = Lemmatizer::new();
+        lemmatizer.read_exceptions(&b"axes ax axis\nmen_of_war man_of_war\n"[..], Pos::Noun).unwrap();
+        assert_eq!("ax", lemmatizer.lemmatize("axes", Some(Pos::Noun)));
+        assert_eq!("man of war", lemmatizer.lemmatize("men of war", None));
+        assert_eq!("ax", lemmatizer.lemmatize("axes", Some(Pos::Verb)));
+    }
+
+    #[test]
+    fn lemma_load_invalid() {
+        assert!(Lemmatizer::load(&b"mice\tmouse\tdeterminer\n"[..]).is_err());
+        assert!(Lemmatizer::load(&b"a\tb\tn\tx\n"[..]).is_err());
+        assert!(Lemmatizer::load(&b"# empty\n\n"[..]).unwrap().forms.is_empty());
+    }
+}
@@ -12,9 +12,35 @@
 
 
 #[macro_use] extern crate itertools;
+extern crate unicode_segmentation;
+extern crate unicode_normalization;
 
 /// Distance module (Levenshtein, Jaro, Jaro-winkler)
 pub mod distance;
 
 /// Phonetics module (Soundex)
 pub mod phonetics;
+
+/// Name matching module (phonetic key and string similarity)
+pub mod matcher;
+
+/// Text normalization module (Unicode forms, case folding, transliteration)
+pub mod normalize;
+
+/// Tokenization module (words, sentences, n-grams)
+pub mod tokenize;
+
+/// Stemming module (Porter, Snowball)
+pub mod stem;
+
+/// Lemmatization module (dictionary and suffix rules)
+pub mod lemma;
+
+/// Stopwords module (built-in lists and filtering)
+pub mod stopwords;
+
+/// Part of speech tagging module (averaged perceptron)
+pub mod pos;
+
+/// Vectorization module (TF-IDF, sparse vectors, cosine similarity)
+pub mod vectorize;
@@ -0,0 +1,170 @@
+use distance::{JaroWinkler, StringSimilarity};
+use phonetics::encoder::PhoneticEncoder;
+use phonetics::metaphone::double_metaphone::DoubleMetaphone;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Scored explanation of a name comparison
+pub struct NameMatch {
+    /// Whether the phonetic codes of the names matched
+    pub phonetic_match: bool,
+    /// Score of the string similarity, between 0.0 and 1.0
+    pub similarity: f64,
+    /// Weighted combination of the phonetic match and the string similarity, between 0.0 and 1.0
+    pub score: f64,
+    /// Whether the names are considered a match
+    pub is_match: bool,
+}
+
+#[derive(Debug)]
+/// Name matcher combining a phonetic key match with a string similarity
+///
+/// The score is the weighted mean of the phonetic match (1.0 or 0.0) and of the string
+/// similarity. Names match when the score reaches the threshold and the string similarity reaches
+/// the minimum similarity.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::matcher::NameMatcher;
+/// use nlp::distance::StringSimilarity;
+///
+/// let matcher = NameMatcher::default();
+/// let result = matcher.compare("Smith", "Smyth");
+/// assert!(result.phonetic_match);
+/// assert!(result.is_match);
+/// assert!(!matcher.is_match("Smith", "Jones"));
+/// assert!(matcher.similarity("Smith", "Smyth") > matcher.similarity("Smith", "Jones"));
+/// ```
+pub struct NameMatcher<E: PhoneticEncoder, S: StringSimilarity> {
+    encoder: E,
+    scorer: S,
+    phonetic_weight: f64,
+    similarity_weight: f64,
+    threshold: f64,
+    minimum_similarity: f64,
+}
+
+impl Default for NameMatcher<DoubleMetaphone, JaroWinkler> {
+    fn default() -> NameMatcher<DoubleMetaphone, JaroWinkler> {
+        NameMatcher::new(DoubleMetaphone::new(), JaroWinkler)
+    }
+}
+
+impl<E: PhoneticEncoder, S: StringSimilarity> NameMatcher<E, S> {
+    /// Name matcher with equal weights, a threshold of 0.8 and a minimum similarity of 0.5
+    pub fn new(encoder: E, scorer: S) -> NameMatcher<E, S> {
+        NameMatcher {
+            encoder,
+            scorer,
+            phonetic_weight: 1.0,
+            similarity_weight: 1.0,
+            threshold: 0.8,
+            minimum_similarity: 0.5,
+        }
+    }
+
+    /// Set the weights of the phonetic match and of the string similarity
+    pub fn weights(mut self, phonetic_weight: f64, similarity_weight: f64) -> NameMatcher<E, S> {
+        self.phonetic_weight = phonetic_weight.max(0.0);
+        self.similarity_weight = similarity_weight.max(0.0);
+        self
+    }
+
+    /// Set the minimum score for names to match
+    pub fn threshold(mut self, threshold: f64) -> NameMatcher<E, S> {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Set the minimum string similarity for names to match, whatever the score
+    pub fn minimum_similarity(mut self, minimum_similarity: f64) -> NameMatcher<E, S> {
+        self.minimum_similarity = minimum_similarity;
+        self
+    }
+
+    /// Compare two names
+    pub fn compare(&self, a: &str, b: &str) -> NameMatch {
+        let phonetic_match = self.encoder.is_match(a, b);
+        let similarity = self.scorer.similarity(a, b);
+        let total_weight = self.phonetic_weight + self.similarity_weight;
+
+        let score = if total_weight > 0.0 {
+            let phonetic_score = if phonetic_match { 1.0 } else { 0.0 };
+            (self.phonetic_weight * phonetic_score + self.similarity_weight * similarity) / total_weight
+        } else {
+            0.0
+        };
+
+        NameMatch {
+            phonetic_match,
+            similarity,
+            score,
+            is_match: score >= self.threshold && similarity >= self.minimum_similarity,
+        }
+    }
+
+    /// Whether two names match
+    pub fn is_match(&self, a: &str, b: &str) -> bool {
+        self.compare(a, b).is_match
+    }
+}
+
+impl<E: PhoneticEncoder, S: StringSimilarity> StringSimilarity for NameMatcher<E, S> {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        self.compare(a, b).score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use distance::{Jaro, Levenshtein};
+    use phonetics::soundex::Soundex;
+    use phonetics::encoder::encoder_by_name;
+
+    #[test]
+    fn matcher_phonetic_and_similarity() {
+        let matcher = NameMatcher::new(Soundex::new(), Jaro);
+        let result = matcher.compare("Robert", "Rupert");
+        assert!(result.phonetic_match);
+        assert!((result.score - (1.0 + result.similarity) / 2.0).abs() < 0.0001);
+        assert!(result.is_match);
+    }
+
+    #[test]
+    fn matcher_false_positive_filtered() {
+        // Same soundex, very different strings
+        let matcher = NameMatcher::new(Soundex::new(), Levenshtein).minimum_similarity(0.6);
+        let result = matcher.compare("Rubin", "Rowbham");
+        assert!(result.phonetic_match);
+        assert!(!result.is_match);
+    }
+
+    #[test]
+    fn matcher_weights() {
+        let phonetic_only = NameMatcher::new(Soundex::new(), Jaro).weights(1.0, 0.0).minimum_similarity(0.0);
+        assert_eq!(1.0, phonetic_only.compare("Rubin", "Rowbham").score);
+
+        let similarity_only = NameMatcher::new(Soundex::new(), Jaro).weights(0.0, 1.0);
+        let result = similarity_only.compare("Martha", "Marhta");
+        assert!((result.score - result.similarity).abs() < 0.0001);
+
+        let none = NameMatcher::new(Soundex::new(), Jaro).weights(0.0, 0.0);
+        assert_eq!(0.0, none.compare("Martha", "Martha").score);
+    }
+
+    #[test]
+    fn matcher_threshold() {
+        let matcher = NameMatcher::new(encoder_by_name("metaphone").unwrap(), Levenshtein).threshold(0.95);
+        assert!(!matcher.is_match("Catherine", "Kathryn"));
+        assert!(matcher.is_match("Catherine", "catherine"));
+    }
+
+    #[test]
+    fn matcher_as_string_similarity() {
+        let scorers: Vec<Box<dyn StringSimilarity>> = vec![Box::new(NameMatcher::default()), Box::new(Jaro)];
+        for scorer in &scorers {
+            assert!(scorer.similarity("Schmidt", "Smith") > scorer.similarity("Schmidt", "Jones"));
+        }
+    }
+}
@@ -0,0 +1,6 @@
+/// Module transliteration to ASCII
+pub mod transliterate;
+/// Module Unicode normalization forms, case folding and diacritics
+pub mod unicode;
+/// Module normalization pipeline
+pub mod normalizer;
@@ -0,0 +1,291 @@
+use distance::StringSimilarity;
+use normalize::transliterate::transliterate;
+use normalize::unicode::{fold_case, nfc, nfd, nfkc, nfkd, strip_diacritics};
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+use unicode_normalization::char::is_combining_mark;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+/// Step of a normalizer
+enum Step {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+    FoldCase,
+    StripDiacritics,
+    Transliterate,
+    StripPunctuation,
+    CollapseWhitespace,
+}
+
+/// Trim the text and replace each run of white spaces by a single space
+///
+/// Examples:
+///
+/// ```
+/// use nlp::normalize::normalizer::collapse_whitespace;
+///
+/// assert_eq!("The Lord of the Rings", collapse_whitespace("  The Lord\tof the\n\nRings "));
+/// ```
+pub fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Replace each run of punctuation and symbols by a single space
+///
+/// Everything but the letters, the digits, the combining marks and the white spaces is
+/// punctuation: "O'Brien" becomes "O Brien".
+///
+/// Examples:
+///
+/// ```
+/// use nlp::normalize::normalizer::strip_punctuation;
+///
+/// assert_eq!("Hello  world ", strip_punctuation("Hello, world!"));
+/// assert_eq!("Jean Luc", strip_punctuation("Jean--Luc"));
+/// ```
+pub fn strip_punctuation(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut in_punctuation = false;
+    for c in text.chars() {
+        if c.is_alphanumeric() || c.is_whitespace() || is_combining_mark(c) {
+            stripped.push(c);
+            in_punctuation = false;
+        } else if !in_punctuation {
+            stripped.push(' ');
+            in_punctuation = true;
+        }
+    }
+
+    stripped
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+/// Text normalization pipeline, its steps run in the order they were added
+///
+/// `Normalized` wraps a phonetic encoder or a string similarity to normalize the words before
+/// encoding or comparing them.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::normalize::normalizer::Normalizer;
+///
+/// let normalizer = Normalizer::new().nfkc().fold_case().strip_diacritics().collapse_whitespace();
+/// assert_eq!("creme brulee", normalizer.normalize(" Cre\u{300}me  BRÛLÉE "));
+/// assert_eq!(normalizer.normalize("Straße"), normalizer.normalize("STRASSE"));
+///
+/// let normalizer = Normalizer::for_matching();
+/// assert_eq!("the lord of the rings return of the king",
+///            normalizer.normalize("The Lord of the Rings: Return of the King"));
+/// ```
+pub struct Normalizer {
+    steps: Vec<Step>,
+}
+
+impl Normalizer {
+    /// Normalizer without any step, leaving the texts as they are
+    pub fn new() -> Normalizer {
+        Normalizer::default()
+    }
+
+    /// Normalizer for comparing texts: NFKC, case folding, diacritics, punctuation and white
+    /// spaces removed
+    pub fn for_matching() -> Normalizer {
+        Normalizer::new().nfkc().fold_case().strip_diacritics().strip_punctuation().collapse_whitespace()
+    }
+
+    fn step(mut self, step: Step) -> Normalizer {
+        self.steps.push(step);
+        self
+    }
+
+    /// Add the canonical composition, see `unicode::nfc`
+    pub fn nfc(self) -> Normalizer {
+        self.step(Step::Nfc)
+    }
+
+    /// Add the canonical decomposition, see `unicode::nfd`
+    pub fn nfd(self) -> Normalizer {
+        self.step(Step::Nfd)
+    }
+
+    /// Add the compatibility composition, see `unicode::nfkc`
+    pub fn nfkc(self) -> Normalizer {
+        self.step(Step::Nfkc)
+    }
+
+    /// Add the compatibility decomposition, see `unicode::nfkd`
+    pub fn nfkd(self) -> Normalizer {
+        self.step(Step::Nfkd)
+    }
+
+    /// Add the full case folding, see `unicode::fold_case`
+    pub fn fold_case(self) -> Normalizer {
+        self.step(Step::FoldCase)
+    }
+
+    /// Add the removal of the diacritics, see `unicode::strip_diacritics`
+    pub fn strip_diacritics(self) -> Normalizer {
+        self.step(Step::StripDiacritics)
+    }
+
+    /// Add the ASCII transliteration, see `transliterate::transliterate`
+    pub fn transliterate(self) -> Normalizer {
+        self.step(Step::Transliterate)
+    }
+
+    /// Add the replacement of the punctuation by spaces, see `strip_punctuation`
+    pub fn strip_punctuation(self) -> Normalizer {
+        self.step(Step::StripPunctuation)
+    }
+
+    /// Add the collapsing of the white spaces, see `collapse_whitespace`
+    pub fn collapse_whitespace(self) -> Normalizer {
+        self.step(Step::CollapseWhitespace)
+    }
+
+    /// Add the steps of another normalizer after these ones
+    pub fn then(mut self, other: &Normalizer) -> Normalizer {
+        self.steps.extend_from_slice(&other.steps);
+        self
+    }
+
+    /// Normalized text
+    pub fn normalize(&self, text: &str) -> String {
+        let mut text = text.to_owned();
+        for step in &self.steps {
+            text = match *step {
+                Step::Nfc                => nfc(&text),
+                Step::Nfd                => nfd(&text),
+                Step::Nfkc               => nfkc(&text),
+                Step::Nfkd               => nfkd(&text),
+                Step::FoldCase           => fold_case(&text),
+                Step::StripDiacritics    => strip_diacritics(&text),
+                Step::Transliterate      => transliterate(&text),
+                Step::StripPunctuation   => strip_punctuation(&text),
+                Step::CollapseWhitespace => collapse_whitespace(&text),
+            };
+        }
+
+        text
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// Phonetic encoder or string similarity normalizing the words first
+///
+/// Examples:
+///
+/// ```
+/// use nlp::normalize::normalizer::{Normalized, Normalizer};
+/// use nlp::distance::{Jaro, StringSimilarity};
+/// use nlp::phonetics::encoder::PhoneticEncoder;
+/// use nlp::phonetics::soundex::Soundex;
+///
+/// assert!(Jaro.similarity("Andre\u{301}", "Andr\u{e9}") < 1.0);
+/// let jaro = Normalized::new(Normalizer::new().nfc(), Jaro);
+/// assert_eq!(1.0, jaro.similarity("Andre\u{301}", "Andr\u{e9}"));
+///
+/// let soundex = Normalized::new(Normalizer::new().strip_diacritics(), Soundex::new());
+/// assert!(soundex.is_match("Émile", "Emile"));
+///
+/// let soundex = Normalized::new(Normalizer::new().transliterate(), Soundex::new());
+/// assert_eq!(Some("I151"), soundex.encode("Иванов").primary());
+/// assert!(soundex.is_match("Søren", "Soren"));
+/// ```
+pub struct Normalized<T> {
+    normalizer: Normalizer,
+    inner: T,
+}
+
+impl<T> Normalized<T> {
+    /// Wrap an encoder or a similarity
+    pub fn new(normalizer: Normalizer, inner: T) -> Normalized<T> {
+        Normalized { normalizer, inner }
+    }
+
+    /// Normalizer of the words
+    pub fn normalizer(&self) -> &Normalizer {
+        &self.normalizer
+    }
+}
+
+impl<E: PhoneticEncoder> PhoneticEncoder for Normalized<E> {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        self.inner.encode(&self.normalizer.normalize(word))
+    }
+
+    fn is_match(&self, a: &str, b: &str) -> bool {
+        self.inner.is_match(&self.normalizer.normalize(a), &self.normalizer.normalize(b))
+    }
+}
+
+impl<S: StringSimilarity> StringSimilarity for Normalized<S> {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        self.inner.similarity(&self.normalizer.normalize(a), &self.normalizer.normalize(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use distance::JaroWinkler;
+    use matcher::NameMatcher;
+    use phonetics::encoder::encoder_by_name;
+    use phonetics::eudex::Eudex;
+    use phonetics::metaphone::double_metaphone::DoubleMetaphone;
+    use phonetics::mra::Mra;
+    use phonetics::soundex::Soundex;
+
+    #[test]
+    fn normalizer_strip_punctuation() {
+        assert_eq!("O Brien", strip_punctuation("O'Brien"));
+        assert_eq!("a b c", strip_punctuation("a+b\u{2014}c"));
+        assert_eq!("cafe\u{301} 42", strip_punctuation("cafe\u{301} 42"));
+        assert_eq!("", collapse_whitespace(" \t\n"));
+    }
+
+    #[test]
+    fn normalizer_steps() {
+        assert_eq!("e\u{301}", Normalizer::new().nfkd().normalize("\u{e9}"));
+        assert_eq!("\u{e9}", Normalizer::new().nfkd().nfc().normalize("\u{e9}"));
+        assert_eq!("Zhukov", Normalizer::new().transliterate().normalize("Жуков"));
+        assert_eq!("text", Normalizer::new().normalize("text"));
+
+        let normalizer = Normalizer::new().fold_case().then(&Normalizer::new().strip_punctuation().collapse_whitespace());
+        assert_eq!("o brien", normalizer.normalize("O'BRIEN"));
+    }
+
+    #[test]
+    fn normalizer_name_matcher() {
+        let normalizer = Normalizer::for_matching();
+        let matcher = NameMatcher::new(Normalized::new(normalizer.clone(), DoubleMetaphone::new()),
+                                       Normalized::new(normalizer, JaroWinkler));
+        let result = matcher.compare("JOSÉ  GARCÍA", "jose\u{301} garci\u{301}a");
+        assert!(result.phonetic_match);
+        assert_eq!(1.0, result.similarity);
+    }
+
+    #[test]
+    fn normalizer_transliterate_encoders() {
+        let soundex = Normalized::new(Normalizer::new().transliterate(), Soundex::new());
+        assert!(soundex.is_match("Иванов", "Ivanov"));
+        assert!(soundex.is_match("Weiß", "Weiss"));
+        assert!(!Soundex::new().is_match("Иванов", "Ivanov"));
+
+        let metaphone = Normalized::new(Normalizer::new().transliterate(), encoder_by_name("double_metaphone").unwrap());
+        assert!(metaphone.is_match("Øster", "Oster"));
+        assert!(metaphone.is_match("Σωκράτης", "Sokratis"));
+    }
+
+    #[test]
+    fn normalizer_keeps_encoder_match() {
+        assert!(Mra.is_match("Byrne", "Boern"));
+        assert!(Normalized::new(Normalizer::for_matching(), Mra).is_match("BYRNE", "Boern"));
+
+        let eudex = Eudex::with_max_distance(1000);
+        assert!(eudex.is_match("Jessica", "Veronica"));
+        assert!(Normalized::new(Normalizer::for_matching(), eudex).is_match("JESSICA", "Verónica"));
+    }
+}
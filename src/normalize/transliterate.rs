@@ -0,0 +1,179 @@
+/// Lowercase ASCII transliteration of a lowercase letter, `None` when it's not known
+fn lowercase_table(c: char) -> Option<&'static str> {
+    let ascii = match c {
+        // Latin diacritics
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' | 'ǎ' | 'ǻ' |
+        'ạ' | 'ả' | 'ấ' | 'ầ' | 'ẩ' | 'ẫ' | 'ậ' | 'ắ' | 'ằ' | 'ẳ' | 'ẵ' | 'ặ' => "a",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č'                                         => "c",
+        'ď' | 'đ' | 'ð'                                                     => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' |
+        'ẹ' | 'ẻ' | 'ẽ' | 'ế' | 'ề' | 'ể' | 'ễ' | 'ệ'                         => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ'                                               => "g",
+        'ĥ' | 'ħ'                                                           => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' | 'ǐ' | 'ỉ' | 'ị' => "i",
+        'ĵ'                                                                 => "j",
+        'ķ' | 'ĸ'                                                           => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł'                                         => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' | 'ŉ' | 'ŋ'                                   => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' | 'ǒ' | 'ǿ' | 'ơ' |
+        'ọ' | 'ỏ' | 'ố' | 'ồ' | 'ổ' | 'ỗ' | 'ộ' | 'ớ' | 'ờ' | 'ở' | 'ỡ' | 'ợ' => "o",
+        'ŕ' | 'ŗ' | 'ř'                                                     => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ș' | 'ſ'                                   => "s",
+        'ţ' | 'ť' | 'ŧ' | 'ț'                                               => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' | 'ǔ' | 'ǖ' |
+        'ǘ' | 'ǚ' | 'ǜ' | 'ư' | 'ụ' | 'ủ' | 'ứ' | 'ừ' | 'ử' | 'ữ' | 'ự'       => "u",
+        'ŵ'                                                                 => "w",
+        'ý' | 'ÿ' | 'ŷ' | 'ỳ' | 'ỵ' | 'ỷ' | 'ỹ'                             => "y",
+        'ź' | 'ż' | 'ž'                                                     => "z",
+
+        // Ligatures and letters of the Nordic and Germanic languages
+        'æ' | 'ǽ' => "ae",
+        'ĳ'       => "ij",
+        'œ'       => "oe",
+        'ß'       => "ss",
+        'þ'       => "th",
+
+        // Cyrillic
+        'а' => "a", 'б' => "b", 'в' => "v", 'г' => "g", 'ґ' => "g", 'д' => "d", 'е' => "e",
+        'ё' => "e", 'є' => "ye", 'ж' => "zh", 'з' => "z", 'и' => "i", 'і' => "i", 'ї' => "yi",
+        'й' => "y", 'к' => "k", 'л' => "l", 'м' => "m", 'н' => "n", 'о' => "o", 'п' => "p",
+        'р' => "r", 'с' => "s", 'т' => "t", 'у' => "u", 'ў' => "u", 'ф' => "f", 'х' => "kh",
+        'ц' => "ts", 'ч' => "ch", 'ш' => "sh", 'щ' => "shch", 'ъ' => "", 'ы' => "y", 'ь' => "",
+        'э' => "e", 'ю' => "yu", 'я' => "ya",
+
+        // Greek
+        'α' | 'ά' => "a", 'β' => "v", 'γ' => "g", 'δ' => "d", 'ε' | 'έ' => "e", 'ζ' => "z",
+        'η' | 'ή' => "i", 'θ' => "th", 'ι' | 'ί' | 'ϊ' | 'ΐ' => "i", 'κ' => "k", 'λ' => "l",
+        'μ' => "m", 'ν' => "n", 'ξ' => "x", 'ο' | 'ό' => "o", 'π' => "p", 'ρ' => "r",
+        'σ' | 'ς' => "s", 'τ' => "t", 'υ' | 'ύ' | 'ϋ' | 'ΰ' => "y", 'φ' => "f", 'χ' => "ch",
+        'ψ' => "ps", 'ω' | 'ώ' => "o",
+
+        _ => return None,
+    };
+
+    Some(ascii)
+}
+
+/// Lowercase ASCII transliteration of a letter, `None` when it's ASCII or not known
+///
+/// Examples:
+///
+/// ```
+/// use nlp::normalize::transliterate::transliterate_char;
+///
+/// assert_eq!(Some("e"), transliterate_char('É'));
+/// assert_eq!(Some("ss"), transliterate_char('ß'));
+/// assert_eq!(Some("zh"), transliterate_char('Ж'));
+/// assert_eq!(None, transliterate_char('e'));
+/// ```
+pub fn transliterate_char(c: char) -> Option<&'static str> {
+    if c.is_ascii() {
+        return None;
+    }
+
+    match c {
+        'İ' => Some("i"),
+        'ẞ' => Some("ss"),
+        _ if c.is_uppercase() => c.to_lowercase().next().and_then(lowercase_table),
+        _ => lowercase_table(c),
+    }
+}
+
+/// Transliterate a text to ASCII
+///
+/// Latin letters lose their diacritics, ligatures are expanded (`æ`, `œ`, `ß` to `ss`), the Nordic
+/// and Icelandic letters are romanized (`ø`, `å`, `þ` to `th`, `ð` to `d`), and so are the Cyrillic
+/// and Greek alphabets. The case is kept, a multi-letter transliteration of an uppercase letter is
+/// only capitalized unless the letter next to it is uppercase too ("Жуков" to "Zhukov", "ЖУКОВ"
+/// to "ZHUKOV"). Unknown chars are kept as they are.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::normalize::transliterate::transliterate;
+///
+/// assert_eq!("Francois Lefevre", transliterate("François Lefèvre"));
+/// assert_eq!("Strasse", transliterate("Straße"));
+/// assert_eq!("Soren Kierkegaard", transliterate("Søren Kierkegaard"));
+/// assert_eq!("Shchukin", transliterate("Щукин"));
+/// assert_eq!("Sokratis", transliterate("Σωκράτης"));
+/// ```
+pub fn transliterate(text: &str) -> String {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut result = String::with_capacity(text.len());
+
+    for (i, &c) in chars.iter().enumerate() {
+        match transliterate_char(c) {
+            Some(ascii) if c.is_uppercase() => {
+                let next = chars.get(i + 1);
+                let previous_upper = i > 0 && chars[i - 1].is_uppercase();
+                if next.is_some_and(|c| c.is_uppercase()) || (previous_upper && !next.is_some_and(|c| c.is_lowercase())) {
+                    result.push_str(&ascii.to_uppercase());
+                } else {
+                    let mut letters = ascii.chars();
+                    if let Some(first) = letters.next() {
+                        result.extend(first.to_uppercase());
+                        result.push_str(letters.as_str());
+                    }
+                }
+            },
+            Some(ascii) => result.push_str(ascii),
+            None => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// Whether the text is made of ASCII chars once transliterated
+///
+/// Examples:
+///
+/// ```
+/// use nlp::normalize::transliterate::is_transliterable;
+///
+/// assert!(is_transliterable("Łódź"));
+/// assert!(!is_transliterable("東京"));
+/// ```
+pub fn is_transliterable(text: &str) -> bool {
+    text.chars().all(|c| c.is_ascii() || transliterate_char(c).is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transliterate_latin() {
+        assert_eq!("Lodz", transliterate("Łódź"));
+        assert_eq!("Dvorak", transliterate("Dvořák"));
+        assert_eq!("Nguyen Van Thieu", transliterate("Nguyễn Văn Thiệu"));
+        assert_eq!("Istanbul", transliterate("İstanbul"));
+        assert_eq!("Thorsdottir", transliterate("Þórsdóttir"));
+        assert_eq!("Gudmundur", transliterate("Guðmundur"));
+        assert_eq!("AESIR Oeuvre", transliterate("ÆSIR Œuvre"));
+        assert_eq!("Laetitia", transliterate("Lætitia"));
+    }
+
+    #[test]
+    fn transliterate_case() {
+        assert_eq!("Zhukov", transliterate("Жуков"));
+        assert_eq!("ZHUKOV", transliterate("ЖУКОВ"));
+        assert_eq!("GROSS", transliterate("GROẞ"));
+        assert_eq!("Tsar", transliterate("Царь"));
+    }
+
+    #[test]
+    fn transliterate_cyrillic_and_greek() {
+        assert_eq!("Yuliya Timoshenko", transliterate("Юлія Тимошенко"));
+        assert_eq!("Khrushchev", transliterate("Хрущёв"));
+        assert_eq!("Athina", transliterate("Αθήνα"));
+        assert_eq!("Psychi", transliterate("Ψυχή"));
+    }
+
+    #[test]
+    fn transliterate_unknown_kept() {
+        assert_eq!("東京 Tokyo", transliterate("東京 Tokyo"));
+        assert_eq!("", transliterate(""));
+    }
+}
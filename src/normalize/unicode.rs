@@ -0,0 +1,179 @@
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Full case folding of a char, when it differs from its lowercase
+fn fold_table(c: char) -> Option<&'static str> {
+    let folded = match c {
+        'µ' => "μ", 'ß' => "ss", 'ŉ' => "ʼn", 'ſ' => "s", 'ǰ' => "j\u{30c}", '\u{345}' => "ι",
+        'ΐ' => "ι\u{308}\u{301}", 'ΰ' => "υ\u{308}\u{301}", 'ς' => "σ", 'ϐ' => "β", 'ϑ' => "θ", 'ϕ' => "φ",
+        'ϖ' => "π", 'ϰ' => "κ", 'ϱ' => "ρ", 'ϵ' => "ε", 'և' => "եւ", 'ᲀ' => "в", 'ᲁ' => "д", 'ᲂ' => "о",
+        'ᲃ' => "с", 'ᲄ' => "т", 'ᲅ' => "т", 'ᲆ' => "ъ", 'ᲇ' => "ѣ", 'ᲈ' => "ꙋ", 'ẖ' => "h\u{331}",
+        'ẗ' => "t\u{308}", 'ẘ' => "w\u{30a}", 'ẙ' => "y\u{30a}", 'ẚ' => "aʾ", 'ẛ' => "ṡ", 'ẞ' => "ss",
+        'ὐ' => "υ\u{313}", 'ὒ' => "υ\u{313}\u{300}", 'ὔ' => "υ\u{313}\u{301}", 'ὖ' => "υ\u{313}\u{342}",
+        'ᾀ' => "ἀι", 'ᾁ' => "ἁι", 'ᾂ' => "ἂι", 'ᾃ' => "ἃι", 'ᾄ' => "ἄι", 'ᾅ' => "ἅι", 'ᾆ' => "ἆι",
+        'ᾇ' => "ἇι", 'ᾈ' => "ἀι", 'ᾉ' => "ἁι", 'ᾊ' => "ἂι", 'ᾋ' => "ἃι", 'ᾌ' => "ἄι", 'ᾍ' => "ἅι",
+        'ᾎ' => "ἆι", 'ᾏ' => "ἇι", 'ᾐ' => "ἠι", 'ᾑ' => "ἡι", 'ᾒ' => "ἢι", 'ᾓ' => "ἣι", 'ᾔ' => "ἤι",
+        'ᾕ' => "ἥι", 'ᾖ' => "ἦι", 'ᾗ' => "ἧι", 'ᾘ' => "ἠι", 'ᾙ' => "ἡι", 'ᾚ' => "ἢι", 'ᾛ' => "ἣι",
+        'ᾜ' => "ἤι", 'ᾝ' => "ἥι", 'ᾞ' => "ἦι", 'ᾟ' => "ἧι", 'ᾠ' => "ὠι", 'ᾡ' => "ὡι", 'ᾢ' => "ὢι",
+        'ᾣ' => "ὣι", 'ᾤ' => "ὤι", 'ᾥ' => "ὥι", 'ᾦ' => "ὦι", 'ᾧ' => "ὧι", 'ᾨ' => "ὠι", 'ᾩ' => "ὡι",
+        'ᾪ' => "ὢι", 'ᾫ' => "ὣι", 'ᾬ' => "ὤι", 'ᾭ' => "ὥι", 'ᾮ' => "ὦι", 'ᾯ' => "ὧι", 'ᾲ' => "ὰι",
+        'ᾳ' => "αι", 'ᾴ' => "άι", 'ᾶ' => "α\u{342}", 'ᾷ' => "α\u{342}ι", 'ᾼ' => "αι", '\u{1fbe}' => "ι",
+        'ῂ' => "ὴι", 'ῃ' => "ηι", 'ῄ' => "ήι", 'ῆ' => "η\u{342}", 'ῇ' => "η\u{342}ι", 'ῌ' => "ηι",
+        'ῒ' => "ι\u{308}\u{300}", '\u{1fd3}' => "ι\u{308}\u{301}", 'ῖ' => "ι\u{342}", 'ῗ' => "ι\u{308}\u{342}",
+        'ῢ' => "υ\u{308}\u{300}", '\u{1fe3}' => "υ\u{308}\u{301}", 'ῤ' => "ρ\u{313}", 'ῦ' => "υ\u{342}",
+        'ῧ' => "υ\u{308}\u{342}", 'ῲ' => "ὼι", 'ῳ' => "ωι", 'ῴ' => "ώι", 'ῶ' => "ω\u{342}",
+        'ῷ' => "ω\u{342}ι", 'ῼ' => "ωι", 'ﬀ' => "ff", 'ﬁ' => "fi", 'ﬂ' => "fl", 'ﬃ' => "ffi", 'ﬄ' => "ffl",
+        'ﬅ' => "st", 'ﬆ' => "st", 'ﬓ' => "մն", 'ﬔ' => "մե", 'ﬕ' => "մի", 'ﬖ' => "վն", 'ﬗ' => "մխ",
+        _ => return None,
+    };
+
+    Some(folded)
+}
+
+/// Whether the char is a Cherokee letter, which folds to uppercase
+fn is_cherokee(c: char) -> bool {
+    ('\u{13a0}'..='\u{13fd}').contains(&c) || ('\u{ab70}'..='\u{abbf}').contains(&c)
+}
+
+/// Canonical composition (NFC): "e" followed by a combining acute accent becomes "é"
+///
+/// Examples:
+///
+/// ```
+/// use nlp::normalize::unicode::nfc;
+///
+/// assert_eq!("\u{e9}", nfc("e\u{301}"));
+/// assert_eq!("\u{c5}", nfc("\u{212b}"));
+/// ```
+pub fn nfc(text: &str) -> String {
+    text.nfc().collect()
+}
+
+/// Canonical decomposition (NFD): "é" becomes "e" followed by a combining acute accent
+///
+/// Examples:
+///
+/// ```
+/// use nlp::normalize::unicode::nfd;
+///
+/// assert_eq!("e\u{301}", nfd("\u{e9}"));
+/// ```
+pub fn nfd(text: &str) -> String {
+    text.nfd().collect()
+}
+
+/// Compatibility composition (NFKC): NFC, with the ligatures, full width letters, superscripts and
+/// other compatibility chars replaced by their plain equivalents
+///
+/// Examples:
+///
+/// ```
+/// use nlp::normalize::unicode::nfkc;
+///
+/// assert_eq!("file No 2", nfkc("\u{fb01}le \u{2116} \u{ff12}"));
+/// assert_eq!("\u{e9}", nfkc("e\u{301}"));
+/// ```
+pub fn nfkc(text: &str) -> String {
+    text.nfkc().collect()
+}
+
+/// Compatibility decomposition (NFKD): NFD, with the compatibility chars replaced as in NFKC
+///
+/// Examples:
+///
+/// ```
+/// use nlp::normalize::unicode::nfkd;
+///
+/// assert_eq!("fi e\u{301}", nfkd("\u{fb01} \u{e9}"));
+/// ```
+pub fn nfkd(text: &str) -> String {
+    text.nfkd().collect()
+}
+
+/// Whether the text is in NFC, so that `nfc` would leave it as is
+pub fn is_nfc(text: &str) -> bool {
+    ::unicode_normalization::is_nfc(text)
+}
+
+/// Full case folding of Unicode, to compare texts without case
+///
+/// Unlike `to_lowercase`, "ß" and "ẞ" fold to "ss", the final sigma to "σ", and the ligatures and
+/// the Greek letters with an iota subscript are expanded. Cherokee folds to uppercase.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::normalize::unicode::fold_case;
+///
+/// assert_eq!("strasse", fold_case("STRAẞE"));
+/// assert_eq!(fold_case("Straße"), fold_case("STRASSE"));
+/// assert_eq!(fold_case("ΟΔΥΣΣΕΥΣ"), fold_case("οδυσσευς"));
+/// ```
+pub fn fold_case(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars() {
+        match fold_table(c) {
+            Some(s)                  => folded.push_str(s),
+            None if is_cherokee(c)   => folded.extend(c.to_uppercase()),
+            None                     => folded.extend(c.to_lowercase()),
+        }
+    }
+
+    folded
+}
+
+/// Remove the diacritics: the combining marks of the canonical decomposition
+///
+/// The text is returned in NFC. The letters which are not a base letter and a mark, such as "ø",
+/// "ł" or "đ", are kept: `transliterate` replaces them.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::normalize::unicode::strip_diacritics;
+///
+/// assert_eq!("Francois Lefevre", strip_diacritics("François Lefèvre"));
+/// assert_eq!("cafe", strip_diacritics("cafe\u{301}"));
+/// assert_eq!("Łodz", strip_diacritics("Łódź"));
+/// ```
+pub fn strip_diacritics(text: &str) -> String {
+    text.nfd().filter(|&c| !is_combining_mark(c)).nfc().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unicode_forms() {
+        let composed = "Cr\u{e8}me br\u{fb}l\u{e9}e";
+        let decomposed = "Cre\u{300}me bru\u{302}le\u{301}e";
+        assert_eq!(composed, nfc(decomposed));
+        assert_eq!(decomposed, nfd(composed));
+        assert_eq!(composed, nfkc(decomposed));
+        assert_eq!(decomposed, nfkd(composed));
+        assert!(is_nfc(composed));
+        assert!(!is_nfc(decomposed));
+        assert_eq!("\u{1e69}", nfc("s\u{323}\u{307}"));
+        assert_eq!("x2", nfkc("x\u{b2}"));
+    }
+
+    #[test]
+    fn unicode_fold_case() {
+        assert_eq!("fish", fold_case("\u{fb01}sh"));
+        assert_eq!("i\u{307}stanbul", fold_case("\u{130}stanbul"));
+        assert_eq!("\u{1f00}\u{3b9}", fold_case("\u{1f88}"));
+        assert_eq!("\u{13f0}", fold_case("\u{13f8}"));
+        assert_eq!("\u{13a0}", fold_case("\u{ab70}"));
+        assert_eq!("\u{3bc}", fold_case("\u{b5}"));
+        assert_eq!("", fold_case(""));
+    }
+
+    #[test]
+    fn unicode_strip_diacritics() {
+        assert_eq!("Nguyen Van Thieu", strip_diacritics("Nguyễn Văn Thiệu"));
+        assert_eq!("Dvorak", strip_diacritics("Dvor\u{30c}a\u{301}k"));
+        assert_eq!("Zoe", strip_diacritics("Zoë"));
+        assert_eq!("Иошкар-Ола", strip_diacritics("Йошкар-Ола"));
+    }
+}
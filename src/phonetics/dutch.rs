@@ -0,0 +1,107 @@
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+use phonetics::utils::rules::{fold, scan_sounds};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Dutch phonetic encoder, a key of the Dutch sounds of a word
+pub struct Dutch {
+    drop_final_n: bool,
+}
+
+impl Default for Dutch {
+    fn default() -> Dutch {
+        Dutch::new()
+    }
+}
+
+impl Dutch {
+    /// Dutch encoder, the final `n` of `-en` is dropped
+    pub fn new() -> Dutch {
+        Dutch { drop_final_n: true }
+    }
+
+    /// Whether the final `n` of `-en` is dropped, as it usually is in speech ("Janssen", "Jansse")
+    pub fn drop_final_n(mut self, drop_final_n: bool) -> Dutch {
+        self.drop_final_n = drop_final_n;
+        self
+    }
+
+    /// Dutch key of a word
+    ///
+    /// `ij`, `ei` and `y` are coded `Y`, `au` and `ou` are coded `OU`, `oe` is coded `U`, `g` and
+    /// `ch` are coded `G` (`sch` is coded `SG`, or `S` at the end of the word), `v` and `f` are
+    /// coded `F`, `z` and `s` are coded `S`, and the final `d` is coded `T`.
+    pub fn dutch(&self, word: &str) -> String {
+        let chars = fold(word, "").chars().collect::<Vec<_>>();
+        let at = |i: usize| chars.get(i).cloned();
+        let n = chars.len();
+
+        scan_sounds(&chars, |i| match (chars[i], at(i + 1)) {
+            ('S', Some('C')) if at(i + 2) == Some('H') => Some((if i + 3 == n { "S" } else { "SG" }, 3)),
+            ('I', Some('J')) | ('E', Some('I')) | ('E', Some('Y')) => Some(("Y", 2)),
+            ('A', Some('U')) | ('O', Some('U')) => Some(("OU", 2)),
+            ('O', Some('E'))                    => Some(("U", 2)),
+            ('I', Some('E'))                    => Some(("I", 2)),
+            ('C', Some('H'))                    => Some(("G", 2)),
+            ('C', Some('K'))                    => Some(("K", 2)),
+            ('C', Some('E')) | ('C', Some('I')) | ('C', Some('Y')) => Some(("S", 1)),
+            ('C', _) | ('Q', _)                 => Some(("K", 1)),
+            ('P', Some('H'))                    => Some(("F", 2)),
+            ('T', Some('H'))                    => Some(("T", 2)),
+            ('D', Some('T'))                    => Some(("T", 2)),
+            ('D', None)                         => Some(("T", 1)),
+            ('E', Some('N')) if self.drop_final_n && i > 0 && i + 2 == n => Some(("E", 2)),
+            ('V', _)                            => Some(("F", 1)),
+            ('X', _)                            => Some(("KS", 1)),
+            ('Z', _)                            => Some(("S", 1)),
+            _                                   => None,
+        })
+    }
+}
+
+/// Dutch key of a word
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::dutch::dutch;
+///
+/// assert_eq!("SGRYFER", dutch("Schrijver"));
+/// assert_eq!(dutch("Schrijver"), dutch("Schreiver"));
+/// assert_eq!(dutch("Bosch"), dutch("Bos"));
+/// assert_eq!(dutch("Janssen"), dutch("Jansen"));
+/// ```
+pub fn dutch(word: &str) -> String {
+    Dutch::new().dutch(word)
+}
+
+impl PhoneticEncoder for Dutch {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        PhoneticCode::new(self.dutch(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dutch_keys() {
+        assert_eq!("BRUYN", dutch("Bruijn"));
+        assert_eq!(dutch("Bruijn"), dutch("Bruyn"));
+        assert_eq!(dutch("IJzerman"), dutch("Eyserman"));
+        assert_eq!(dutch("Pauwels"), dutch("Pouwels"));
+        assert_eq!(dutch("Goossens"), dutch("Chossens"));
+        assert_eq!(dutch("Smidt"), dutch("Smit"));
+        assert_eq!(dutch("Willemsen"), dutch("Willemse"));
+        assert_eq!(dutch("Vermeulen"), dutch("Fermeulen"));
+        assert_eq!(dutch("Claes"), dutch("Klaes"));
+        assert_eq!("", dutch(""));
+    }
+
+    #[test]
+    fn dutch_final_n() {
+        assert_eq!("JANSEN", Dutch::new().drop_final_n(false).dutch("Janssen"));
+        assert_eq!("JANSE", Dutch::new().dutch("Janssen"));
+        assert_eq!("EN", Dutch::new().dutch("En"));
+    }
+}
@@ -0,0 +1,207 @@
+use phonetics::soundex::{Soundex, SoundexVariant};
+use phonetics::metaphone::metaphone::Metaphone;
+use phonetics::metaphone::double_metaphone::DoubleMetaphone;
+use phonetics::mra::Mra;
+use phonetics::eudex::Eudex;
+use phonetics::phonex::Phonex;
+use phonetics::phonix::Phonix;
+use phonetics::fuzzy_soundex::FuzzySoundex;
+use phonetics::french::soundex2::Soundex2;
+use phonetics::french::phonex::PhonexFr;
+use phonetics::french::fonem::Fonem;
+use phonetics::spanish::{Fonetico, SoundexEs};
+use phonetics::portuguese::BuscaBr;
+use phonetics::dutch::Dutch;
+use phonetics::polish::Polish;
+use phonetics::nordic::{Nordic, NordicLanguage};
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug, Default)]
+/// Phonetic code of a word, holding one or many alternative codes
+pub struct PhoneticCode {
+    codes: Vec<String>,
+}
+
+impl PhoneticCode {
+    /// Phonetic code made of a single code, empty codes are ignored
+    pub fn new(code: String) -> PhoneticCode {
+        PhoneticCode::with_alternates(vec![code])
+    }
+
+    /// Phonetic code made of alternative codes, in order of preference
+    ///
+    /// Empty and duplicated codes are ignored.
+    pub fn with_alternates(alternates: Vec<String>) -> PhoneticCode {
+        let mut codes: Vec<String> = Vec::with_capacity(alternates.len());
+        for code in alternates {
+            if !code.is_empty() && !codes.contains(&code) {
+                codes.push(code);
+            }
+        }
+
+        PhoneticCode { codes }
+    }
+
+    /// Preferred code
+    pub fn primary(&self) -> Option<&str> {
+        self.codes.first().map(|code| &code[..])
+    }
+
+    /// All the codes, in order of preference
+    pub fn codes(&self) -> &[String] {
+        &self.codes
+    }
+
+    /// Whether there's no code at all
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    /// Whether the two phonetic codes share at least one code
+    pub fn matches(&self, other: &PhoneticCode) -> bool {
+        self.codes.iter().any(|code| other.codes.contains(code))
+    }
+}
+
+/// Common interface of the phonetic algorithms
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::encoder::PhoneticEncoder;
+/// use nlp::phonetics::metaphone::double_metaphone::DoubleMetaphone;
+///
+/// let code = DoubleMetaphone::new().encode("Smith");
+/// assert_eq!(Some("SM0"), code.primary());
+/// assert_eq!(2, code.codes().len());
+/// assert!(DoubleMetaphone::new().is_match("Smith", "Schmidt"));
+/// ```
+pub trait PhoneticEncoder {
+    /// Encode a word
+    fn encode(&self, word: &str) -> PhoneticCode;
+
+    /// Whether two words sound alike, by default when their codes have one code in common
+    fn is_match(&self, a: &str, b: &str) -> bool {
+        self.encode(a).matches(&self.encode(b))
+    }
+}
+
+impl<E: PhoneticEncoder + ?Sized> PhoneticEncoder for &E {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        (**self).encode(word)
+    }
+
+    fn is_match(&self, a: &str, b: &str) -> bool {
+        (**self).is_match(a, b)
+    }
+}
+
+impl<E: PhoneticEncoder + ?Sized> PhoneticEncoder for Box<E> {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        (**self).encode(word)
+    }
+
+    fn is_match(&self, a: &str, b: &str) -> bool {
+        (**self).is_match(a, b)
+    }
+}
+
+/// Find a phonetic encoder by its name, ignoring case, `-` and `_`
+///
+/// Known names are `soundex`, `soundex_simplified`, `soundex_sql_server`, `soundex_special`,
+/// `refined_soundex`, `fuzzy_soundex`, `phonex`, `phonix`, `metaphone`, `double_metaphone`, `mra`,
+/// `eudex`, the French `soundex2`, `phonex_fr` and `fonem`, the Spanish `fonetico` and `soundex_es`
+/// the Portuguese `buscabr`, and the `dutch`, `polish`, `swedish` and `norwegian` keys.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::encoder::encoder_by_name;
+///
+/// let encoder = encoder_by_name("Double-Metaphone").unwrap();
+/// assert!(encoder.is_match("Smith", "Schmidt"));
+/// assert!(encoder_by_name("unknown").is_none());
+/// ```
+pub fn encoder_by_name(name: &str) -> Option<Box<dyn PhoneticEncoder>> {
+    let name = name
+        .chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .flat_map(|c| c.to_lowercase())
+        .collect::<String>();
+
+    let encoder: Box<dyn PhoneticEncoder> = match &name[..] {
+        "soundex"           => Box::new(Soundex::new()),
+        "soundexsimplified" => Box::new(Soundex::new().variant(SoundexVariant::Simplified)),
+        "soundexsqlserver"  => Box::new(Soundex::new().variant(SoundexVariant::SqlServer)),
+        "soundexspecial"    => Box::new(Soundex::new().variant(SoundexVariant::Special)),
+        "refinedsoundex"    => Box::new(Soundex::refined()),
+        "metaphone"         => Box::new(Metaphone::new()),
+        "doublemetaphone"   => Box::new(DoubleMetaphone::new()),
+        "mra"               => Box::new(Mra),
+        "eudex"             => Box::new(Eudex::new()),
+        "phonex"            => Box::new(Phonex::new()),
+        "phonix"            => Box::new(Phonix::new()),
+        "fuzzysoundex"      => Box::new(FuzzySoundex::new()),
+        "soundex2"          => Box::new(Soundex2::new()),
+        "phonexfr"          => Box::new(PhonexFr),
+        "fonem"             => Box::new(Fonem),
+        "fonetico"          => Box::new(Fonetico::default()),
+        "soundexes"         => Box::new(SoundexEs::default()),
+        "buscabr"           => Box::new(BuscaBr::default()),
+        "dutch"             => Box::new(Dutch::new()),
+        "polish"            => Box::new(Polish),
+        "swedish"           => Box::new(Nordic::new(NordicLanguage::Swedish)),
+        "norwegian"         => Box::new(Nordic::new(NordicLanguage::Norwegian)),
+        _                   => return None,
+    };
+
+    Some(encoder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phonetic_code_alternates() {
+        let code = PhoneticCode::with_alternates(vec!["SM0".to_owned(), "".to_owned(), "SM0".to_owned(), "XMT".to_owned()]);
+        assert_eq!(Some("SM0"), code.primary());
+        assert_eq!(&["SM0".to_owned(), "XMT".to_owned()], code.codes());
+        assert!(PhoneticCode::new("".to_owned()).is_empty());
+    }
+
+    #[test]
+    fn phonetic_code_matches() {
+        let a = PhoneticCode::with_alternates(vec!["SM0".to_owned(), "XMT".to_owned()]);
+        let b = PhoneticCode::with_alternates(vec!["XMT".to_owned(), "SMT".to_owned()]);
+        assert!(a.matches(&b));
+        assert!(!a.matches(&PhoneticCode::default()));
+    }
+
+    #[test]
+    fn encoders_by_name() {
+        let names = ["soundex", "soundex_simplified", "soundex-sql-server", "SOUNDEX_SPECIAL",
+                     "refined_soundex", "fuzzy_soundex", "phonex", "phonix", "metaphone", "double_metaphone",
+                     "mra", "eudex"];
+        for name in &names {
+            let encoder = encoder_by_name(name).unwrap();
+            assert!(encoder.is_match("Stephen", "Stephen"), "{}", name);
+            assert!(!encoder.encode("Stephen").is_empty(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn encoders_agree_with_functions() {
+        assert_eq!(Some("A261"), encoder_by_name("soundex").unwrap().encode("Ashcraft").primary());
+        assert_eq!(Some("SMN"), encoder_by_name("metaphone").unwrap().encode("simon").primary());
+        assert_eq!(Some("BYRN"), encoder_by_name("mra").unwrap().encode("Byrne").primary());
+        assert_eq!(2, encoder_by_name("soundex_special").unwrap().encode("Van Dyke").codes().len());
+    }
+
+    #[test]
+    fn encoders_empty_word() {
+        assert!(encoder_by_name("double_metaphone").unwrap().encode("").is_empty());
+        assert!(encoder_by_name("metaphone").unwrap().encode("").is_empty());
+        assert!(encoder_by_name("soundex").unwrap().encode(" ").is_empty());
+        assert!(!encoder_by_name("double_metaphone").unwrap().is_match("", ""));
+    }
+}
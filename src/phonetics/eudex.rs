@@ -0,0 +1,341 @@
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+
+/// Maximum eudex distance, when every bit of every byte differs
+const MAX_DISTANCE: u32 = 8 * (1 + 2 + 4 + 8 + 16 + 32 + 64 + 128);
+
+/// Default distance under which two hashes are similar
+const SIMILAR_DISTANCE: u32 = 10;
+
+// Each phone is described by a byte:
+//
+//   bit 7: confident (trilled, lateral...), bit 6: labial, bit 5: liquid, bit 4: dental,
+//   bit 3: plosive, bit 2: fricative, bit 1: nasal, bit 0: discriminant (vowel-ish)
+//
+// Vowels are 0 or 1 so that they are mostly ignored outside of the first letter.
+
+/// Phones of the a-z letters, used after the first letter
+static PHONES: [u64; 26] = [
+    0,          // a
+    0b01001000, // b
+    0b00001100, // c
+    0b00011000, // d
+    0,          // e
+    0b01000100, // f
+    0b00001000, // g
+    0b00000100, // h
+    1,          // i
+    0b00000101, // j
+    0b00001001, // k
+    0b10100000, // l
+    0b00000010, // m
+    0b00010010, // n
+    0,          // o
+    0b01001001, // p
+    0b10101000, // q
+    0b10100001, // r
+    0b00010100, // s
+    0b00011101, // t
+    1,          // u
+    0b01000101, // v
+    0b00000000, // w
+    0b10000100, // x
+    1,          // y
+    0b10010100, // z
+];
+
+/// Phones of the U+00DF-U+00FF Latin-1 letters, used after the first letter
+static PHONES_C1: [u64; 33] = [
+    0b00010101, // ß
+    0,          // à
+    0,          // á
+    0,          // â
+    0,          // ã
+    0,          // ä [æ]
+    1,          // å [oː]
+    0,          // æ [æ]
+    0b10010101, // ç [t͡ʃ]
+    1,          // è
+    1,          // é
+    1,          // ê
+    1,          // ë
+    1,          // ì
+    1,          // í
+    1,          // î
+    1,          // ï
+    0b00010101, // ð [ð̠], a non-plosive t
+    0b00010111, // ñ [nj], a combination of n and j
+    0,          // ò
+    0,          // ó
+    0,          // ô
+    0,          // õ
+    1,          // ö [ø]
+    !0,         // ÷
+    1,          // ø [ø]
+    1,          // ù
+    1,          // ú
+    1,          // û
+    1,          // ü
+    1,          // ý
+    0b00010101, // þ [ð̠], a non-plosive t
+    1,          // ÿ
+];
+
+/// Phones of the a-z letters, used for the first letter: vowels are kept distinct
+static INJECTIVE_PHONES: [u64; 26] = [
+    0b10000100, // a*
+    0b00100100, // b
+    0b00000110, // c
+    0b00001100, // d
+    0b11011000, // e*
+    0b00100010, // f
+    0b00000100, // g
+    0b00000010, // h
+    0b11111000, // i*
+    0b00000011, // j
+    0b00000101, // k
+    0b01010000, // l
+    0b00000001, // m
+    0b00001001, // n
+    0b10010100, // o*
+    0b00100101, // p
+    0b01010100, // q
+    0b01010001, // r
+    0b00001010, // s
+    0b00001110, // t
+    0b11100000, // u*
+    0b00100011, // v
+    0b00000000, // w
+    0b01000010, // x
+    0b11100100, // y*
+    0b01001010, // z
+];
+
+/// Phones of the U+00DF-U+00FF Latin-1 letters, used for the first letter
+static INJECTIVE_PHONES_C1: [u64; 33] = [
+    0b00001011, // ß
+    0b10000101, // à
+    0b10000101, // á
+    0b10000101, // â
+    0b10000101, // ã
+    0b10100110, // ä [æ]
+    0b11000010, // å [oː]
+    0b10100111, // æ [æ]
+    0b01001011, // ç [t͡ʃ]
+    0b11011001, // è
+    0b11011001, // é
+    0b11011001, // ê
+    0b11000110, // ë [ə] or [œ]
+    0b11111001, // ì
+    0b11111001, // í
+    0b11111001, // î
+    0b11111001, // ï
+    0b00010101, // ð [ð̠], a non-plosive t
+    0b00010111, // ñ [nj], a combination of n and j
+    0b10010101, // ò
+    0b10010101, // ó
+    0b10010101, // ô
+    0b10010101, // õ
+    0b11011100, // ö [ø]
+    !0,         // ÷
+    0b11011100, // ø [ø]
+    0b11100001, // ù
+    0b11100001, // ú
+    0b11100001, // û
+    0b11100101, // ü [y]
+    0b11100101, // ý
+    0b00010101, // þ [ð̠], a non-plosive t
+    0b11100101, // ÿ
+];
+
+/// Look a char up in a letter table and in the matching Latin-1 table
+fn phone(c: char, letters: &[u64; 26], c1: &[u64; 33]) -> Option<u64> {
+    let c = c.to_lowercase().next().unwrap_or(c);
+    match c {
+        'a'..='z'           => Some(letters[c as usize - 'a' as usize]),
+        '\u{df}'..='\u{ff}' => Some(c1[c as usize - 0xdf]),
+        _                   => None,
+    }
+}
+
+/// Eudex hash of a word
+///
+/// The first letter is kept in the most significant byte, then the following phones (collapsing
+/// phones which only differ by their discriminant bit) fill the next bytes, so early letters
+/// weigh more than late ones. Chars outside of the a-z and Latin-1 letters are ignored.
+///
+/// Unlike soundex, which only tells if two words sound alike, eudex hashes can be compared by
+/// distance: it should be preferred to rank candidates, or when soundex codes are too coarse
+/// (vowels, long words, accented names).
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::eudex::eudex;
+///
+/// assert_eq!(eudex("Jesper"), eudex("JESPER"));
+/// assert_eq!(eudex("Jesper"), eudex("Jasper"));
+/// assert!(eudex("Jesper") != eudex("Gregor"));
+/// ```
+pub fn eudex(word: &str) -> u64 {
+    let mut chars = word.chars().skip_while(|c| phone(*c, &INJECTIVE_PHONES, &INJECTIVE_PHONES_C1).is_none());
+
+    let first = match chars.next() {
+        Some(c) => phone(c, &INJECTIVE_PHONES, &INJECTIVE_PHONES_C1).unwrap_or(0),
+        None => return 0
+    };
+
+    let mut hash: u64 = 0;
+    let mut count: u8 = 1;
+
+    for c in chars {
+        if count == 0 {
+            break;
+        }
+
+        let phone = match phone(c, &PHONES, &PHONES_C1) {
+            Some(phone) => phone,
+            None => continue
+        };
+
+        if hash & 0xFE != phone & 0xFE {
+            hash = (hash << 8) | phone;
+            count <<= 1;
+        }
+    }
+
+    hash | (first << 56)
+}
+
+/// Distance between two eudex hashes: Hamming weight of each byte of the XOR, weighted by the
+/// byte position (the first letter byte weighs 128, the last one 1)
+fn hash_distance(a: u64, b: u64) -> u32 {
+    let xor = a ^ b;
+    (0..8).map(|i| ((xor >> (8 * i)) as u8).count_ones() << i).sum()
+}
+
+/// Eudex distance between two words, 0 means they sound the same
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::eudex::eudex_distance;
+///
+/// assert_eq!(0, eudex_distance("Jesper", "jesper"));
+/// assert!(eudex_distance("Rupert", "Robert") < eudex_distance("Rupert", "Wilson"));
+/// ```
+pub fn eudex_distance(a: &str, b: &str) -> u32 {
+    hash_distance(eudex(a), eudex(b))
+}
+
+/// Eudex similarity between two words, between 0.0 and 1.0 (higher value means more similar)
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::eudex::eudex_similarity;
+///
+/// assert_eq!(1.0, eudex_similarity("Jesper", "jesper"));
+/// assert!(eudex_similarity("Rupert", "Robert") > eudex_similarity("Rupert", "Wilson"));
+/// ```
+pub fn eudex_similarity(a: &str, b: &str) -> f64 {
+    1.0 - eudex_distance(a, b) as f64 / MAX_DISTANCE as f64
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Eudex phonetic encoder, words match when their distance is under the maximum distance
+pub struct Eudex {
+    max_distance: u32,
+}
+
+impl Default for Eudex {
+    fn default() -> Eudex {
+        Eudex::new()
+    }
+}
+
+impl Eudex {
+    /// Eudex encoder matching words at a distance under 10
+    pub fn new() -> Eudex {
+        Eudex::with_max_distance(SIMILAR_DISTANCE)
+    }
+
+    /// Eudex encoder matching words at a distance under `max_distance`
+    pub fn with_max_distance(max_distance: u32) -> Eudex {
+        Eudex { max_distance }
+    }
+}
+
+impl PhoneticEncoder for Eudex {
+    /// Hexadecimal representation of the eudex hash
+    fn encode(&self, word: &str) -> PhoneticCode {
+        if !word.chars().any(char::is_alphabetic) {
+            return PhoneticCode::default();
+        }
+        PhoneticCode::new(format!("{:016x}", eudex(word)))
+    }
+
+    fn is_match(&self, a: &str, b: &str) -> bool {
+        eudex_distance(a, b) < self.max_distance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eudex_case_insensitive() {
+        assert_eq!(eudex("JAva"), eudex("jAva"));
+        assert_eq!(eudex("Éléonore"), eudex("éléonore"));
+    }
+
+    #[test]
+    fn eudex_first_letter() {
+        assert_eq!(INJECTIVE_PHONES[0] << 56, eudex("a"));
+        assert_eq!(INJECTIVE_PHONES[1] << 56, eudex("b"));
+        assert_eq!(INJECTIVE_PHONES_C1[9] << 56, eudex("è"));
+        assert_eq!(eudex("smith"), eudex("  Smith"));
+        assert_eq!(0, eudex(""));
+        assert_eq!(0, eudex("--"));
+    }
+
+    #[test]
+    fn eudex_collapses_similar_phones() {
+        assert_eq!(eudex("Jessica"), eudex("Jesica"));
+        assert_eq!(eudex("Fillip"), eudex("Filip"));
+    }
+
+    #[test]
+    fn eudex_distance_ordering() {
+        assert_eq!(0, eudex_distance("Jesper", "Jesper"));
+        assert!(eudex_distance("Rupert", "Robert") < eudex_distance("Rupert", "Wilson"));
+        assert!(eudex_distance("Jensen", "Jenssen") < eudex_distance("Jensen", "Hansen"));
+        // differences on the first letter weigh more than at the end of the word
+        assert!(eudex_distance("Mark", "Park") > eudex_distance("Mark", "Marc"));
+    }
+
+    #[test]
+    fn eudex_distance_weights() {
+        assert_eq!(1, hash_distance(0, 1));
+        assert_eq!(128, hash_distance(0, 1 << 56));
+        assert_eq!(MAX_DISTANCE, hash_distance(0, !0));
+    }
+
+    #[test]
+    fn eudex_multilingual() {
+        assert_eq!(0, eudex_distance("Müller", "Muller"));
+        assert!(eudex_similarity("Straße", "Strasse") > eudex_similarity("Straße", "Strand"));
+        assert!(eudex_distance("Peña", "Pena") < eudex_distance("Peña", "Pera"));
+        assert!(eudex("Ørsted") != eudex("Orsted"));
+    }
+
+    #[test]
+    fn eudex_encoder() {
+        let encoder = Eudex::new();
+        assert_eq!(Some("2200000000000000"), encoder.encode("f").primary());
+        assert!(encoder.encode("").is_empty());
+        assert!(encoder.is_match("Jessica", "Jesica"));
+        assert!(!encoder.is_match("Jessica", "Veronica"));
+        assert!(Eudex::with_max_distance(1000).is_match("Jessica", "Veronica"));
+    }
+}
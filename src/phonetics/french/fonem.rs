@@ -0,0 +1,181 @@
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+use phonetics::french::normalize;
+use phonetics::utils::rules::{after, before, is_one_of, replace_end, replace_if, replace_start, squeeze};
+
+const VOWELS: &str = "AEIOUY";
+const CONSONANTS: &str = "BCDFGHJKLMNPQRSTVWXZ";
+/// Consonants which are not `N`, before which `M` is a nasal
+const NOT_N: &str = "BCDFGHJKLMPQRSTVWXZ";
+const SEPARATORS: &[char] = &[' ', '-', '.'];
+
+/// Spellings of "saint" and "sainte" as the first part of a name (rules C-30 to C-33)
+static SAINTS: &[(&str, &str)] = &[
+    ("SAINT", "ST-"), ("SAIN", "ST-"), ("SINT", "ST-"), ("SIN", "ST-"), ("SEIN", "ST-"),
+    ("SEIM", "ST-"), ("CINQ", "ST-"), ("CIN", "ST-"), ("ST", "ST-"),
+    ("SAINTE", "STE-"), ("STE", "STE-"),
+];
+
+/// Final consonant groups which are pronounced (rule C-29)
+static PRONOUNCED_ENDINGS: &[&str] = &[
+    "ILS", "CH", "SH", "MP", "NP", "RC", "RF", "RK", "RL", "RN", "RS", "RX",
+];
+
+fn is_vowel(c: Option<char>) -> bool {
+    is_one_of(c, VOWELS)
+}
+
+fn is_consonant(c: Option<char>) -> bool {
+    is_one_of(c, CONSONANTS)
+}
+
+/// Split the "saint" prefix of a name, which must be followed by a space, a hyphen or a dot
+fn split_saint(word: &str) -> (&'static str, &str) {
+    if let Some(i) = word.find(SEPARATORS) {
+        let first = normalize(&word[..i]);
+        for &(saint, prefix) in SAINTS {
+            if first == saint {
+                return (prefix, word[i..].trim_start_matches(SEPARATORS));
+            }
+        }
+    }
+
+    ("", word)
+}
+
+/// FONEM code of a French name
+///
+/// FONEM (Bouchard & Pouyez) rewrites a name with its French-Canadian pronunciation, applying
+/// ordered rules on vowels groups (`eau`, `au`, `ill`, `ou`), nasals, consonants (`ph`, `qu`,
+/// `gn`, soft `c` and `g`, doubled letters) and pronounced or silent final consonants. The
+/// "saint" prefix of a name is coded as `ST-` or `STE-`.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::french::fonem::fonem;
+///
+/// assert_eq!("BERJERON", fonem("Bergeron"));
+/// assert_eq!("BOLIEU", fonem("Beaulieu"));
+/// assert_eq!("ST-JERMIN", fonem("St-Germain"));
+/// assert_eq!(fonem("Arsenault"), fonem("Arseneau"));
+/// ```
+pub fn fonem(word: &str) -> String {
+    let (prefix, word) = split_saint(word);
+    let mut w = normalize(word);
+
+    // Doubled letters
+    w = squeeze(&w, VOWELS);
+    w = squeeze(&w, "BDFGHJKMNPQRTVWXZ");
+    w = replace_if(&w, "CC", "C", |w, _, e| e == w.len() || is_consonant(after(w, e)));
+    w = replace_if(&w, "SS", "S", |w, s, _| s == 0 || is_consonant(before(w, s)));
+    w = replace_if(&w, "SS", "S", |w, _, e| e == w.len() || is_consonant(after(w, e)));
+    w = replace_if(&w, "LL", "L", |w, s, _| before(w, s) != Some('I'));
+    w = replace_end(&w, "ILE", "ILLE");
+
+    // Soft and hard C and G, `#` protects groups from the later rules
+    w = w.replace("GEAU", "JO").replace("GEO", "JO");
+    w = replace_if(&w, "CC", "K", |w, _, e| is_one_of(after(w, e), "AOU"));
+    w = replace_if(&w, "CC", "X", |w, _, e| is_one_of(after(w, e), "EIY"));
+    w = replace_if(&w, "G", "J", |w, _, e| is_one_of(after(w, e), "EIY"));
+    w = replace_if(&w, "MAC", "MA#", |w, s, e| s == 0 && is_consonant(after(w, e)));
+    w = replace_start(&w, "MC", "MA#");
+    w = replace_if(&w, "C", "S", |w, s, e| {
+        is_one_of(before(w, s), "BDFGHJKLMNPQRSTVWZ") && is_one_of(after(w, e), "EIY")
+    });
+    w = replace_if(&w, "C", "S", |w, s, e| s == 0 && is_one_of(after(w, e), "EIY"));
+    w = replace_if(&w, "C", "K", |w, _, e| is_one_of(after(w, e), "BDFGJKLMNPQRSTVWXZ"));
+
+    // "o" sounds and silent endings after them
+    for ending in &["EAULT", "EAULX", "AULT", "AULX", "OLT", "OLX", "EAUT", "EAUX", "AUT", "AUX",
+                    "EAULD", "EAUD", "AULD", "AUD"] {
+        if w.ends_with(ending) {
+            w = replace_end(&w, ending, "O");
+            break;
+        }
+    }
+    w = w.replace("EAU", "O").replace("AU", "O");
+    w = replace_if(&w, "H", "", |w, s, _| !is_one_of(before(w, s), "PCS"));
+
+    // Nasals
+    w = replace_if(&w, "GA", "G#", |w, _, e| {
+        is_one_of(after(w, e), "MN") || (after(w, e) == Some('I') && is_one_of(after(w, e + 1), "MN"))
+    });
+    w = replace_if(&w, "AM", "EN", |w, _, e| is_one_of(after(w, e), NOT_N));
+    w = replace_if(&w, "EM", "EN", |w, _, e| is_one_of(after(w, e), NOT_N));
+    w = replace_if(&w, "AN", "EN", |w, _, e| is_consonant(after(w, e)));
+    for src in &["AIM", "AIN", "EIN"] {
+        w = replace_if(&w, src, "IN", |w, _, e| e == w.len() || is_consonant(after(w, e)));
+    }
+
+    // Y and the vowel groups
+    w = replace_if(&w, "AY", "E", |w, s, e| e == w.len() && before(w, s) != Some('G'));
+    w = replace_end(&w, "EUX", "EU");
+    w = replace_if(&w, "EY", "E", |w, _, e| e == w.len() || is_consonant(after(w, e)));
+    w = w.replace("Y", "I");
+    w = replace_if(&w, "I", "Y", |w, s, e| is_vowel(before(w, s)) && is_vowel(after(w, e)));
+    w = replace_if(&w, "ILL", "Y", |w, s, _| is_vowel(before(w, s)));
+    w = replace_if(&w, "OU", "W", |w, _, e| {
+        is_one_of(after(w, e), "AEOU") || (after(w, e) == Some('I') && after(w, e + 1) != Some('L'))
+    });
+    w = replace_if(&w, "OM", "ON", |w, _, e| is_one_of(after(w, e), NOT_N));
+    for ending in &["BOURNE", "BOURN", "BORNE", "BORN", "BURNE", "BURN"] {
+        if w.ends_with(ending) {
+            w = replace_end(&w, ending, "BURN");
+            break;
+        }
+    }
+    w = replace_if(&w, "IM", "IN", |w, s, e| {
+        s == 0 || (is_consonant(before(w, s)) && is_one_of(after(w, e), NOT_N))
+    });
+
+    // Consonants
+    w = w.replace("BV", "V");
+    w = replace_if(&w, "C", "SS", |w, s, e| is_vowel(before(w, s)) && is_one_of(after(w, e), "EIY"));
+    w = replace_if(&w, "C", "K", |w, s, e| s == 0 && is_one_of(after(w, e), "AOU"));
+    w = replace_if(&w, "C", "K", |w, s, e| e == w.len() && is_vowel(before(w, s)));
+    w = replace_if(&w, "GNI", "GN", |w, _, e| is_vowel(after(w, e)));
+    w = w.replace("JEA", "JA").replace("PH", "F").replace("QU", "K");
+    w = replace_if(&w, "SC", "S", |w, s, e| s == 0 && is_one_of(after(w, e), "EIY"));
+    w = replace_if(&w, "SC", "SS", |w, s, e| s > 0 && is_one_of(after(w, e), "EIY"));
+    w = replace_if(&w, "SC", "SK", |w, s, e| s > 0 && is_one_of(after(w, e), "AOU"));
+    w = w.replace("SH", "CH");
+    w = replace_end(&w, "TIA", "SSIA");
+    w = replace_if(&w, "W", "", |w, s, _| is_one_of(before(w, s), "AIOUY"));
+    w = w.replace("XC", "X").replace("XS", "X").replace("XZ", "X");
+    w = replace_if(&w, "Z", "S", |w, s, e| {
+        is_vowel(before(w, s)) || (is_consonant(before(w, s)) && is_consonant(after(w, e)))
+    });
+
+    // Silent final consonant
+    if !PRONOUNCED_ENDINGS.iter().any(|ending| w.ends_with(ending)) {
+        let chars = w.chars().collect::<Vec<_>>();
+        let n = chars.len();
+        if n >= 2 && is_consonant(Some(chars[n - 2])) && is_consonant(Some(chars[n - 1])) {
+            w.pop();
+        }
+    }
+
+    prefix.to_owned() + &w.replace("G#", "GA").replace("MA#", "MAC")
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// FONEM phonetic encoder
+pub struct Fonem;
+
+impl PhoneticEncoder for Fonem {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        PhoneticCode::new(fonem(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fonem_split_saint() {
+        assert_eq!(("ST-", "Laurent"), split_saint("Saint-Laurent"));
+        assert_eq!(("STE-", "Marie"), split_saint("Ste. Marie"));
+        assert_eq!(("", "Sinclair"), split_saint("Sinclair"));
+    }
+}
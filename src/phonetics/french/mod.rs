@@ -0,0 +1,41 @@
+/// Module soundex2
+pub mod soundex2;
+/// Module phonex (French)
+pub mod phonex;
+/// Module fonem
+pub mod fonem;
+
+use phonetics::utils::rules::fold;
+
+/// Normalize a French word for phonetic encoding
+///
+/// The word is uppercased, accents are removed, `æ` and `œ` are expanded and everything which is
+/// not a letter is dropped. As spaces, hyphens and apostrophes disappear, elisions and liaisons
+/// between the parts of a name don't change its code ("D'Artagnan" and "Dartagnan" are the same).
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::french::normalize;
+///
+/// assert_eq!("PUREE", normalize("purée"));
+/// assert_eq!("DARTAGNAN", normalize("D'Artagnan"));
+/// assert_eq!("OEUVRE", normalize("Œuvre"));
+/// ```
+pub fn normalize(word: &str) -> String {
+    fold(word, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn french_normalize() {
+        assert_eq!("FRANCOIS", normalize("François"));
+        assert_eq!("NOEL", normalize("Noël"));
+        assert_eq!("LAETITIA", normalize("Lætitia"));
+        assert_eq!("SAINTOUEN", normalize("Saint-Ouen"));
+        assert_eq!("", normalize(" - "));
+    }
+}
@@ -0,0 +1,129 @@
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+use phonetics::utils::rules::fold;
+use phonetics::utils::rules::{after, before, is_one_of, replace_if, squeeze};
+
+/// Sounds and letters of a key, in the order of their base 22 digit
+const DIGITS: &str = "12345efghiklnorstuwxyz";
+/// Vowels and nasal sounds, `1` to `4`
+const VOWEL_SOUNDS: &str = "aeiou1234";
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz12345";
+
+/// Replace each group by its sound
+fn replace_all(word: String, groups: &[(&str, &str)]) -> String {
+    groups.iter().fold(word, |word, &(src, tar)| word.replace(src, tar))
+}
+
+/// Replace each group by its sound, when followed by one of `next`
+fn replace_before(word: String, groups: &[&str], tar: &str, next: &str) -> String {
+    groups.iter().fold(word, |word, src| {
+        replace_if(&word, src, tar, |w, _, e| is_one_of(after(w, e), next))
+    })
+}
+
+/// Phonex-FR key of a French word
+///
+/// The key is made of the letters `e f g h i k l n o r s t u w x y z` and of the digits `1` (an),
+/// `2` (oi), `3` (ou), `4` (in) and `5` (ch), silent letters and endings removed.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::french::phonex::phonex_fr_key;
+///
+/// assert_eq!("nort4", phonex_fr_key("Martin"));
+/// assert_eq!(phonex_fr_key("Philippe"), phonex_fr_key("Filipe"));
+/// assert_eq!(phonex_fr_key("Rousseau"), phonex_fr_key("Rousso"));
+/// ```
+pub fn phonex_fr_key(word: &str) -> String {
+    let mut word = fold(word, "ÉÈÊ").to_lowercase().replace("y", "i");
+
+    // H is silent, unless in ch, sh or ph
+    word = replace_if(&word, "h", "", |w, s, _| !is_one_of(before(w, s), "csp"));
+    word = word.replace("ph", "f");
+    word = replace_all(word, &[("gan", "kan"), ("gam", "kam"), ("gain", "kain"), ("gaim", "kaim")]);
+
+    // ain, ein, aim and eim sound like "èn" before a vowel, like "in" otherwise
+    word = replace_before(word, &["ain", "ein", "aim", "eim"], "yn", "aeiou");
+    word = replace_all(word, &[
+        ("eau", "o"), ("oua", "2"), ("ein", "4"), ("ain", "4"), ("eim", "4"), ("aim", "4"),
+    ]);
+
+    // "é" sounds
+    word = replace_all(word, &[
+        ("é", "y"), ("è", "y"), ("ê", "y"), ("ai", "y"), ("ei", "y"),
+        ("er", "yr"), ("ess", "yss"), ("et", "yt"),
+    ]);
+
+    // Nasals, unless followed by a vowel
+    for &(src, tar) in &[("an", "1"), ("am", "1"), ("en", "1"), ("em", "1"), ("in", "4")] {
+        word = replace_if(&word, src, tar, |w, _, e| !is_one_of(after(w, e), VOWEL_SOUNDS));
+    }
+
+    // S between vowels sounds like Z
+    word = replace_if(&word, "s", "z", |w, s, e| {
+        is_one_of(before(w, s), VOWEL_SOUNDS) && is_one_of(after(w, e), VOWEL_SOUNDS)
+    });
+
+    word = replace_all(word, &[
+        ("oe", "e"), ("eu", "e"), ("au", "o"), ("oi", "2"), ("oy", "2"), ("ou", "3"),
+        ("sch", "5"), ("ch", "5"), ("sh", "5"), ("ss", "s"),
+    ]);
+    word = replace_before(word, &["sc", "c"], "s", "ei");
+    word = replace_all(word, &[
+        ("qu", "k"), ("c", "k"), ("q", "k"), ("gu", "k"), ("ga", "ka"), ("go", "ko"), ("gy", "ky"),
+        ("a", "o"), ("d", "t"), ("p", "t"), ("j", "g"), ("b", "f"), ("v", "f"), ("m", "n"),
+    ]);
+    word = squeeze(&word, LETTERS);
+
+    // Silent endings
+    if word.ends_with('t') || word.ends_with('x') {
+        word.pop();
+    }
+
+    word.chars().filter(|c| DIGITS.contains(*c)).collect()
+}
+
+/// Phonex-FR value of a French word, a number between 0 and 1
+///
+/// Phonex-FR, or "phonétique française", encodes a French word as a number written in base 22,
+/// each digit being a sound of its key (see [`phonex_fr_key`]). Words sounding alike have the
+/// same value, and close values are close sounds at the start of the words.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::french::phonex::phonex_fr;
+///
+/// assert_eq!(phonex_fr("Martin"), phonex_fr("Martain"));
+/// assert_eq!(0.0, phonex_fr(""));
+/// assert!(phonex_fr("Martin") < 1.0);
+/// ```
+pub fn phonex_fr(word: &str) -> f64 {
+    phonex_fr_key(word)
+        .chars()
+        .rev()
+        .filter_map(|c| DIGITS.find(c))
+        .fold(0.0, |value, digit| (value + digit as f64) / 22.0)
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Phonex-FR phonetic encoder, the codes are the keys of the words
+pub struct PhonexFr;
+
+impl PhoneticEncoder for PhonexFr {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        PhoneticCode::new(phonex_fr_key(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phonex_fr_value() {
+        assert_eq!(0.0, phonex_fr("t"));
+        assert_eq!((14.0 + 2.0 / 22.0) / 22.0, phonex_fr("Roux"));
+        assert!(phonex_fr("Martin") > phonex_fr("Bernard"));
+    }
+}
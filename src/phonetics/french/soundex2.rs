@@ -0,0 +1,129 @@
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+use phonetics::french::normalize;
+use phonetics::utils::rules::{before, is_one_of, replace_end, replace_if, replace_start, squeeze, LETTERS};
+
+const MAX_LEN: usize = 4;
+
+/// Letter groups replaced before the vowels are merged
+static GROUPS: &[(&str, &str)] = &[
+    ("GUI", "KI"), ("GUE", "KE"), ("GA", "KA"), ("GO", "KO"), ("GU", "K"),
+    ("CA", "KA"), ("CO", "KO"), ("CU", "KU"), ("Q", "K"), ("CC", "K"), ("CK", "K"),
+];
+
+/// Prefixes replaced once the vowels are merged
+static PREFIXES: &[(&str, &str)] = &[
+    ("MAC", "MCC"), ("ASA", "AZA"), ("KN", "NN"), ("PF", "FF"), ("SCH", "SSS"), ("PH", "FF"),
+];
+
+fn encode(word: &str, max_length: usize) -> String {
+    let mut code = normalize(word);
+    if code.is_empty() {
+        return code;
+    }
+
+    for &(src, tar) in GROUPS {
+        code = code.replace(src, tar);
+    }
+
+    // All vowels but Y become A, except the first letter
+    code = code
+        .chars()
+        .enumerate()
+        .map(|(i, c)| if i > 0 && "EIOU".contains(c) { 'A' } else { c })
+        .collect();
+
+    for &(src, tar) in PREFIXES {
+        code = replace_start(&code, src, tar);
+    }
+
+    // H is silent unless it follows C or S, Y unless it follows A
+    code = replace_if(&code, "H", "", |w, s, _| s > 0 && !is_one_of(before(w, s), "CS"));
+    code = replace_if(&code, "Y", "", |w, s, _| s > 0 && !is_one_of(before(w, s), "A"));
+
+    // Silent endings
+    for ending in &["A", "T", "D", "S"] {
+        if code.len() > 1 && code.ends_with(ending) {
+            code = replace_end(&code, ending, "");
+            break;
+        }
+    }
+
+    let first = code.remove(0);
+    code = first.to_string() + &code.replace("A", "");
+    code = squeeze(&code, LETTERS);
+
+    if max_length > 0 {
+        code.truncate(max_length);
+        while code.len() < max_length {
+            code.push(' ');
+        }
+    }
+
+    code
+}
+
+/// Soundex2 code of a French word, 4 chars long, padded with spaces
+///
+/// Soundex2 is the French adaptation of soundex: instead of digits it keeps the consonants of the
+/// word once `gu`, `qu`, `ph`, `sch` and the like are simplified, with silent H and Y and the
+/// silent endings (`-t`, `-d`, `-s`, `-e`) removed.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::french::soundex2::soundex2;
+///
+/// assert_eq!("FLP ", soundex2("Philippe"));
+/// assert_eq!(soundex2("Gauthier"), soundex2("Gautier"));
+/// assert_eq!(soundex2("purée"), soundex2("purées"));
+/// ```
+pub fn soundex2(word: &str) -> String {
+    encode(word, MAX_LEN)
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Soundex2 phonetic encoder
+pub struct Soundex2 {
+    max_length: usize,
+}
+
+impl Default for Soundex2 {
+    fn default() -> Soundex2 {
+        Soundex2::new()
+    }
+}
+
+impl Soundex2 {
+    /// Soundex2 with codes of 4 chars, padded with spaces
+    pub fn new() -> Soundex2 {
+        Soundex2::with_max_length(MAX_LEN)
+    }
+
+    /// Soundex2 with codes of `max_length` chars, padded with spaces, `0` means unlimited
+    pub fn with_max_length(max_length: usize) -> Soundex2 {
+        Soundex2 { max_length }
+    }
+
+    /// Soundex2 code of a French word
+    pub fn soundex2(&self, word: &str) -> String {
+        encode(word, self.max_length)
+    }
+}
+
+impl PhoneticEncoder for Soundex2 {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        PhoneticCode::new(self.soundex2(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn soundex2_unlimited() {
+        assert_eq!("MKNZ", Soundex2::with_max_length(0).soundex2("MacKenzie"));
+        assert_eq!("BRTLM", Soundex2::with_max_length(0).soundex2("Barthélémy"));
+        assert_eq!("", Soundex2::new().soundex2("--"));
+    }
+}
@@ -0,0 +1,136 @@
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+use phonetics::utils::code::{encode_letters, pad};
+
+const MAX_LEN: usize = 5;
+
+/// Substitutions of the first letters
+static PREFIXES: &[(&str, &str)] = &[
+    ("CS", "SS"), ("CZ", "SS"), ("TS", "SS"), ("TZ", "SS"),
+    ("GN", "NN"),
+    ("HR", "RR"), ("WR", "RR"),
+    ("HW", "WW"),
+    ("KN", "NN"), ("NG", "NN"),
+];
+
+/// Substitutions of the last letters
+static SUFFIXES: &[(&str, &str)] = &[
+    ("CH", "KK"),
+    ("NT", "TT"),
+    ("RT", "RR"),
+    ("RDT", "RR"),
+];
+
+/// Substitutions anywhere in the word, applied in order
+static SUBSTITUTIONS: &[(&str, &str)] = &[
+    ("CA", "KA"), ("CC", "KK"), ("CK", "KK"), ("CE", "SE"), ("CHL", "KL"), ("CL", "KL"),
+    ("CHR", "KR"), ("CR", "KR"), ("CI", "SI"), ("CO", "KO"), ("CU", "KU"), ("CY", "SY"),
+    ("DG", "GG"), ("GH", "HH"), ("MAC", "MK"), ("MC", "MK"), ("NST", "NSS"), ("PF", "FF"),
+    ("PH", "FF"), ("SCH", "SSS"), ("TIO", "SIO"), ("TIA", "SIO"), ("TCH", "CHH"),
+];
+
+/// Encode an uppercase letter into a fuzzy soundex digit, H, W and Y have no digit
+fn digit(c: char) -> Option<char> {
+    match c {
+        'A' | 'E' | 'I' | 'O' | 'U'       => Some('0'),
+        'B' | 'F' | 'P' | 'V'             => Some('1'),
+        'D' | 'T'                         => Some('3'),
+        'L'                               => Some('4'),
+        'M' | 'N'                         => Some('5'),
+        'R'                               => Some('6'),
+        'G' | 'J' | 'K' | 'Q' | 'X'       => Some('7'),
+        'C' | 'S' | 'Z'                   => Some('9'),
+        _                                 => None,
+    }
+}
+
+fn encode(word: &str, max_length: usize) -> String {
+    let mut word = word
+        .chars()
+        .flat_map(|c| c.to_uppercase())
+        .filter(|c| c.is_ascii_uppercase())
+        .collect::<String>();
+
+    let mut code = String::new();
+
+    if !word.is_empty() {
+        if let Some(&(prefix, replacement)) = PREFIXES.iter().find(|&&(p, _)| word.starts_with(p)) {
+            word = replacement.to_owned() + &word[prefix.len()..];
+        }
+
+        if let Some(&(suffix, replacement)) = SUFFIXES.iter().find(|&&(s, _)| word.ends_with(s)) {
+            let end = word.len() - suffix.len();
+            word = word[..end].to_owned() + replacement;
+        }
+
+        for &(src, tar) in SUBSTITUTIONS {
+            word = word.replace(src, tar);
+        }
+
+        let mut digits = word.chars().filter_map(digit).collect::<Vec<_>>();
+        digits.dedup();
+
+        let first = word.chars().next().unwrap_or('0');
+        code.push(first);
+        let skip = match first {
+            'H' | 'W' | 'Y' => 0,
+            _               => 1,
+        };
+        code.extend(digits.into_iter().skip(skip).filter(|c| *c != '0'));
+    }
+
+    pad(code, max_length)
+}
+
+/// Fuzzy soundex code of a name (Holmes & McCabe), 5 chars long, padded with zeros
+///
+/// Letter groups are substituted before coding (CS, CZ, TS, TZ, GN, KN... at the start; CH, NT,
+/// RT, RDT at the end; and about twenty groups anywhere), and letters are coded with fuzzy
+/// soundex classes.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::fuzzy_soundex::fuzzy_soundex;
+///
+/// assert_eq!("K6935", fuzzy_soundex("Kristen"));
+/// assert_eq!(fuzzy_soundex("Kristen"), fuzzy_soundex("Christen"));
+/// assert_eq!("N3000", fuzzy_soundex("Knight"));
+/// ```
+pub fn fuzzy_soundex(word: &str) -> String {
+    encode(word, MAX_LEN)
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Fuzzy soundex phonetic encoder
+pub struct FuzzySoundex {
+    max_length: usize,
+}
+
+impl Default for FuzzySoundex {
+    fn default() -> FuzzySoundex {
+        FuzzySoundex::new()
+    }
+}
+
+impl FuzzySoundex {
+    /// Fuzzy soundex with codes of 5 chars, padded with zeros
+    pub fn new() -> FuzzySoundex {
+        FuzzySoundex::with_max_length(MAX_LEN)
+    }
+
+    /// Fuzzy soundex with codes of `max_length` chars, padded with zeros, `0` means unlimited
+    pub fn with_max_length(max_length: usize) -> FuzzySoundex {
+        FuzzySoundex { max_length }
+    }
+
+    /// Fuzzy soundex code of a name
+    pub fn fuzzy_soundex(&self, word: &str) -> String {
+        encode(word, self.max_length)
+    }
+}
+
+impl PhoneticEncoder for FuzzySoundex {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        encode_letters(word, |word| self.fuzzy_soundex(word))
+    }
+}
@@ -0,0 +1,300 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use phonetics::encoder::PhoneticEncoder;
+
+/// Block key: index of the encoder and phonetic code
+type BlockKey = (usize, String);
+
+#[derive(PartialEq, Clone, Debug)]
+/// Record returned by a phonetic index lookup
+pub struct Candidate<I> {
+    /// Record id
+    pub id: I,
+    /// Number of blocks shared with the query, over all encoders and codes
+    pub hits: usize,
+    /// Indexes of the encoders that matched, in order
+    pub encoders: Vec<usize>,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// A block of the index, i.e. the records sharing a phonetic code
+pub struct BlockInfo {
+    /// Index of the encoder which produced the code
+    pub encoder: usize,
+    /// Phonetic code
+    pub code: String,
+    /// Number of records in the block
+    pub size: usize,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Block size statistics of a phonetic index
+pub struct BlockStats {
+    /// Number of records
+    pub records: usize,
+    /// Number of non-empty blocks
+    pub blocks: usize,
+    /// Size of the largest block
+    pub max_size: usize,
+    /// Mean block size
+    pub mean_size: f64,
+    /// Number of record pairs to compare when comparing every pair inside each block
+    pub pairs: usize,
+}
+
+#[derive(Debug)]
+/// Blocking index mapping each phonetic code (primary and alternates) to record ids
+///
+/// Several encoders can be used at once, their codes are kept in separate blocks and the
+/// candidates of a query are merged.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::index::PhoneticIndex;
+/// use nlp::phonetics::metaphone::double_metaphone::DoubleMetaphone;
+///
+/// let mut index = PhoneticIndex::new(DoubleMetaphone::new());
+/// index.insert(1, "Smith");
+/// index.insert(2, "Schmidt");
+/// index.insert(3, "Jones");
+///
+/// let ids = index.candidates("Smyth").into_iter().map(|c| c.id).collect::<Vec<_>>();
+/// assert_eq!(vec![1, 2], ids);
+/// ```
+pub struct PhoneticIndex<I: Ord + Clone, E: PhoneticEncoder> {
+    encoders: Vec<E>,
+    blocks: HashMap<BlockKey, BTreeSet<I>>,
+    records: BTreeMap<I, BTreeSet<BlockKey>>,
+}
+
+impl<I: Ord + Clone, E: PhoneticEncoder> PhoneticIndex<I, E> {
+    /// Empty index using a single encoder
+    pub fn new(encoder: E) -> PhoneticIndex<I, E> {
+        PhoneticIndex::with_encoders(vec![encoder])
+    }
+
+    /// Empty index using several encoders
+    ///
+    /// Use `Box<dyn PhoneticEncoder>` to mix different algorithms.
+    pub fn with_encoders(encoders: Vec<E>) -> PhoneticIndex<I, E> {
+        PhoneticIndex {
+            encoders,
+            blocks: HashMap::new(),
+            records: BTreeMap::new(),
+        }
+    }
+
+    /// Block keys of a value, for every encoder
+    fn keys(&self, value: &str) -> BTreeSet<BlockKey> {
+        let mut keys = BTreeSet::new();
+        for (i, encoder) in self.encoders.iter().enumerate() {
+            for code in encoder.encode(value).codes() {
+                keys.insert((i, code.clone()));
+            }
+        }
+
+        keys
+    }
+
+    /// Index a record under a value, a record can be indexed under several values
+    ///
+    /// Returns `false` when the value gives no phonetic code at all.
+    pub fn insert(&mut self, id: I, value: &str) -> bool {
+        let keys = self.keys(value);
+        if keys.is_empty() {
+            return false;
+        }
+
+        for key in &keys {
+            self.blocks.entry(key.clone()).or_default().insert(id.clone());
+        }
+        self.records.entry(id).or_default().extend(keys);
+
+        true
+    }
+
+    /// Remove a record from every block, returns `false` when it wasn't indexed
+    pub fn remove(&mut self, id: &I) -> bool {
+        let keys = match self.records.remove(id) {
+            Some(keys) => keys,
+            None => return false
+        };
+
+        for key in keys {
+            let is_empty = match self.blocks.get_mut(&key) {
+                Some(block) => {
+                    block.remove(id);
+                    block.is_empty()
+                },
+                None => false
+            };
+            if is_empty {
+                self.blocks.remove(&key);
+            }
+        }
+
+        true
+    }
+
+    /// Whether a record is indexed
+    pub fn contains(&self, id: &I) -> bool {
+        self.records.contains_key(id)
+    }
+
+    /// Number of indexed records
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Whether no record is indexed
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Records sharing at least one block with the query, most hits first
+    pub fn candidates(&self, query: &str) -> Vec<Candidate<I>> {
+        let mut found: BTreeMap<&I, Candidate<I>> = BTreeMap::new();
+
+        for key in self.keys(query) {
+            if let Some(block) = self.blocks.get(&key) {
+                for id in block {
+                    let candidate = found.entry(id).or_insert_with(|| Candidate { id: id.clone(), hits: 0, encoders: Vec::new() });
+                    candidate.hits += 1;
+                    if !candidate.encoders.contains(&key.0) {
+                        candidate.encoders.push(key.0);
+                    }
+                }
+            }
+        }
+
+        let mut candidates = found.into_values().collect::<Vec<_>>();
+        for candidate in &mut candidates {
+            candidate.encoders.sort();
+        }
+        candidates.sort_by_key(|c| Reverse(c.hits));
+
+        candidates
+    }
+
+    /// Size of every block, largest first
+    pub fn blocks(&self) -> Vec<BlockInfo> {
+        let mut blocks = self.blocks
+            .iter()
+            .map(|(key, ids)| BlockInfo { encoder: key.0, code: key.1.clone(), size: ids.len() })
+            .collect::<Vec<_>>();
+        blocks.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| (a.encoder, &a.code).cmp(&(b.encoder, &b.code))));
+
+        blocks
+    }
+
+    /// Blocks holding more than `size` records, largest first
+    pub fn oversized_blocks(&self, size: usize) -> Vec<BlockInfo> {
+        self.blocks().into_iter().filter(|block| block.size > size).collect()
+    }
+
+    /// Block size statistics
+    pub fn stats(&self) -> BlockStats {
+        let sizes = self.blocks.values().map(|ids| ids.len()).collect::<Vec<_>>();
+        let total: usize = sizes.iter().sum();
+
+        BlockStats {
+            records: self.records.len(),
+            blocks: sizes.len(),
+            max_size: sizes.iter().cloned().max().unwrap_or(0),
+            mean_size: if sizes.is_empty() { 0.0 } else { total as f64 / sizes.len() as f64 },
+            pairs: sizes.iter().map(|n| n * (n - 1) / 2).sum(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use phonetics::encoder::encoder_by_name;
+    use phonetics::soundex::Soundex;
+
+    fn ids<I: Ord + Clone>(candidates: Vec<Candidate<I>>) -> Vec<I> {
+        candidates.into_iter().map(|c| c.id).collect()
+    }
+
+    #[test]
+    fn index_insert_candidates() {
+        let mut index = PhoneticIndex::new(Soundex::new());
+        assert!(index.insert("a", "Robert"));
+        assert!(index.insert("b", "Rupert"));
+        assert!(index.insert("c", "Rubin"));
+        assert!(!index.insert("d", ""));
+
+        assert_eq!(vec!["a", "b"], ids(index.candidates("Roberts")));
+        assert!(index.candidates("Smith").is_empty());
+        assert_eq!(3, index.len());
+        assert!(!index.contains(&"d"));
+    }
+
+    #[test]
+    fn index_alternate_codes() {
+        let mut index = PhoneticIndex::new(encoder_by_name("double_metaphone").unwrap());
+        index.insert(1, "Schmidt");
+        // Smith is SM0 / XMT, Schmidt is XMT / SMT
+        assert_eq!(vec![1], ids(index.candidates("Smith")));
+    }
+
+    #[test]
+    fn index_remove() {
+        let mut index = PhoneticIndex::new(Soundex::new());
+        index.insert(1, "Robert");
+        index.insert(1, "Bob");
+        index.insert(2, "Rupert");
+
+        assert!(index.remove(&1));
+        assert!(!index.remove(&1));
+        assert_eq!(vec![2], ids(index.candidates("Robert")));
+        assert!(index.candidates("Bob").is_empty());
+        assert_eq!(1, index.stats().blocks);
+    }
+
+    #[test]
+    fn index_multiple_encoders() {
+        let encoders = vec![encoder_by_name("soundex").unwrap(), encoder_by_name("metaphone").unwrap()];
+        let mut index = PhoneticIndex::with_encoders(encoders);
+        index.insert(1, "Catherine");
+        index.insert(2, "Kathryn");
+        index.insert(3, "Cathy");
+
+        let candidates = index.candidates("Kathrine");
+        assert_eq!(vec![2, 1], ids(candidates.clone()));
+        assert_eq!(vec![0, 1], candidates[0].encoders);
+        assert_eq!(2, candidates[0].hits);
+        assert_eq!(vec![1], candidates[1].encoders);
+    }
+
+    #[test]
+    fn index_stats() {
+        let mut index = PhoneticIndex::new(Soundex::new());
+        for (i, name) in ["Robert", "Rupert", "Rubert", "Smith", "Smyth", "Jones"].iter().enumerate() {
+            index.insert(i, name);
+        }
+
+        let stats = index.stats();
+        assert_eq!(6, stats.records);
+        assert_eq!(3, stats.blocks);
+        assert_eq!(3, stats.max_size);
+        assert!((2.0 - stats.mean_size).abs() < 0.001);
+        assert_eq!(4, stats.pairs);
+
+        let oversized = index.oversized_blocks(2);
+        assert_eq!(1, oversized.len());
+        assert_eq!("R163", oversized[0].code);
+        assert_eq!(3, oversized[0].size);
+        assert_eq!("S530", index.blocks()[1].code);
+    }
+
+    #[test]
+    fn index_empty_stats() {
+        let index: PhoneticIndex<usize, Soundex> = PhoneticIndex::new(Soundex::new());
+        assert!(index.is_empty());
+        assert_eq!(0, index.stats().max_size);
+        assert_eq!(0.0, index.stats().mean_size);
+    }
+}
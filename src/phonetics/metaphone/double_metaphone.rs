@@ -1,19 +1,23 @@
 use phonetics::utils::metaphone_utils::char_at;
 use phonetics::metaphone::handler::*;
 use phonetics::metaphone::double_metaphone_result::DoubleMetaphoneResult;
+use phonetics::metaphone::trace::{substring, Trace};
+use phonetics::metaphone::metaphone::MetaphoneCompatibility;
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
 
 static SILENT_START: &'static [&'static str] = &["GN", "KN", "PN", "WR", "PS"];
 // static ES_EP_EB_EL_EY_IB_IL_IN_IE_EI_ER: &'static Vec<&str> = ["ES", "EP", "EB", "EL", "EY", "IB", "IL", "IN", "IE", "EI", "ER"];
 // static L_T_K_S_N_M_B_Z: &'static Vec<&str> = ["L", "T", "K", "S", "N", "M", "B", "Z"];
 
-const MAX_LEN: i32 = 4;
+const MAX_LEN: usize = 4;
 
 /// Double metaphone with alternate
 pub fn double_metaphone(value: &str) -> Option<DoubleMetaphoneResult> {
-    let value:String = match clean_input(&value) {
-        Some(v) => v,
-        None => return None
-    };
+    encode(value, MAX_LEN, MetaphoneCompatibility::CommonsCodec, None)
+}
+
+fn encode(value: &str, max_length: usize, compatibility: MetaphoneCompatibility, mut trace: Option<&mut Trace>) -> Option<DoubleMetaphoneResult> {
+    let value: String = clean_input(value, compatibility)?;
 
     let slavo_germanic: bool = is_slavo_germanic(&value);
     let mut index: usize = if is_silent_start(&value) {
@@ -22,7 +26,14 @@ pub fn double_metaphone(value: &str) -> Option<DoubleMetaphoneResult> {
         0
     };
 
-    let mut result = DoubleMetaphoneResult::new(MAX_LEN);
+    let mut result = DoubleMetaphoneResult::new(max_length);
+    let mut rules = RuleRecorder::default();
+
+    if index == 1 {
+        if let Some(ref mut trace) = trace {
+            trace.push(0, substring(&value, 0, 1), "silent start: GN, KN, PN, WR, PS", "", "");
+        }
+    }
 
     while !result.is_complete() && index <= value.len() - 1 {
 
@@ -31,43 +42,149 @@ pub fn double_metaphone(value: &str) -> Option<DoubleMetaphoneResult> {
             None => {break;}
         };
 
+        let (start, primary, alternate) = (index, result.primary.len(), result.alternate.len());
+
         index = match current_char {
-            'A'| 'E'| 'I'| 'O'| 'U'| 'Y' => handle_aeiouy(&mut result, index),
-            'B'                          => handle_b(&mut result, &value, index),
-            'Ç'                          => handle_c_cedilla(&mut result, index),
-            'C'                          => handle_c(&mut result, &value, index),
-            'D'                          => handle_d(&mut result, &value, index),
-            'F'                          => handle_f(&mut result, &value, index),
-            'G'                          => handle_g(&mut result, &value, index, slavo_germanic),
-            'H'                          => handle_h(&mut result, &value, index),
-            'J'                          => handle_j(&mut result, &value, index, slavo_germanic),
-            'K'                          => handle_k(&mut result, &value, index),
-            'L'                          => handle_l(&mut result, &value, index),
-            'M'                          => handle_m(&mut result, &value, index),
-            'N'                          => handle_n(&mut result, &value, index),
-            'P'                          => handle_p(&mut result, &value, index),
-            'Q'                          => handle_q(&mut result, &value, index),
-            'R'                          => handle_r(&mut result, &value, index, slavo_germanic),
-            'S'                          => handle_s(&mut result, &value, index, slavo_germanic),
-            'T'                          => handle_t(&mut result, &value, index),
-            'V'                          => handle_v(&mut result, &value, index),
-            'W'                          => handle_w(&mut result, &value, index),
-            'X'                          => handle_x(&mut result, &value, index),
-            'Z'                          => handle_z(&mut result, &value, index, slavo_germanic),
-            'Ñ'|_ => index + 1
+            'A'| 'E'| 'I'| 'O'| 'U'| 'Y' => handle_aeiouy(&mut result, &mut rules, index),
+            'B'                          => handle_b(&mut result, &mut rules, &value, index),
+            'Ç'                          => handle_c_cedilla(&mut result, &mut rules, index),
+            'C'                          => handle_c(&mut result, &mut rules, &value, index),
+            'D'                          => handle_d(&mut result, &mut rules, &value, index),
+            'F'                          => handle_f(&mut result, &mut rules, &value, index),
+            'G'                          => handle_g(&mut result, &mut rules, &value, index, slavo_germanic),
+            'H'                          => handle_h(&mut result, &mut rules, &value, index),
+            'J'                          => handle_j(&mut result, &mut rules, &value, index, slavo_germanic),
+            'K'                          => handle_k(&mut result, &mut rules, &value, index),
+            'L'                          => handle_l(&mut result, &mut rules, &value, index),
+            'M'                          => handle_m(&mut result, &mut rules, &value, index),
+            'N'                          => handle_n(&mut result, &mut rules, &value, index),
+            'P'                          => handle_p(&mut result, &mut rules, &value, index),
+            'Q'                          => handle_q(&mut result, &mut rules, &value, index),
+            'R'                          => handle_r(&mut result, &mut rules, &value, index, slavo_germanic),
+            'S'                          => handle_s(&mut result, &mut rules, &value, index, slavo_germanic),
+            'T'                          => handle_t(&mut result, &mut rules, &value, index),
+            'V'                          => handle_v(&mut result, &mut rules, &value, index),
+            'W'                          => handle_w(&mut result, &mut rules, &value, index),
+            'X'                          => handle_x(&mut result, &mut rules, &value, index),
+            'Z'                          => handle_z(&mut result, &mut rules, &value, index, slavo_germanic),
+            'Ñ'|_ => {
+                rules.rule("skipped");
+                index + 1
+            }
+        };
+
+        let rule = rules.take();
+        if let Some(ref mut trace) = trace {
+            trace.push(start, substring(&value, start, index), rule, &result.primary[primary..], &result.alternate[alternate..]);
         }
     }
 
     result.cleanup();
+    if compatibility == MetaphoneCompatibility::Postgres {
+        let length = result.alternate.trim_end().len();
+        result.alternate.truncate(length);
+    }
 
     Some(result)
 }
 
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Double metaphone phonetic encoder, the alternate code is kept as an alternative
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::metaphone::double_metaphone::DoubleMetaphone;
+///
+/// assert_eq!("ALKS", DoubleMetaphone::new().double_metaphone("Alexander").unwrap().primary);
+/// assert_eq!("ALKSNT", DoubleMetaphone::with_max_length(6).double_metaphone("Alexander").unwrap().primary);
+/// assert_eq!("ALKSNTR", DoubleMetaphone::with_max_length(0).double_metaphone("Alexander").unwrap().primary);
+/// ```
+///
+/// The codes are the ones of commons-codec by default. PostgreSQL fuzzystrmatch `dmetaphone()`
+/// only differs on the input it's given: it doesn't trim it, it leaves the non ASCII letters out
+/// (`Ç` is not `S`, `ß` is not `SS`), and it doesn't end the alternate code with a space after a
+/// final `J` ("Raj" is RJ and R, not RJ and "R ").
+///
+/// ```
+/// use nlp::phonetics::metaphone::double_metaphone::DoubleMetaphone;
+/// use nlp::phonetics::metaphone::metaphone::MetaphoneCompatibility;
+///
+/// let postgres = DoubleMetaphone::new().compatibility(MetaphoneCompatibility::Postgres);
+/// assert_eq!("R ", DoubleMetaphone::new().double_metaphone("Raj").unwrap().alternate);
+/// assert_eq!("R", postgres.double_metaphone("Raj").unwrap().alternate);
+/// ```
+pub struct DoubleMetaphone {
+    max_length: usize,
+    compatibility: MetaphoneCompatibility,
+}
+
+impl Default for DoubleMetaphone {
+    fn default() -> DoubleMetaphone {
+        DoubleMetaphone::new()
+    }
+}
+
+impl DoubleMetaphone {
+    /// Double metaphone with codes of 4 chars at most
+    pub fn new() -> DoubleMetaphone {
+        DoubleMetaphone::with_max_length(MAX_LEN)
+    }
+
+    /// Double metaphone with codes of `max_length` chars at most, `0` means unlimited
+    pub fn with_max_length(max_length: usize) -> DoubleMetaphone {
+        DoubleMetaphone { max_length, compatibility: MetaphoneCompatibility::CommonsCodec }
+    }
+
+    /// Select the implementation to be compatible with
+    pub fn compatibility(mut self, compatibility: MetaphoneCompatibility) -> DoubleMetaphone {
+        self.compatibility = compatibility;
+        self
+    }
+
+    /// Double metaphone with alternate
+    pub fn double_metaphone(&self, value: &str) -> Option<DoubleMetaphoneResult> {
+        encode(value, self.max_length, self.compatibility, None)
+    }
+
+    /// Double metaphone with alternate, and the rules applied at each position of the word
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use nlp::phonetics::metaphone::double_metaphone::DoubleMetaphone;
+    ///
+    /// let (result, trace) = DoubleMetaphone::new().encode_with_trace("Schmidt").unwrap();
+    /// assert_eq!("XMT", result.primary);
+    /// assert_eq!("SCH: initial", trace.steps[0].rule);
+    /// assert_eq!("SCH", trace.steps[0].consumed);
+    /// assert_eq!("X", trace.steps[0].primary);
+    /// assert_eq!("S", trace.steps[0].alternate);
+    /// assert_eq!(vec!["SCH: initial", "M", "vowel", "DT, DD"], trace.rules());
+    /// ```
+    pub fn encode_with_trace(&self, value: &str) -> Option<(DoubleMetaphoneResult, Trace)> {
+        let mut trace = Trace::new();
+        encode(value, self.max_length, self.compatibility, Some(&mut trace)).map(|result| (result, trace))
+    }
+}
+
+impl PhoneticEncoder for DoubleMetaphone {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        match self.double_metaphone(word) {
+            Some(result) => PhoneticCode::with_alternates(vec![result.primary, result.alternate]),
+            None => PhoneticCode::default()
+        }
+    }
+}
+
 // Handler
 // Private methods
-fn handle_aeiouy(result: &mut DoubleMetaphoneResult, index: usize) -> usize {
+fn handle_aeiouy(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, index: usize) -> usize {
     if index == 0 {
+        rules.rule("initial vowel");
         result.append('A');
+    } else {
+        rules.rule("vowel");
     }
 
     index + 1
@@ -84,12 +201,15 @@ fn is_silent_start(value: &str) -> bool {
         .fold(false, |acc, x| acc || x)
 }
 
-fn clean_input(value: &str) -> Option<String> {
+fn clean_input(value: &str, compatibility: MetaphoneCompatibility) -> Option<String> {
     if value.len() == 0 {
         return None;
     }
 
-    let value = value.trim();
+    let value = match compatibility {
+        MetaphoneCompatibility::CommonsCodec => value.trim(),
+        MetaphoneCompatibility::Postgres     => return Some(value.to_ascii_uppercase()),
+    };
 
     if value.len() == 0 {
         return None;
@@ -9,12 +9,13 @@ pub struct DoubleMetaphoneResult {
 }
 
 impl DoubleMetaphoneResult {
-    pub fn new(length: i32) -> DoubleMetaphoneResult {
-        DoubleMetaphoneResult { primary: String::with_capacity(length as usize), alternate: String::with_capacity(length as usize), max_length: length as usize }
+    /// A `length` of 0 means the codes are never truncated
+    pub fn new(length: usize) -> DoubleMetaphoneResult {
+        DoubleMetaphoneResult { primary: String::with_capacity(length), alternate: String::with_capacity(length), max_length: length }
     }
 
     pub fn is_complete(&mut self) -> bool {
-        self.primary.len() >= self.max_length && self.alternate.len() >= self.max_length
+        self.max_length > 0 && self.primary.len() >= self.max_length && self.alternate.len() >= self.max_length
     }
 
     pub fn append_primary(&mut self, letter: char) {
@@ -31,6 +32,9 @@ impl DoubleMetaphoneResult {
     }
 
     pub fn cleanup(&mut self) {
+        if self.max_length == 0 {
+            return;
+        }
         if self.primary.len() > self.max_length {
             self.primary.truncate(self.max_length);
         }
@@ -39,3 +43,59 @@ impl DoubleMetaphoneResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cleanup_truncates_both_codes() {
+        let mut result = DoubleMetaphoneResult::new(4);
+        for letter in "ANTRS".chars() {
+            result.append(letter);
+        }
+        result.append_alternate('N');
+        assert!(result.is_complete());
+
+        result.cleanup();
+        assert_eq!("ANTR", result.primary);
+        assert_eq!("ANTR", result.alternate);
+    }
+
+    #[test]
+    fn cleanup_truncates_longest_code_only() {
+        let mut result = DoubleMetaphoneResult::new(4);
+        for letter in "SMT".chars() {
+            result.append(letter);
+        }
+        result.append_primary('0');
+        result.append_primary('X');
+        assert!(!result.is_complete());
+
+        result.cleanup();
+        assert_eq!("SMT0", result.primary);
+        assert_eq!("SMT", result.alternate);
+    }
+
+    #[test]
+    fn cleanup_keeps_short_codes() {
+        let mut result = DoubleMetaphoneResult::new(4);
+        result.append('K');
+        result.cleanup();
+        assert_eq!("K", result.primary);
+        assert_eq!("K", result.alternate);
+    }
+
+    #[test]
+    fn cleanup_unlimited_length() {
+        let mut result = DoubleMetaphoneResult::new(0);
+        for letter in "ALKSNTR".chars() {
+            result.append(letter);
+        }
+        assert!(!result.is_complete());
+
+        result.cleanup();
+        assert_eq!("ALKSNTR", result.primary);
+        assert_eq!("ALKSNTR", result.alternate);
+    }
+}
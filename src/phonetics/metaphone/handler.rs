@@ -5,7 +5,26 @@ use phonetics::utils::metaphone_utils::*;
 use phonetics::metaphone::conditions::{condition_c0, condition_ch0, condition_ch1, condition_m0, condition_l0};
 use phonetics::metaphone::double_metaphone_result::DoubleMetaphoneResult;
 
-pub fn handle_b(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+/// Name of the rule being applied, for the traces
+#[derive(Debug, Default)]
+pub struct RuleRecorder {
+    rule: &'static str,
+}
+
+impl RuleRecorder {
+    /// Name the rule being applied
+    pub fn rule(&mut self, rule: &'static str) {
+        self.rule = rule;
+    }
+
+    /// Name of the last rule applied, which is then forgotten
+    pub fn take(&mut self) -> &'static str {
+        ::std::mem::take(&mut self.rule)
+    }
+}
+
+pub fn handle_b(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
+    rules.rule("B");
     result.append('P');
     match char_at(&value, index + 1) {
         Some(ch) => if ch == 'B' {
@@ -17,54 +36,65 @@ pub fn handle_b(result: &mut DoubleMetaphoneResult, value: &String, index: usize
     }
 }
 
-pub fn handle_c_cedilla(result: &mut DoubleMetaphoneResult, index: usize) -> usize {
+pub fn handle_c_cedilla(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, index: usize) -> usize {
+    rules.rule("Ç");
     result.append('S');
     index + 1
 }
 
-pub fn handle_c(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_c(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
     if condition_c0(&value, index) {
+        rules.rule("C: germanic -ACH- (condition_c0)");
         result.append('K');
         index + 2
     } else if index == 0 && contains(&value, index, 6, "CAESAR") {
+        rules.rule("C: initial CAESAR");
         result.append('S');
         index + 2
     } else if contains(&value, index, 2, "CH") {
-        handle_ch(result, &value, index)
+        handle_ch(result, rules, &value, index)
     } else if contains(&value, index, 2, "CZ") && !(index > 1 && contains(&value, index - 2, 4, "WICZ")) {
 
         //-- "Czerny" --//
+        rules.rule("CZ: Czerny");
         result.append_primary('S');
         result.append_alternate('X');
         index + 2
     } else if contains(&value, index + 1, 3, "CIA") {
         //-- "focaccia" --//
+        rules.rule("CIA: focaccia");
         result.append('X');
         index + 3
     } else if contains(&value, index, 2, "CC") && ! (index == 1 && char_at_match(&value, 0, 'M')) {
         //-- double "cc" but not "McClelland" --//
-        handle_cc(result, &value, index)
+        handle_cc(result, rules, &value, index)
     } else if contains(&value, index, 2, "CK") || contains(&value, index, 2, "CG") || contains(&value, index, 2, "CQ") {
+        rules.rule("CK, CG, CQ");
         result.append('K');
         index + 2
     } else if contains(&value, index, 2, "CI") || contains(&value, index, 2, "CE") || contains(&value, index, 2, "CY") {
         //-- Italian vs. English --//
         if contains(&value, index, 3, "CIO") || contains(&value, index, 3, "CIE") || contains(&value, index, 3, "CIA") {
+            rules.rule("CIO, CIE, CIA: Italian");
             result.append_primary('S');
             result.append_alternate('X');
             index + 2
         } else {
+            rules.rule("CE, CI, CY");
             result.append('S');
             index + 2
         }
     } else {
+        rules.rule("C");
         result.append('K');
         if contains(&value, index + 1, 2, " C") || contains(&value, index + 1, 2, " Q") || contains(&value, index + 1, 2, " G") {
             //-- Mac Caffrey, Mac Gregor --//
+            rules.rule("C: Mac Caffrey, Mac Gregor");
             index + 3
         } else if
             (contains(&value, index + 1, 1, "C") || contains(&value, index + 1, 1, "K") || contains(&value, index + 1, 1, "Q")) &&
                 ! (contains(&value, index + 1, 2, "CE") || contains(&value, index + 1, 2, "CI")) {
+            rules.rule("C: CC, CK, CQ");
             index + 2
         } else {
             index + 1
@@ -72,46 +102,55 @@ pub fn handle_c(result: &mut DoubleMetaphoneResult, value: &String, index: usize
     }
 }
 
-fn handle_cc(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+fn handle_cc(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
     if contains_at_least_one(&value, index + 2, 1, &["I", "E", "H"]) && !contains(&value, index + 2, 2, "HU") {
         //-- "bellocchio" but not "bacchus" --//
-        if (index == 1 && char_at_match_before(&value, index, 1, 'A')) || (contains_at_least_one(&value, index - 1, 5, &["UCCEE", "UCCES"])) {
+        if (index == 1 && char_at_match_before(&value, index, 1, 'A')) || (index > 0 && contains_at_least_one(&value, index - 1, 5, &["UCCEE", "UCCES"])) {
             //-- "accident", "accede", "succeed" --//
+            rules.rule("CC: accident, succeed");
             result.append('K');
             result.append('S');
         } else {
             //-- "bacci", "bertucci", other Italian --//
+            rules.rule("CC: Italian, bacci");
             result.append('X');
         }
         index + 3
     } else {
+        rules.rule("CC");
         result.append('K');
         index + 2
     }
 }
 
-pub fn handle_ch(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_ch(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
     if index > 0 && contains(&value, index, 4, "CHAE") {
+        rules.rule("CHAE: Michael");
         result.append_primary('K');
         result.append_alternate('X');
         index + 2
     } else if condition_ch0(&value, index) {
         //-- Greek roots ("chemistry", "chorus", etc.) --//
+        rules.rule("CH: Greek root (condition_ch0)");
         result.append('K');
         index + 2
     } else if condition_ch1(&value, index) {
         //-- Germanic, Greek, or otherwise 'ch' for 'kh' sound --//
+        rules.rule("CH: germanic or Greek (condition_ch1)");
         result.append('K');
         index + 2
     } else {
         if index > 0 {
             if contains(&value, 0, 2, "MC") {
+                rules.rule("CH: Mc");
                 result.append('K');
             } else {
+                rules.rule("CH");
                 result.append_primary('X');
                 result.append_alternate('K');
             }
         } else {
+            rules.rule("CH: initial");
             result.append('X');
         }
 
@@ -119,61 +158,71 @@ pub fn handle_ch(result: &mut DoubleMetaphoneResult, value: &String, index: usiz
     }
 }
 
-pub fn handle_d(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_d(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
     if contains(&value, index, 2, "DG") {
         //-- "Edge" --//
         if contains_at_least_one(&value, index + 2, 1, &["I", "E", "Y"]) {
             //-- "Edgar" --//
+            rules.rule("DGE, DGI, DGY: edge");
             result.append('J');
             index + 3
         } else {
+            rules.rule("DG: Edgar");
             result.append('T');
             result.append('K');
             index + 2
         }
     } else if contains_at_least_one(&value, index, 2, &["DT", "DD"]) {
+        rules.rule("DT, DD");
         result.append('T');
         index + 2
     } else {
+        rules.rule("D");
         result.append('T');
         index + 1
     }
 }
 
-pub fn handle_f(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_f(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
+    rules.rule("F");
     double_next_char(result, value, index, 'F')
 }
 
-pub fn handle_g(result: &mut DoubleMetaphoneResult, value: &String, index: usize, slavo_germanic: bool) -> usize {
+pub fn handle_g(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize, slavo_germanic: bool) -> usize {
     let current_char = match char_at(&value, index + 1) {
         Some(v) => v,
         None => '_'
     };
 
     if current_char == 'H' {
-        handle_gh(result, value, index)
+        handle_gh(result, rules, value, index)
     } else if current_char == 'N' {
         if index == 1 && is_vowel(&value, 0) && !slavo_germanic {
+            rules.rule("GN: after initial vowel");
             result.append_primary('K');
             result.append_primary('N');
             result.append_alternate('N');
         } else if !contains(&value, index + 2, 2, "EY") && !slavo_germanic {
+            rules.rule("GN");
             result.append_primary('N');
             result.append_alternate('K');
             result.append_alternate('N');
         } else {
+            rules.rule("GN: slavo-germanic or GNEY");
             result.append('K');
             result.append('N');
         }
 
         index + 2
     } else if contains(&value, index + 1, 2, "LI") && !slavo_germanic {
+        rules.rule("GLI");
         result.append_primary('K');
         result.append_primary('L');
         result.append_alternate('L');
         index + 2
     } else if index == 0 && (current_char == 'Y' || contains_at_least_one(&value, index + 1, 2, ES_EP_EB_EL_EY_IB_IL_IN_IE_EI_ER)) {
         //-- -ges-, -gep-, -gel-, -gie- at beginning --//
+        rules.rule("G: initial -GES-, -GEP-, -GEL-, -GIE-");
         result.append_primary('K');
         result.append_alternate('J');
         index + 2
@@ -182,6 +231,7 @@ pub fn handle_g(result: &mut DoubleMetaphoneResult, value: &String, index: usize
         !contains_at_least_one(&value, index - 1, 1, &["E", "I"]) &&
         !contains_at_least_one(&value, index - 1, 3, &["RGY", "OGY"]) {
             //-- -ger-, -gy- --//
+            rules.rule("G: -GER-, -GY-");
             result.append_primary('K');
             result.append_alternate('J');
             index + 2
@@ -189,27 +239,33 @@ pub fn handle_g(result: &mut DoubleMetaphoneResult, value: &String, index: usize
         //-- Italian "biaggi" --//
         if contains_at_least_one(&value, 0, 4, &["VAN ", "VON "]) || contains(&value, 0, 3, "SCH") || contains(&value, index + 1, 2, "ET") {
             //-- obvious germanic --//
+            rules.rule("G: before E, I, Y, germanic");
             result.append('K');
         } else if contains(&value, index + 1, 3, "IER") {
+            rules.rule("GIER");
             result.append('J');
         } else {
+            rules.rule("G: before E, I, Y, Italian biaggi");
             result.append_primary('J');
             result.append_alternate('K');
         }
 
         index + 2
     } else if current_char == 'G' {
+        rules.rule("GG");
         result.append('K');
 
         index + 2
     } else {
+        rules.rule("G");
         result.append('K');
         index + 1
     }
 }
 
-fn handle_gh(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+fn handle_gh(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
     if index > 0 && ! is_vowel(&value, index - 1) {
+        rules.rule("GH: after consonant");
         result.append('K');
         index + 2
     } else if index == 0 {
@@ -219,8 +275,10 @@ fn handle_gh(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -
         };
 
         if char_plus_two == 'I' {
+            rules.rule("GH: initial GHI");
             result.append('J');
         } else {
+            rules.rule("GH: initial");
             result.append('K');
         }
 
@@ -229,50 +287,63 @@ fn handle_gh(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -
         (index > 2 && contains_at_least_one(&value, index - 3, 1, &["B", "H", "D"])) ||
         (index > 3 && contains_at_least_one(&value, index - 4, 1, &["B", "H", "D"])) {
             //-- Parker's rule (with some further refinements) - "hugh"
+            rules.rule("GH: Parker's rule, hugh");
             index + 2
         } else {
+            rules.rule("GH: silent");
             if index > 2 && char_at_match_before(&value, index, 1, 'U') && contains_at_least_one(&value, index - 3, 1, &["C", "G", "L", "R", "T"]) {
                 //-- "laugh", "McLaughlin", "cough", "gough", "rough", "tough"
+                rules.rule("GH: laugh, cough, rough");
                 result.append('F');
             } else if index > 0 && !char_at_match_before(&value, index, 1, 'I') {
+                rules.rule("GH");
                 result.append('K');
             }
             index + 2
         }
 }
 
-pub fn handle_h(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_h(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
     //-- only keep if first & before vowel or between 2 vowels --//
     if (index == 0 || is_vowel(&value, index - 1)) && is_vowel(&value, index + 1) {
+        rules.rule("H: before vowel");
         result.append('H');
         index + 2
     } else {
+        rules.rule("H: silent");
         index + 1
     }
 }
 
-pub fn handle_j(result: &mut DoubleMetaphoneResult, value: &String, index: usize, slavo_germanic: bool) -> usize {
+pub fn handle_j(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize, slavo_germanic: bool) -> usize {
     if contains(&value, index, 4, "JOSE") || contains(&value, 0, 4, "SAN") {
         //-- obvious Spanish, "Jose", "San Jacinto" --//
         if  (index == 0 && (index > 4 && char_at_match_after(&value, index, 4, ' ')) || value.len() == 4) || contains(&value, 0, 4, "SAN ") {
+            rules.rule("J: Spanish, Jose, San Jacinto");
             result.append('H');
         } else {
+            rules.rule("J: Spanish or English");
             result.append_primary('J');
             result.append_alternate('H');
         }
 
         index + 1
     } else {
+        rules.rule("J: silent");
         if index == 0 && !contains(&value, index, 4, "JOSE") {
+            rules.rule("J: initial");
             result.append_primary('J');
             result.append_alternate('A');
         } else if is_vowel(&value, index - 1) && !slavo_germanic && (char_at_match_after(&value, index, 1, 'A') || char_at_match_after(&value, index, 1, 'O')) {
+            rules.rule("J: Spanish, between vowels");
             result.append_primary('J');
             result.append_alternate('H');
         } else if index == value.len() - 1 {
+            rules.rule("J: final");
             result.append_primary('J');
             result.append_alternate(' ');
         } else if !contains_at_least_one(&value, index + 1, 1, L_T_K_S_N_M_B_Z) && !contains_at_least_one(&value, index - 1, 1, &["S", "K", "L"]) {
+            rules.rule("J");
             result.append('J');
         }
 
@@ -283,7 +354,8 @@ pub fn handle_j(result: &mut DoubleMetaphoneResult, value: &String, index: usize
     }
 }
 
-pub fn handle_k(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_k(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
+    rules.rule("K");
     result.append('K');
     match char_at(&value, index + 1) {
         Some(v) if v == 'K' => index + 2,
@@ -291,46 +363,59 @@ pub fn handle_k(result: &mut DoubleMetaphoneResult, value: &String, index: usize
     }
 }
 
-pub fn handle_l(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_l(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
     match char_at(&value, index + 1) {
         Some(v) => {
             if v == 'L' {
                 if condition_l0(&value, index) {
+                    rules.rule("LL: Spanish, cabrillo, gallegos (condition_l0)");
                     result.append_primary('L');
                 } else {
+                    rules.rule("LL");
                     result.append('L');
                 }
                 index + 2
             } else {
+                rules.rule("L");
                 result.append('L');
                 index + 1
             }
         },
         None => {
+            rules.rule("L");
             result.append('L');
             index + 1
         }
     }
 }
 
-pub fn handle_m(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_m(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
+    rules.rule("M");
     result.append('M');
-    if condition_m0(&value, index) { index + 2 } else { index + 1 }
+    if condition_m0(&value, index) {
+        rules.rule("MM, -UMB- (condition_m0)");
+        index + 2
+    } else {
+        index + 1
+    }
 }
 
-pub fn handle_n(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_n(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
+    rules.rule("N");
     double_next_char(result, value, index, 'N')
 }
 
-pub fn handle_p(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_p(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
     let current_char = match char_at(&value, index + 1) {
         Some(v) => v,
         _ => '_'
     };
     if current_char == 'H' {
+        rules.rule("PH");
         result.append('F');
         index + 2
     } else {
+        rules.rule("P");
         result.append('P');
         if contains_at_least_one(&value, index + 1, 1, &["P", "B"]) {
             index + 2
@@ -340,7 +425,8 @@ pub fn handle_p(result: &mut DoubleMetaphoneResult, value: &String, index: usize
     }
 }
 
-pub fn handle_q(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_q(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
+    rules.rule("Q");
     result.append('K');
     match char_at(&value, index + 1) {
         Some(v) if v == 'Q' => index + 2,
@@ -348,10 +434,12 @@ pub fn handle_q(result: &mut DoubleMetaphoneResult, value: &String, index: usize
     }
 }
 
-pub fn handle_r(result: &mut DoubleMetaphoneResult, value: &String, index: usize, slavo_germanic: bool) -> usize {
-    if index == value.len() - 1 && !slavo_germanic && contains(&value, index - 2, 2, "IE") && !contains_at_least_one(&value, index - 4, 2, &["ME", "MA"]) {
+pub fn handle_r(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize, slavo_germanic: bool) -> usize {
+    if index == value.len() - 1 && !slavo_germanic && index > 1 && contains(&value, index - 2, 2, "IE") && !(index > 3 && contains_at_least_one(&value, index - 4, 2, &["ME", "MA"])) {
+        rules.rule("R: French final -IER");
         result.append_alternate('R');
     } else {
+        rules.rule("R");
         result.append('R');
     }
     match char_at(&value, index + 1) {
@@ -360,28 +448,34 @@ pub fn handle_r(result: &mut DoubleMetaphoneResult, value: &String, index: usize
     }
 }
 
-pub fn handle_s(result: &mut DoubleMetaphoneResult, value: &String, index: usize, slavo_germanic: bool) -> usize {
+pub fn handle_s(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize, slavo_germanic: bool) -> usize {
     if index > 0 && contains_at_least_one(&value, index - 1, 3, &["ISL", "YSL"]) {
         //-- special cases "island", "isle", "carlisle", "carlysle" --//
+        rules.rule("S: island, isle");
         index + 1
     } else if index == 0 && contains(&value, index, 5, "SUGAR") {
         //-- special case "sugar" --//
+        rules.rule("S: initial SUGAR");
         result.append_primary('X');
         result.append_alternate('S');
         index + 1
     } else if contains(&value, index, 2, "SH") {
         if contains_at_least_one(&value, index + 1, 4, &["HEIM", "HOEK", "HOLM", "HOLZ"]) {
             //-- germanic --//
+            rules.rule("SH: germanic");
             result.append('S');
         } else {
+            rules.rule("SH");
             result.append('X');
         }
 
         index + 2
     } else if contains_at_least_one(&value, index, 3, &["SIO", "SIA"]) || contains(&value, index, 4, "SIAN") {
         if slavo_germanic {
+            rules.rule("SIO, SIA, SIAN: slavo-germanic");
             result.append('S');
         } else {
+            rules.rule("SIO, SIA, SIAN");
             result.append_primary('S');
             result.append_alternate('X');
         }
@@ -391,6 +485,7 @@ pub fn handle_s(result: &mut DoubleMetaphoneResult, value: &String, index: usize
         // "snider" match "schneider" --//
         //-- also, -sz- in slavic language although in hungarian it //
         //   is pronounced "s" --//
+        rules.rule("S: germanic, smith, schneider, -SZ-");
         result.append_primary('S');
         result.append_alternate('X');
         if contains(&value, index + 1, 1, "Z") {
@@ -399,12 +494,14 @@ pub fn handle_s(result: &mut DoubleMetaphoneResult, value: &String, index: usize
             index + 1
         }
     } else if contains(&value, index, 2, "SC") {
-        handle_sc(result, &value, index)
+        handle_sc(result, rules, &value, index)
     } else {
-        if index == value.len() - 1 && contains_at_least_one(&value, index - 2, 2, &["AI", "OI"]) {
+        if index == value.len() - 1 && index > 1 && contains_at_least_one(&value, index - 2, 2, &["AI", "OI"]) {
             //-- french e.g. "resnais", "artois" --//
+            rules.rule("S: French final, artois");
             result.append_alternate('S');
         } else {
+            rules.rule("S");
             result.append('S');
         }
 
@@ -416,7 +513,7 @@ pub fn handle_s(result: &mut DoubleMetaphoneResult, value: &String, index: usize
     }
 }
 
-fn handle_sc(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+fn handle_sc(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
     let current_char = match char_at(&value, index + 2) {
         Some(v) => v,
         _ => '_'
@@ -427,24 +524,30 @@ fn handle_sc(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -
             //-- Dutch origin, e.g. "school", "schooner" --//
             if contains_at_least_one(&value, index + 3, 2, &["ER", "EN"]) {
                 //-- "schermhorn", "shenker" --//
+                rules.rule("SCH: Dutch, schermerhorn");
                 result.append_primary('X');
                 result.append_alternate('S');
                 result.append_alternate('K');
             } else {
+                rules.rule("SCH: Dutch, school");
                 result.append('S');
                 result.append('K');
             }
         } else {
             if index == 0 && !is_vowel(&value, 3) && !char_at_match(&value, 3, 'W') {
+                rules.rule("SCH: initial");
                 result.append_primary('X');
                 result.append_alternate('S');
             } else {
+                rules.rule("SCH");
                 result.append('X');
             }
         }
     } else if contains_at_least_one(&value, index + 2, 1, &["I", "E", "Y"]) {
+        rules.rule("SCE, SCI, SCY");
         result.append('S');
     } else {
+        rules.rule("SC");
         result.append('S');
         result.append('K');
     }
@@ -452,11 +555,13 @@ fn handle_sc(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -
     index + 3
 }
 
-pub fn handle_t(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_t(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
     if contains(&value, index, 4, "TION") {
+        rules.rule("TION");
         result.append('X');
         index + 3
     } else if contains_at_least_one(&value, index, 3, &["TIA", "TCH"]) {
+        rules.rule("TIA, TCH");
         result.append('X');
         index + 3
     } else if contains(&value, index, 2, "TH") || contains(&value, index, 3, "TTH") {
@@ -464,13 +569,16 @@ pub fn handle_t(result: &mut DoubleMetaphoneResult, value: &String, index: usize
             contains_at_least_one(&value, 0, 4, &["VAN ", "VON "]) ||
             contains(&value, 0, 3, "SCH")
         {
+            rules.rule("TH: germanic");
             result.append('T');
         } else {
+            rules.rule("TH");
             result.append_primary('0');
             result.append_alternate('T');
         }
         index + 2
     } else {
+        rules.rule("T");
         result.append('T');
 
         if contains_at_least_one(&value, index + 1, 1, &["T", "D"]) { index + 2 } else { index + 1 }
@@ -478,7 +586,8 @@ pub fn handle_t(result: &mut DoubleMetaphoneResult, value: &String, index: usize
 }
 
 
-pub fn handle_v(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_v(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
+    rules.rule("V");
     result.append('F');
     match char_at(&value, index + 1) {
         Some(v) if v == 'V' => index + 2,
@@ -486,49 +595,58 @@ pub fn handle_v(result: &mut DoubleMetaphoneResult, value: &String, index: usize
     }
 }
 
-pub fn handle_w(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_w(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
     if contains(&value, index, 2, "WR") {
         //-- can also be in middle of word --//
+        rules.rule("WR");
         result.append('R');
         index + 2
     } else {
         if index == 0 && (is_vowel(&value, index +1) || contains(&value, index, 2, "WH")) {
             if is_vowel(&value, index + 1) {
                 //-- Wasserman should match Vasserman --//
+                rules.rule("W: initial before vowel, Wasserman");
                 result.append_primary('A');
                 result.append_alternate('F');
             } else {
                 //-- need Uomo to match Womo --//
+                rules.rule("WH: initial, Womo");
                 result.append('A');
             }
             index + 1
         } else if (index > 0 && index == value.len()-1 && is_vowel(&value, index - 1)) || (index > 0 && contains_at_least_one(&value, index - 1, 5, &["EWSKI", "EWSKY", "OWSKI", "OWSKY"])) || contains(&value, 0, 3, "SCH") {
             //-- Arnow should match Arnoff --//
+            rules.rule("W: final, -EWSKI-, Arnow");
             result.append_alternate('F');
             index + 1
         } else if contains_at_least_one(&value, index, 4, &["WICZ", "WITZ"]) {
             //-- Polish e.g. "filipowicz" --//
+            rules.rule("WICZ, WITZ: Polish");
             result.append_primary('T');
             result.append_primary('S');
             result.append_alternate('F');
             result.append_alternate('X');
             index + 4
         } else {
+            rules.rule("W: silent");
             index + 1
         }
     }
 }
 
-pub fn handle_x(result: &mut DoubleMetaphoneResult, value: &String, index: usize) -> usize {
+pub fn handle_x(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize) -> usize {
     if index == 0 {
+        rules.rule("X: initial");
         result.append('S');
         index + 1
     } else {
         let index_test = index == value.len() - 1;
         let contains_iau_eau = index > 2 && contains_at_least_one(&value, index - 3, 3, &["IAU", "EAU"]);
-        let contains_au_ou = contains_at_least_one(&value, index - 2, 2, &["AU", "OU"]);
+        let contains_au_ou = index > 1 && contains_at_least_one(&value, index - 2, 2, &["AU", "OU"]);
+        rules.rule("X: French final, breaux");
         if !(index_test && (contains_iau_eau || contains_au_ou)) {
             //-- French e.g. breaux --//
+            rules.rule("X");
             result.append('K');
             result.append('S');
         }
@@ -537,21 +655,24 @@ pub fn handle_x(result: &mut DoubleMetaphoneResult, value: &String, index: usize
     }
 }
 
-pub fn handle_z(result: &mut DoubleMetaphoneResult, value: &String, index: usize, slavo_germanic: bool) -> usize {
+pub fn handle_z(result: &mut DoubleMetaphoneResult, rules: &mut RuleRecorder, value: &String, index: usize, slavo_germanic: bool) -> usize {
     let current_char = match char_at(&value, index + 1) {
         Some(v) => v,
         _ => '_'
     };
     if current_char == 'H' {
         //-- Chinese pinyin e.g. "zhao" or Angelina "Zhang" --//
+        rules.rule("ZH: pinyin, zhao");
         result.append('J');
         index + 2
     } else {
         if contains_at_least_one(&value, index + 1, 2, &["ZO", "ZI", "ZA"]) || (slavo_germanic && index > 0 && !char_at_match_before(&value, index, 1, 'T')) {
+            rules.rule("Z: ZO, ZI, ZA, slavo-germanic");
             result.append_primary('S');
             result.append_alternate('T');
             result.append_alternate('S');
         } else {
+            rules.rule("Z");
             result.append('S');
         }
 
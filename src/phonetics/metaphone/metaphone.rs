@@ -3,6 +3,111 @@ static VARSON: &'static str = "CSPTG";
 const MAX_LEN: usize = 10;
 
 use phonetics::utils::metaphone_utils::is_vowel_without_y as is_vowel;
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+use phonetics::metaphone::trace::{substring, Trace};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Reference implementation whose metaphone codes are reproduced
+///
+/// The original description of metaphone leaves room for interpretation, and the common
+/// implementations disagree on a few rules:
+///
+/// | Rule              | Commons codec                                  | Postgres                               |
+/// |-------------------|------------------------------------------------|----------------------------------------|
+/// | initial `WH`      | `W` ("Whitney" is WTN)                         | `H` ("Whitney" is HTN)                 |
+/// | initial `X`       | read as `S` ("Xiong" is XNK)                   | `S` ("Xiong" is SNK)                   |
+/// | `CH`              | `K` after `S` or at the start before a vowel   | `K` after `S` or before `R`            |
+/// | `GH`              | `K` before a vowel, silent elsewhere           | `F`, silent 3 letters after `B, D, H`  |
+/// | `MB`              | `B` silent at the end only                     | `B` always silent                      |
+/// | `TCH`             | `T` silent                                     | `T` kept                               |
+/// | `SCHW`            | `SKW` ("Schwartz" is SKWRTS)                   | `XW` ("Schwartz" is XWRTS)             |
+/// | single letter     | the letter itself                              | encoded like any word                  |
+/// | non-letters       | read as silent letters                         | skipped                                |
+/// | non ASCII letters | uppercased (`ß` is `SS`)                       | skipped                                |
+pub enum MetaphoneCompatibility {
+    /// Apache commons-codec `Metaphone`, the default
+    CommonsCodec,
+    /// PostgreSQL fuzzystrmatch `metaphone()`
+    Postgres,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Metaphone phonetic encoder
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::metaphone::metaphone::{Metaphone, MetaphoneCompatibility};
+///
+/// assert_eq!("TSTN", Metaphone::with_max_length(4).metaphone("testing"));
+/// assert_eq!("TSTNK", Metaphone::new().metaphone("testing"));
+/// assert_eq!("HTN", Metaphone::new().compatibility(MetaphoneCompatibility::Postgres).metaphone("Whitney"));
+/// ```
+pub struct Metaphone {
+    max_length: usize,
+    compatibility: MetaphoneCompatibility,
+}
+
+impl Default for Metaphone {
+    fn default() -> Metaphone {
+        Metaphone::new()
+    }
+}
+
+impl Metaphone {
+    /// Metaphone with codes of 10 chars at most
+    pub fn new() -> Metaphone {
+        Metaphone::with_max_length(MAX_LEN)
+    }
+
+    /// Metaphone with codes of `max_length` chars at most, `0` means unlimited
+    pub fn with_max_length(max_length: usize) -> Metaphone {
+        Metaphone { max_length, compatibility: MetaphoneCompatibility::CommonsCodec }
+    }
+
+    /// Select the implementation to be compatible with
+    pub fn compatibility(mut self, compatibility: MetaphoneCompatibility) -> Metaphone {
+        self.compatibility = compatibility;
+        self
+    }
+
+    /// Try metaphone
+    pub fn metaphone<T:ToString + ?Sized>(&self, word: &T) -> String {
+        self.encode_traced(&word.to_string(), None)
+    }
+
+    fn encode_traced(&self, word: &str, trace: Option<&mut Trace>) -> String {
+        match self.compatibility {
+            MetaphoneCompatibility::CommonsCodec => encode(word, self.max_length, trace),
+            MetaphoneCompatibility::Postgres     => encode_postgres(word, self.max_length, trace),
+        }
+    }
+
+    /// Metaphone code, and the rules applied at each position of the word
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use nlp::phonetics::metaphone::metaphone::Metaphone;
+    ///
+    /// let (code, trace) = Metaphone::new().encode_with_trace("Knight");
+    /// assert_eq!("NT", code);
+    /// assert_eq!("initial KN, GN, PN: first letter silent", trace.steps[0].rule);
+    /// assert_eq!("K", trace.steps[0].consumed);
+    /// assert_eq!(1, trace.steps[1].position);
+    /// ```
+    pub fn encode_with_trace<T:ToString + ?Sized>(&self, word: &T) -> (String, Trace) {
+        let mut trace = Trace::new();
+        let code = self.encode_traced(&word.to_string(), Some(&mut trace));
+        (code, trace)
+    }
+}
+
+impl PhoneticEncoder for Metaphone {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        PhoneticCode::new(self.metaphone(word))
+    }
+}
 
 /// Try metaphone
 ///
@@ -14,13 +119,20 @@ use phonetics::utils::metaphone_utils::is_vowel_without_y as is_vowel;
 /// assert_eq!("S", metaphone("s"));
 /// ```
 pub fn metaphone<T:ToString + ?Sized>(word: &T) -> String {
-    let word = word.to_string();
+    encode(&word.to_string(), MAX_LEN, None)
+}
+
+fn encode(word: &str, max_length: usize, mut trace: Option<&mut Trace>) -> String {
     if word.is_empty() {
         return "".to_owned();
     }
 
     if word.len() == 1 {
-        return word.to_uppercase().to_owned();
+        let code = word.to_uppercase();
+        if let Some(ref mut trace) = trace {
+            trace.push(0, code.clone(), "single letter", &code, &code);
+        }
+        return code;
     }
 
     let upper_word = word.to_uppercase();
@@ -37,14 +149,25 @@ pub fn metaphone<T:ToString + ?Sized>(word: &T) -> String {
         }
     };
 
-    match word_char[0] {
-        'K' | 'G' | 'P'            => action_when_second_letter_match(&mut local, 'N'),
-        'A'                        => action_when_second_letter_match(&mut local, 'E'),
-        'W' if word_char[1] == 'R' => action_when_second_letter_match(&mut local, 'R'),
-        'W' if word_char[1] == 'H' => {action_when_second_letter_match(&mut local, 'H'); local.remove(0); local.insert(0, 'W');},
-        'W'                        => local.push_str(&upper_word),
-        'X'                        => {local.push_str(&upper_word); local.remove(0); local.insert(0, 'S');},
-        _                          => local.push_str(&upper_word)
+    let start_rule = match word_char[0] {
+        'K' | 'G' | 'P'            => {action_when_second_letter_match(&mut local, 'N'); "initial KN, GN, PN: first letter silent"},
+        'A'                        => {action_when_second_letter_match(&mut local, 'E'); "initial AE: A silent"},
+        'W' if word_char[1] == 'R' => {action_when_second_letter_match(&mut local, 'R'); "initial WR: W silent"},
+        'W' if word_char[1] == 'H' => {action_when_second_letter_match(&mut local, 'H'); local.remove(0); local.insert(0, 'W'); "initial WH: H silent"},
+        'W'                        => {local.push_str(&upper_word); ""},
+        'X'                        => {local.push_str(&upper_word); local.remove(0); local.insert(0, 'S'); "initial X: as S"},
+        _                          => {local.push_str(&upper_word); ""}
+    };
+
+    // Index of a char of `local` in the uppercased word
+    let dropped = upper_word.chars().count() - local.chars().count();
+    let wh = dropped == 1 && local.starts_with('W');
+    let origin = |n: usize| if n == 0 && wh { 0 } else { n + dropped };
+    if let Some(ref mut trace) = trace {
+        if dropped == 1 || word_char[0] == 'X' {
+            let silent = if wh { 1 } else { 0 };
+            trace.push(silent, substring(&upper_word, silent, silent + 1), start_rule, "", "");
+        }
     }
 
     let local_size = local.len();
@@ -52,49 +175,159 @@ pub fn metaphone<T:ToString + ?Sized>(word: &T) -> String {
     let mut code = String::new();
 
     let mut n = 0;
-    while (code.len() < MAX_LEN) && n < local_size {
+    while (max_length == 0 || code.len() < max_length) && n < local_size {
         let char_at = local.chars().clone().nth(n).unwrap_or('_');
         let char_next = local.chars().clone().nth(n + 1).unwrap_or('_');
+        let (start, length) = (n, code.len());
 
-        if char_at != 'C' && is_previous_char(&local, n, char_at) {
+        let rule = if char_at != 'C' && is_previous_char(&local, n, char_at) {
             n += 1;
+            "duplicate letter"
         } else {
-            match char_at {
-                'A' | 'E' | 'I' | 'O' | 'U' if n == 0                                                                                         => code.push(char_at),
-                'B' if b_testing(&local, n)                                                                                                   => code.push(char_at),
-                'C' if c_testing(&local, n, char_next) && region_match(&local, n, "CIA")                                                      => code.push('X'),
-                'C' if c_testing(&local, n, char_next) && !is_last_char(local_size, n) && FRONTV.contains(char_next)                          => code.push('S'),
-                'C' if c_testing(&local, n, char_next) && is_previous_char(&local, n, 'S') && is_next_char(&local, n, 'H')                    => code.push('K'),
-                'C' if c_testing(&local, n, char_next) && is_next_char(&local, n, 'H') && (n == 0 && local_size >= 3 && is_vowel(&local, 2))  => code.push('K'),
-                'C' if c_testing(&local, n, char_next) && is_next_char(&local, n, 'H') && !(n == 0 && local_size >= 3 && is_vowel(&local, 2)) => code.push('X'),
-                'C' if c_testing(&local, n, char_next)                                                                                        => code.push('K'),
-                'D' if d_testing(&local, n)                                                                                                   => {code.push('J'); n += 2;},
-                'D'                                                                                                                           => code.push('T'),
-                'G' if g_testing(&local, n) && g_testing_j(&local, n)                                                                         => code.push('J'),
-                'G' if g_testing(&local, n)                                                                                                   => code.push('K'),
-                'H' if h_testing(&local, n) && is_vowel(&local, n + 1)                                                                        => code.push('H'),
-                'F' | 'J' | 'L' | 'M' | 'N' | 'R'                                                                                             => code.push(char_at),
-                'K' if n == 0 || !is_previous_char(&local, n, 'C')                                                                            => code.push(char_at),
-                'P' if is_next_char(&local, n, 'H')                                                                                           => code.push('F'),
-                'P'                                                                                                                           => code.push(char_at),
-                'Q'                                                                                                                           => code.push('K'),
-                'S' if region_match(&local, n, "SH") || region_match(&local, n, "SIO") || region_match(&local, n, "SIA")                      => code.push('X'),
-                'S'                                                                                                                           => code.push('S'),
-                'T' if region_match(&local, n, "TIA") || region_match(&local, n, "TIO")                                                       => code.push('X'),
-                'T' if !region_match(&local, n, "TCH") && region_match(&local, n, "TH")                                                       => code.push('0'),
-                'T' if !region_match(&local, n, "TCH")                                                                                        => code.push('T'),
-                'V'                                                                                                                           => code.push('F'),
-                'W' | 'Y' if !is_last_char(local_size, n) && is_vowel(&local, n + 1)                                                          => code.push(char_at),
-                'X'                                                                                                                           => {code.push('K'); code.push('S');},
-                'Z'                                                                                                                           => code.push('S'),
+            let rule = match char_at {
+                'A' | 'E' | 'I' | 'O' | 'U' if n == 0                                                                                         => {code.push(char_at); "initial vowel"},
+                'B' if b_testing(&local, n)                                                                                                   => {code.push(char_at); "B"},
+                'C' if c_testing(&local, n, char_next) && region_match(&local, n, "CIA")                                                      => {code.push('X'); "CIA"},
+                'C' if c_testing(&local, n, char_next) && !is_last_char(local_size, n) && FRONTV.contains(char_next)                          => {code.push('S'); "CE, CI, CY"},
+                'C' if c_testing(&local, n, char_next) && is_previous_char(&local, n, 'S') && is_next_char(&local, n, 'H')                    => {code.push('K'); "SCH"},
+                'C' if c_testing(&local, n, char_next) && is_next_char(&local, n, 'H') && (n == 0 && local_size >= 3 && is_vowel(&local, 2))  => {code.push('K'); "CH: initial before vowel"},
+                'C' if c_testing(&local, n, char_next) && is_next_char(&local, n, 'H') && !(n == 0 && local_size >= 3 && is_vowel(&local, 2)) => {code.push('X'); "CH"},
+                'C' if c_testing(&local, n, char_next)                                                                                        => {code.push('K'); "C"},
+                'D' if d_testing(&local, n)                                                                                                   => {code.push('J'); n += 2; "DGE, DGI, DGY"},
+                'D'                                                                                                                           => {code.push('T'); "D"},
+                'G' if g_testing(&local, n) && g_testing_j(&local, n)                                                                         => {code.push('J'); "GE, GI, GY"},
+                'G' if g_testing(&local, n)                                                                                                   => {code.push('K'); "G"},
+                'H' if h_testing(&local, n) && is_vowel(&local, n + 1)                                                                        => {code.push('H'); "H before vowel"},
+                'F' | 'J' | 'L' | 'M' | 'N' | 'R'                                                                                             => {code.push(char_at); "F, J, L, M, N, R"},
+                'K' if n == 0 || !is_previous_char(&local, n, 'C')                                                                            => {code.push(char_at); "K"},
+                'P' if is_next_char(&local, n, 'H')                                                                                           => {code.push('F'); "PH"},
+                'P'                                                                                                                           => {code.push(char_at); "P"},
+                'Q'                                                                                                                           => {code.push('K'); "Q"},
+                'S' if region_match(&local, n, "SH") || region_match(&local, n, "SIO") || region_match(&local, n, "SIA")                      => {code.push('X'); "SH, SIO, SIA"},
+                'S'                                                                                                                           => {code.push('S'); "S"},
+                'T' if region_match(&local, n, "TIA") || region_match(&local, n, "TIO")                                                       => {code.push('X'); "TIA, TIO"},
+                'T' if !region_match(&local, n, "TCH") && region_match(&local, n, "TH")                                                       => {code.push('0'); "TH"},
+                'T' if !region_match(&local, n, "TCH")                                                                                        => {code.push('T'); "T"},
+                'V'                                                                                                                           => {code.push('F'); "V"},
+                'W' | 'Y' if !is_last_char(local_size, n) && is_vowel(&local, n + 1)                                                          => {code.push(char_at); "W, Y before vowel"},
+                'X'                                                                                                                           => {code.push('K'); code.push('S'); "X"},
+                'Z'                                                                                                                           => {code.push('S'); "Z"},
                 '_' => {
                     break;
                 }
-                _ => ()
+                _ => "silent"
             };
             n += 1;
+            rule
+        };
+
+        if let Some(ref mut trace) = trace {
+            let appended = &code[length..];
+            trace.push(origin(start), substring(&local, start, n), rule, appended, appended);
+        }
+    }
+
+    if max_length > 0 {
+        code.truncate(max_length);
+    }
+
+    code
+}
+
+/// Metaphone of PostgreSQL fuzzystrmatch, non ASCII letters and other chars are skipped
+fn encode_postgres(word: &str, max_length: usize, mut trace: Option<&mut Trace>) -> String {
+    let chars = word
+        .chars()
+        .map(|c| c.to_ascii_uppercase())
+        .collect::<Vec<_>>();
+    let at = |n: usize| chars.get(n).cloned().unwrap_or('\0');
+    let back = |n: usize, count: usize| if n >= count { chars[n - count] } else { '\0' };
+    let is_vowel = |c: char| "AEIOU".contains(c);
+    let is_soft = |c: char| FRONTV.contains(c);
+
+    let mut n = match chars.iter().position(|c| c.is_ascii_alphabetic()) {
+        Some(n) => n,
+        None => return "".to_owned(),
+    };
+    let mut code = String::new();
+
+    let next = at(n + 1);
+    let (initial, rule, length) = match chars[n] {
+        'A' if next == 'E'                    => (Some('E'), "initial AE: E", 2),
+        'G' | 'K' | 'P' if next == 'N'        => (Some('N'), "initial GN, KN, PN: N", 2),
+        'W' if next == 'H' || next == 'R'     => (Some(next), "initial WH, WR: second letter", 2),
+        'W' if is_vowel(next)                 => (Some('W'), "initial W before vowel", 2),
+        'X'                                   => (Some('S'), "initial X: as S", 1),
+        'A' | 'E' | 'I' | 'O' | 'U'           => (Some(chars[n]), "initial vowel", 1),
+        _                                     => (None, "", 0),
+    };
+    if let Some(initial) = initial {
+        code.push(initial);
+        if let Some(ref mut trace) = trace {
+            trace.push(n, chars[n..n + length].iter().collect(), rule, &code, &code);
+        }
+        n += length;
+    }
+
+    while n < chars.len() && (max_length == 0 || code.len() < max_length) {
+        let (char_at, previous, next, after) = (chars[n], back(n, 1), at(n + 1), at(n + 2));
+        let (start, length) = (n, code.len());
+        let mut skip = 0;
+
+        if !char_at.is_ascii_alphabetic() {
+            n += 1;
+            continue;
+        }
+
+        let rule = match char_at {
+            _ if char_at == previous && char_at != 'C'                        => "duplicate letter",
+            'B' if previous == 'M'                                            => "MB: B silent",
+            'B'                                                               => {code.push('B'); "B"},
+            'C' if is_soft(next) && next == 'I' && after == 'A'               => {code.push('X'); "CIA"},
+            'C' if is_soft(next) && previous == 'S'                           => "SCE, SCI, SCY: C silent",
+            'C' if is_soft(next)                                              => {code.push('S'); "CE, CI, CY"},
+            'C' if next == 'H' && (after == 'R' || previous == 'S')           => {code.push('K'); skip = 1; "CHR, SCH"},
+            'C' if next == 'H'                                                => {code.push('X'); skip = 1; "CH"},
+            'C'                                                               => {code.push('K'); "C"},
+            'D' if next == 'G' && is_soft(after)                              => {code.push('J'); skip = 1; "DGE, DGI, DGY"},
+            'D'                                                               => {code.push('T'); "D"},
+            'G' if next == 'H' && !("BDH".contains(back(n, 3)) || back(n, 4) == 'H')  => {code.push('F'); skip = 1; "GH"},
+            'G' if next == 'H'                                                => "GH: silent after B, D, H",
+            'G' if next == 'N' && (!after.is_ascii_alphabetic() || (after == 'E' && at(n + 3) == 'D'))
+                                                                              => "GN, GNED: G silent",
+            'G' if is_soft(next) && previous != 'G'                           => {code.push('J'); "GE, GI, GY"},
+            'G'                                                               => {code.push('K'); "G"},
+            'H' if is_vowel(next) && !VARSON.contains(previous)               => {code.push('H'); "H before vowel"},
+            'K' if previous != 'C'                                            => {code.push('K'); "K"},
+            'P' if next == 'H'                                                => {code.push('F'); "PH"},
+            'P'                                                               => {code.push('P'); "P"},
+            'Q'                                                               => {code.push('K'); "Q"},
+            'S' if next == 'I' && (after == 'O' || after == 'A')              => {code.push('X'); "SIO, SIA"},
+            'S' if next == 'H'                                                => {code.push('X'); skip = 1; "SH"},
+            'S' if next == 'C' && after == 'H' && at(n + 3) == 'W'            => {code.push('X'); skip = 2; "SCHW"},
+            'S'                                                               => {code.push('S'); "S"},
+            'T' if next == 'I' && (after == 'O' || after == 'A')              => {code.push('X'); "TIA, TIO"},
+            'T' if next == 'H'                                                => {code.push('0'); skip = 1; "TH"},
+            'T'                                                               => {code.push('T'); "T"},
+            'V'                                                               => {code.push('F'); "V"},
+            'W' | 'Y' if is_vowel(next)                                       => {code.push(char_at); "W, Y before vowel"},
+            'X'                                                               => {code.push('K'); code.push('S'); "X"},
+            'Z'                                                               => {code.push('S'); "Z"},
+            'F' | 'J' | 'L' | 'M' | 'N' | 'R'                                 => {code.push(char_at); "F, J, L, M, N, R"},
+            _                                                                 => "silent",
+        };
+        n += 1 + skip;
+
+        if let Some(ref mut trace) = trace {
+            let appended = &code[length..];
+            trace.push(start, chars[start..n.min(chars.len())].iter().collect(), rule, appended, appended);
         }
     }
+
+    if max_length > 0 {
+        code.truncate(max_length);
+    }
+
     code
 }
 
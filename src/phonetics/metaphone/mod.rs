@@ -5,3 +5,5 @@ mod double_metaphone_result;
 pub mod double_metaphone;
 /// Module metaphone
 pub mod metaphone;
+/// Module encoding trace
+pub mod trace;
@@ -0,0 +1,65 @@
+use std::fmt;
+
+#[derive(PartialEq, Clone, Debug)]
+/// Rule applied at a position of a word while encoding it
+pub struct TraceStep {
+    /// Index of the first char consumed, in chars, in the uppercased word
+    pub position: usize,
+    /// Chars consumed by the rule
+    pub consumed: String,
+    /// Name of the rule
+    pub rule: &'static str,
+    /// Chars appended to the primary code
+    pub primary: String,
+    /// Chars appended to the alternate code, the same as `primary` for metaphone
+    pub alternate: String,
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+/// Rules applied while encoding a word, in order
+///
+/// The codes are truncated to their maximum length once all the rules are applied, so the
+/// chars appended by the last steps can be missing from the codes.
+pub struct Trace {
+    /// Steps of the encoding
+    pub steps: Vec<TraceStep>,
+}
+
+impl Trace {
+    /// Empty trace
+    pub fn new() -> Trace {
+        Trace::default()
+    }
+
+    /// Record a step
+    pub(crate) fn push(&mut self, position: usize, consumed: String, rule: &'static str, primary: &str, alternate: &str) {
+        self.steps.push(TraceStep {
+            position,
+            consumed,
+            rule,
+            primary: primary.to_owned(),
+            alternate: alternate.to_owned(),
+        });
+    }
+
+    /// Names of the rules applied, in order
+    pub fn rules(&self) -> Vec<&'static str> {
+        self.steps.iter().map(|step| step.rule).collect()
+    }
+}
+
+impl fmt::Display for Trace {
+    /// One line per step: position, chars consumed, rule, and chars appended to the codes
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for step in &self.steps {
+            writeln!(f, "{:>3} {:<6} {:<48} {:<4} {}",
+                     step.position, step.consumed, step.rule, step.primary, step.alternate)?;
+        }
+        Ok(())
+    }
+}
+
+/// Chars of `value` from `start` to `end`, in chars
+pub(crate) fn substring(value: &str, start: usize, end: usize) -> String {
+    value.chars().skip(start).take(end.saturating_sub(start)).collect()
+}
@@ -4,3 +4,31 @@ mod utils;
 pub mod soundex;
 /// Module metaphone
 pub mod metaphone;
+/// Module match rating approach
+pub mod mra;
+/// Module phonetic encoder
+pub mod encoder;
+/// Module phrase
+pub mod phrase;
+/// Module phonetic index
+pub mod index;
+/// Module eudex
+pub mod eudex;
+/// Module phonex
+pub mod phonex;
+/// Module phonix
+pub mod phonix;
+/// Module fuzzy soundex
+pub mod fuzzy_soundex;
+/// Module french phonetic encoders
+pub mod french;
+/// Module spanish phonetic encoders
+pub mod spanish;
+/// Module portuguese phonetic encoders
+pub mod portuguese;
+/// Module dutch phonetic encoder
+pub mod dutch;
+/// Module polish phonetic encoder
+pub mod polish;
+/// Module swedish and norwegian phonetic encoders
+pub mod nordic;
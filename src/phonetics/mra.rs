@@ -0,0 +1,236 @@
+use std::cmp::max;
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+
+static VOWELS: &str = "AEIOU";
+
+#[derive(PartialEq, Clone, Debug)]
+/// Result of a Match Rating Approach comparison
+pub struct MraResult {
+    /// Codex of the first name
+    pub codex_a: String,
+    /// Codex of the second name
+    pub codex_b: String,
+    /// Similarity rating, between 0 and 6
+    pub similarity: usize,
+    /// Minimum rating required for the names to match
+    pub minimum_rating: usize,
+    /// Whether the names are considered a match
+    pub is_match: bool,
+}
+
+/// Minimum rating depending on the sum of the codex lengths
+fn minimum_rating(sum_length: usize) -> usize {
+    match sum_length {
+        0..=4  => 5,
+        5..=7  => 4,
+        8..=11 => 3,
+        12     => 2,
+        _      => 1,
+    }
+}
+
+/// Compute the similarity rating between two codexes: identical characters are removed from
+/// left to right, then from right to left, and the unmatched characters of the longest codex
+/// are subtracted from 6.
+fn similarity(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut a_left = a.clone();
+    let mut b_left = b.clone();
+
+    for i in 0..a.len() {
+        if i >= b.len() {
+            break;
+        }
+
+        if a[i] == b[i] {
+            a_left[i] = ' ';
+            b_left[i] = ' ';
+        }
+
+        let (a_end, b_end) = (a.len() - 1 - i, b.len() - 1 - i);
+        if a[a_end] == b[b_end] {
+            a_left[a_end] = ' ';
+            b_left[b_end] = ' ';
+        }
+    }
+
+    let unmatched = max(a_left.iter().filter(|c| **c != ' ').count(),
+                        b_left.iter().filter(|c| **c != ' ').count());
+
+    6 - unmatched
+}
+
+/// Match Rating Approach codex of a name
+///
+/// Vowels are removed unless they start the name, double consonants are collapsed, and the
+/// codex is reduced to its first 3 and last 3 letters.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::mra::mra_codex;
+///
+/// assert_eq!("BYRN", mra_codex("Byrne"));
+/// assert_eq!("BRN", mra_codex("Boern"));
+/// assert_eq!("CTHRN", mra_codex("Catherine"));
+/// assert_eq!("KTHRYN", mra_codex("Kathryn"));
+/// assert_eq!("ABRNSN", mra_codex("Abernathy-Hanson"));
+/// ```
+pub fn mra_codex(name: &str) -> String {
+    let letters = name
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .flat_map(|c| c.to_uppercase())
+        .collect::<Vec<_>>();
+
+    let mut codex: Vec<char> = Vec::with_capacity(letters.len());
+    for (i, c) in letters.into_iter().enumerate() {
+        if i > 0 && VOWELS.contains(c) {
+            continue;
+        }
+        if !VOWELS.contains(c) && codex.last() == Some(&c) {
+            continue;
+        }
+        codex.push(c);
+    }
+
+    if codex.len() > 6 {
+        let last = codex.split_off(codex.len() - 3);
+        codex.truncate(3);
+        codex.extend(last);
+    }
+
+    codex.into_iter().collect()
+}
+
+/// Compare two names with the Match Rating Approach
+///
+/// Returns `None` when the names can't be compared, i.e. when one of them is empty or when
+/// their codexes differ in length by 3 or more.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::mra::mra_compare;
+///
+/// let result = mra_compare("Byrne", "Boern").unwrap();
+/// assert_eq!(5, result.similarity);
+/// assert_eq!(4, result.minimum_rating);
+/// assert!(result.is_match);
+///
+/// assert!(mra_compare("Tim", "Timothy-Alexander").is_none());
+/// ```
+pub fn mra_compare(a: &str, b: &str) -> Option<MraResult> {
+    let codex_a = mra_codex(a);
+    let codex_b = mra_codex(b);
+    let (len_a, len_b) = (codex_a.chars().count(), codex_b.chars().count());
+
+    if len_a == 0 || len_b == 0 || max(len_a, len_b) - len_a.min(len_b) >= 3 {
+        return None;
+    }
+
+    let similarity = similarity(&codex_a, &codex_b);
+    let minimum_rating = minimum_rating(len_a + len_b);
+
+    Some(MraResult {
+        codex_a,
+        codex_b,
+        similarity,
+        minimum_rating,
+        is_match: similarity >= minimum_rating,
+    })
+}
+
+/// Compare MRA words
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::mra::compare_mra;
+///
+/// assert!(compare_mra("Byrne", "Boern"));
+/// assert!(compare_mra("Smith", "Smyth"));
+/// assert!(compare_mra("Catherine", "Kathryn"));
+/// assert!(!compare_mra("Smith", "Jones-Llewellyn"));
+/// ```
+pub fn compare_mra(a: &str, b: &str) -> bool {
+    match mra_compare(a, b) {
+        Some(result) => result.is_match,
+        None => false
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Match Rating Approach phonetic encoder
+///
+/// Words match according to the MRA comparison rule, not on codex equality.
+pub struct Mra;
+
+impl PhoneticEncoder for Mra {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        PhoneticCode::new(mra_codex(word))
+    }
+
+    fn is_match(&self, a: &str, b: &str) -> bool {
+        compare_mra(a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mra_codex_vowels() {
+        assert_eq!("SMTH", mra_codex("Smith"));
+        assert_eq!("ALXNDR", mra_codex("Alexander"));
+        assert_eq!("ANT", mra_codex("Aunt"));
+    }
+
+    #[test]
+    fn mra_codex_double_consonants() {
+        assert_eq!("BL", mra_codex("Bellaleal"));
+        assert_eq!("MTHW", mra_codex("Matthew"));
+        assert_eq!("SN", mra_codex("Siinn"));
+    }
+
+    #[test]
+    fn mra_codex_first_3_last_3() {
+        assert_eq!("ABRNSN", mra_codex("Abernathy-Hanson"));
+        assert_eq!("CNSSNT", mra_codex("Constantinopolitanisant"));
+    }
+
+    #[test]
+    fn mra_codex_empty() {
+        assert_eq!("", mra_codex(""));
+        assert_eq!("", mra_codex(" - "));
+    }
+
+    #[test]
+    fn mra_compare_known_pairs() {
+        assert!(compare_mra("Franciszek", "Frances"));
+        assert!(compare_mra("Karl", "Carl"));
+        assert!(compare_mra("Mark", "Marc"));
+        assert!(compare_mra("Stephen", "Steven"));
+        assert!(compare_mra("Catherine", "Katharine"));
+        assert!(!compare_mra("Smith", "Jones-Llewellyn"));
+        assert!(!compare_mra("Byrne", "Kowalski"));
+    }
+
+    #[test]
+    fn mra_compare_rating() {
+        let result = mra_compare("Smith", "Smyth").unwrap();
+        assert_eq!("SMTH", result.codex_a);
+        assert_eq!("SMYTH", result.codex_b);
+        assert_eq!(5, result.similarity);
+        assert_eq!(3, result.minimum_rating);
+        assert!(result.is_match);
+    }
+
+    #[test]
+    fn mra_compare_length_difference() {
+        assert_eq!(None, mra_compare("Tim", "Timothy-Alexander"));
+        assert_eq!(None, mra_compare("", "Smith"));
+    }
+}
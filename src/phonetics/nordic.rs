@@ -0,0 +1,132 @@
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+use phonetics::utils::rules::{fold, scan_sounds};
+
+/// Vowels before which `k`, `g` and `sk` are soft
+const FRONT_VOWELS: &str = "EIYÄÖÆØ";
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Scandinavian language used to encode the words
+pub enum NordicLanguage {
+    /// Swedish
+    Swedish,
+    /// Norwegian, where the `d` of the final `-ld`, `-nd` and `-rd` is silent
+    Norwegian,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Swedish and Norwegian phonetic encoder, a key of the sounds of a word
+pub struct Nordic {
+    language: NordicLanguage,
+}
+
+impl Nordic {
+    /// Encoder for a Scandinavian language
+    pub fn new(language: NordicLanguage) -> Nordic {
+        Nordic { language }
+    }
+
+    /// Swedish or Norwegian key of a word
+    ///
+    /// The "sj" sound (`sj`, `skj`, `stj`, `sch`, `sh`, `ch` and `sk` before a front vowel) is
+    /// coded `X`, the "tj" sound (`tj`, `kj` and `k` before a front vowel) is coded `C`, and `j`,
+    /// `hj`, `dj`, `gj`, `lj` and `g` before a front vowel are coded `J`; the `h` of `hv` is
+    /// silent. `å` and `aa` are coded `O`, `ä` and `æ` are coded `E`, `ö` and `ø` are coded `O`.
+    pub fn nordic(&self, word: &str) -> String {
+        let chars = fold(word, "ÅÄÖÆØ").chars().collect::<Vec<_>>();
+        let at = |i: usize| chars.get(i).cloned();
+        let is_front = |i: usize| FRONT_VOWELS.contains(at(i).unwrap_or('_'));
+        let silent_d = self.language == NordicLanguage::Norwegian;
+
+        scan_sounds(&chars, |i| match (chars[i], at(i + 1)) {
+            ('S', Some('K')) | ('S', Some('T')) if at(i + 2) == Some('J') => Some(("X", 3)),
+            ('S', Some('C')) if at(i + 2) == Some('H') => Some(("X", 3)),
+            ('S', Some('K')) if is_front(i + 2) => Some(("X", 2)),
+            ('S', Some('J')) | ('S', Some('H')) | ('C', Some('H')) => Some(("X", 2)),
+            ('T', Some('J')) | ('K', Some('J')) => Some(("C", 2)),
+            ('K', _) if is_front(i + 1)         => Some(("C", 1)),
+            ('H', Some('J')) | ('D', Some('J')) | ('G', Some('J')) | ('L', Some('J')) => Some(("J", 2)),
+            ('G', _) if is_front(i + 1)         => Some(("J", 1)),
+            ('H', Some('V'))                    => Some(("V", 2)),
+            ('D', None) if silent_d && i > 0 && "LNR".contains(chars[i - 1]) => Some(("", 1)),
+            ('A', Some('A')) | ('Å', _)         => Some(("O", if chars[i] == 'A' { 2 } else { 1 })),
+            ('Ä', _) | ('Æ', _)                 => Some(("E", 1)),
+            ('Ö', _) | ('Ø', _)                 => Some(("O", 1)),
+            ('D', Some('T'))                    => Some(("T", 2)),
+            ('C', Some('K'))                    => Some(("K", 2)),
+            ('P', Some('H'))                    => Some(("F", 2)),
+            ('T', Some('H'))                    => Some(("T", 2)),
+            ('C', Some('E')) | ('C', Some('I')) | ('C', Some('Y')) => Some(("S", 1)),
+            ('C', _) | ('Q', _)                 => Some(("K", 1)),
+            ('W', _)                            => Some(("V", 1)),
+            ('Z', _)                            => Some(("S", 1)),
+            ('X', _)                            => Some(("KS", 1)),
+            _                                   => None,
+        })
+    }
+}
+
+/// Swedish key of a word
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::nordic::swedish;
+///
+/// assert_eq!("XOBERG", swedish("Sjöberg"));
+/// assert_eq!(swedish("Kjell"), swedish("Tjell"));
+/// assert_eq!(swedish("Hjalmar"), swedish("Jalmar"));
+/// ```
+pub fn swedish(word: &str) -> String {
+    Nordic::new(NordicLanguage::Swedish).nordic(word)
+}
+
+/// Norwegian key of a word
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::nordic::norwegian;
+///
+/// assert_eq!("XOL", norwegian("Skjold"));
+/// assert_eq!(norwegian("Aasen"), norwegian("Åsen"));
+/// assert_eq!(norwegian("Gerd"), norwegian("Jer"));
+/// ```
+pub fn norwegian(word: &str) -> String {
+    Nordic::new(NordicLanguage::Norwegian).nordic(word)
+}
+
+impl PhoneticEncoder for Nordic {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        PhoneticCode::new(self.nordic(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swedish_keys() {
+        assert_eq!(swedish("Sjöberg"), swedish("Schöberg"));
+        assert_eq!(swedish("Skärlund"), swedish("Sjärlund"));
+        assert_eq!(swedish("Kerstin"), swedish("Tjerstin"));
+        assert_eq!(swedish("Göran"), swedish("Jöran"));
+        assert_eq!(swedish("Ljungberg"), swedish("Jungberg"));
+        assert_eq!(swedish("Björn"), swedish("Bjorn"));
+        assert_eq!(swedish("Nilsson"), swedish("Nilson"));
+        assert_eq!("SKOG", swedish("Skog"));
+        assert_eq!("GUNAR", swedish("Gunnar"));
+        assert_eq!("", swedish(""));
+    }
+
+    #[test]
+    fn norwegian_keys() {
+        assert_eq!("XOLD", swedish("Skjold"));
+        assert_eq!(norwegian("Hvidsten"), norwegian("Vidsten"));
+        assert_eq!(norwegian("Kjersti"), norwegian("Tjersti"));
+        assert_eq!(norwegian("Aamodt"), norwegian("Åmot"));
+        assert_eq!(norwegian("Ødegård"), norwegian("Odegaar"));
+        assert_eq!(norwegian("Strand"), norwegian("Stran"));
+        assert_ne!(swedish("Strand"), swedish("Stran"));
+    }
+}
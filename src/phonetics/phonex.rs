@@ -0,0 +1,133 @@
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+use phonetics::utils::code::{encode_letters, pad};
+use phonetics::soundex::{encode as soundex_char, SoundexChar};
+
+const MAX_LEN: usize = 4;
+
+fn is_vowel(c: Option<&char>) -> bool {
+    match c {
+        Some(c) => "AEIOUY".contains(*c),
+        None => false
+    }
+}
+
+/// Phonetic equivalent of the first letter
+fn first_letter(c: char) -> char {
+    match c {
+        'A' | 'E' | 'I' | 'O' | 'U' | 'Y' => 'A',
+        'B' | 'P'                         => 'B',
+        'V' | 'F'                         => 'F',
+        'C' | 'K' | 'Q'                   => 'C',
+        'G' | 'J'                         => 'G',
+        'S' | 'Z'                         => 'S',
+        _                                 => c,
+    }
+}
+
+fn encode(word: &str, max_length: usize) -> String {
+    let mut name = word.to_uppercase().chars().filter(|c| !c.is_whitespace()).collect::<Vec<_>>();
+
+    // Trailing S are ignored
+    while name.last() == Some(&'S') {
+        name.pop();
+    }
+
+    // Phonetic equivalents of the first 2 letters, the second letter is a duplicate or ignored
+    match (name.first().cloned(), name.get(1).cloned()) {
+        (Some('K'), Some('N')) => { name.remove(0); },
+        (Some('P'), Some('H')) => { name.remove(0); name[0] = 'F'; },
+        (Some('W'), Some('R')) => { name.remove(0); },
+        _ => ()
+    }
+
+    if name.first() == Some(&'H') {
+        name.remove(0);
+    }
+
+    let mut code = String::new();
+    let mut last = match name.first() {
+        Some(c) => first_letter(*c),
+        None => '0'
+    };
+    if !name.is_empty() {
+        code.push(last);
+    }
+
+    for i in 1..name.len() {
+        let next = name.get(i + 1);
+        let digit = match soundex_char(name[i]) {
+            SoundexChar::S3 if next == Some(&'C') => None,
+            SoundexChar::S4 | SoundexChar::S6 if !(is_vowel(next) || next.is_none()) => None,
+            SoundexChar::S5 => {
+                // D and G after M or N are ignored
+                if next == Some(&'D') || next == Some(&'G') {
+                    name[i + 1] = name[i];
+                }
+                Some('5')
+            },
+            c => c.digit(),
+        };
+
+        if let Some(digit) = digit {
+            if digit != last {
+                code.push(digit);
+            }
+            last = digit;
+        }
+    }
+
+    pad(code, max_length)
+}
+
+/// Phonex code of a name (Lait & Randell), 4 chars long, padded with zeros
+///
+/// Phonex is a soundex with the first letters normalized (KN, PH, WR, initial H and vowels) and
+/// with context rules on D, T, L, R, M and N; trailing S are ignored.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::phonex::phonex;
+///
+/// assert_eq!("C214", phonex("Catchpole"));
+/// assert_eq!(phonex("Kneves"), phonex("Neves"));
+/// assert_eq!(phonex("River"), phonex("Rivers"));
+/// ```
+pub fn phonex(word: &str) -> String {
+    encode(word, MAX_LEN)
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Phonex phonetic encoder
+pub struct Phonex {
+    max_length: usize,
+}
+
+impl Default for Phonex {
+    fn default() -> Phonex {
+        Phonex::new()
+    }
+}
+
+impl Phonex {
+    /// Phonex with codes of 4 chars, padded with zeros
+    pub fn new() -> Phonex {
+        Phonex::with_max_length(MAX_LEN)
+    }
+
+    /// Phonex with codes of `max_length` chars, padded with zeros, `0` means unlimited
+    pub fn with_max_length(max_length: usize) -> Phonex {
+        Phonex { max_length }
+    }
+
+    /// Phonex code of a name
+    pub fn phonex(&self, word: &str) -> String {
+        encode(word, self.max_length)
+    }
+}
+
+impl PhoneticEncoder for Phonex {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        encode_letters(word, |word| self.phonex(word))
+    }
+}
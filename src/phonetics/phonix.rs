@@ -0,0 +1,271 @@
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+use phonetics::utils::code::{encode_letters, pad};
+
+const MAX_LEN: usize = 4;
+
+#[derive(Clone, Copy)]
+enum Position {
+    Start,
+    End,
+    Middle,
+    Anywhere,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Context {
+    Any,
+    Vowel,
+    Consonant,
+}
+
+use self::Context::{Any, Consonant, Vowel};
+use self::Position::{Anywhere, End, Middle, Start};
+
+/// Phonix substitutions, applied in order: position, letters, replacement, and the letters
+/// allowed before and after the replaced letters
+static SUBSTITUTIONS: &[(Position, &str, &str, Context, Context)] = &[
+    (Anywhere, "DG", "G", Any, Any),
+    (Anywhere, "CO", "KO", Any, Any),
+    (Anywhere, "CA", "KA", Any, Any),
+    (Anywhere, "CU", "KU", Any, Any),
+    (Anywhere, "CY", "SI", Any, Any),
+    (Anywhere, "CI", "SI", Any, Any),
+    (Anywhere, "CE", "SE", Any, Any),
+    (Start, "CL", "KL", Any, Vowel),
+    (Anywhere, "CK", "K", Any, Any),
+    (End, "GC", "K", Any, Any),
+    (End, "JC", "K", Any, Any),
+    (Start, "CHR", "KR", Any, Vowel),
+    (Start, "CR", "KR", Any, Vowel),
+    (Start, "WR", "R", Any, Any),
+    (Anywhere, "NC", "NK", Any, Any),
+    (Anywhere, "CT", "KT", Any, Any),
+    (Anywhere, "PH", "F", Any, Any),
+    (Anywhere, "AA", "AR", Any, Any),
+    (Anywhere, "SCH", "SH", Any, Any),
+    (Anywhere, "BTL", "TL", Any, Any),
+    (Anywhere, "GHT", "T", Any, Any),
+    (Anywhere, "AUGH", "ARF", Any, Any),
+    (Middle, "LJ", "LD", Vowel, Vowel),
+    (Anywhere, "LOUGH", "LOW", Any, Any),
+    (Start, "Q", "KW", Any, Any),
+    (Start, "KN", "N", Any, Any),
+    (End, "GN", "N", Any, Any),
+    (Anywhere, "GHN", "N", Any, Any),
+    (End, "GNE", "N", Any, Any),
+    (Anywhere, "GHNE", "NE", Any, Any),
+    (End, "GNES", "NS", Any, Any),
+    (Start, "GN", "N", Any, Any),
+    (Middle, "GN", "N", Any, Consonant),
+    (End, "GN", "N", Any, Any),
+    (Start, "PS", "S", Any, Any),
+    (Start, "PT", "T", Any, Any),
+    (Start, "CZ", "C", Any, Any),
+    (Middle, "WZ", "Z", Vowel, Any),
+    (Middle, "CZ", "CH", Any, Any),
+    (Anywhere, "LZ", "LSH", Any, Any),
+    (Anywhere, "RZ", "RSH", Any, Any),
+    (Middle, "Z", "S", Any, Vowel),
+    (Anywhere, "ZZ", "TS", Any, Any),
+    (Middle, "Z", "TS", Consonant, Any),
+    (Anywhere, "HROUG", "REW", Any, Any),
+    (Anywhere, "OUGH", "OF", Any, Any),
+    (Middle, "Q", "KW", Vowel, Vowel),
+    (Middle, "J", "Y", Vowel, Vowel),
+    (Start, "YJ", "Y", Any, Vowel),
+    (Start, "GH", "G", Any, Any),
+    (End, "GH", "E", Vowel, Any),
+    (Start, "CY", "S", Any, Any),
+    (Anywhere, "NX", "NKS", Any, Any),
+    (Start, "PF", "F", Any, Any),
+    (End, "DT", "T", Any, Any),
+    (End, "TL", "TIL", Any, Any),
+    (End, "DL", "DIL", Any, Any),
+    (Anywhere, "YTH", "ITH", Any, Any),
+    (Start, "TJ", "CH", Any, Vowel),
+    (Start, "TSJ", "CH", Any, Vowel),
+    (Start, "TS", "T", Any, Vowel),
+    (Anywhere, "TCH", "CH", Any, Any),
+    (Middle, "WSK", "VSKIE", Vowel, Any),
+    (End, "WSK", "VSKIE", Vowel, Any),
+    (Start, "MN", "N", Any, Vowel),
+    (Start, "PN", "N", Any, Vowel),
+    (Middle, "STL", "SL", Vowel, Any),
+    (End, "STL", "SL", Vowel, Any),
+    (End, "TNT", "ENT", Any, Any),
+    (End, "EAUX", "OH", Any, Any),
+    (Anywhere, "EXCI", "ECS", Any, Any),
+    (Anywhere, "X", "ECS", Any, Any),
+    (End, "NED", "ND", Any, Any),
+    (Anywhere, "JR", "DR", Any, Any),
+    (End, "EE", "EA", Any, Any),
+    (Anywhere, "ZS", "S", Any, Any),
+    (Middle, "R", "AH", Vowel, Consonant),
+    (End, "R", "AH", Vowel, Any),
+    (Middle, "HR", "AH", Vowel, Consonant),
+    (End, "HR", "AH", Vowel, Any),
+    (End, "RE", "AR", Any, Any),
+    (End, "R", "AH", Vowel, Any),
+    (Anywhere, "LLE", "LE", Any, Any),
+    (End, "LE", "ILE", Consonant, Any),
+    (End, "LES", "ILES", Consonant, Any),
+    (End, "E", "", Any, Any),
+    (End, "ES", "S", Any, Any),
+    (End, "SS", "AS", Vowel, Any),
+    (End, "MB", "M", Vowel, Any),
+    (Anywhere, "MPTS", "MPS", Any, Any),
+    (Anywhere, "MPS", "MS", Any, Any),
+    (Anywhere, "MPT", "MT", Any, Any),
+];
+
+fn is_vowel(c: u8) -> bool {
+    b"AEIOUY".contains(&c)
+}
+
+impl Context {
+    fn accepts(self, c: Option<&u8>) -> bool {
+        match (self, c) {
+            (Any, _)             => true,
+            (Vowel, Some(c))     => is_vowel(*c),
+            (Consonant, Some(c)) => !is_vowel(*c),
+            _                    => false,
+        }
+    }
+}
+
+/// Apply a substitution on a word made of A-Z letters
+fn substitute(word: &str, rule: &(Position, &str, &str, Context, Context)) -> String {
+    let (position, src, tar, pre, post) = *rule;
+    let bytes = word.as_bytes();
+    let (n, len) = (bytes.len(), src.len());
+
+    let matches = |i: usize| {
+        let before = if i > 0 { bytes.get(i - 1) } else { None };
+        let after = bytes.get(i + len);
+        let in_place = match position {
+            Start    => i == 0,
+            End      => i + len == n,
+            // A middle substitution never touches the first or last letter, except to check the
+            // letters around it
+            Middle   => (i > 0 || pre != Any) && (i + len < n || post != Any),
+            Anywhere => true,
+        };
+
+        in_place && pre.accepts(before) && post.accepts(after)
+    };
+
+    let mut result = String::with_capacity(n + 4);
+    let mut i = 0;
+    while i < n {
+        if i + len <= n && &word[i..i + len] == src && matches(i) {
+            result.push_str(tar);
+            i += len;
+        } else {
+            result.push(bytes[i] as char);
+            i += 1;
+        }
+    }
+
+    result
+}
+
+/// Encode an uppercase letter into a phonix digit
+fn digit(c: char) -> char {
+    match c {
+        'B' | 'P'                         => '1',
+        'C' | 'G' | 'J' | 'K' | 'Q'       => '2',
+        'D' | 'T'                         => '3',
+        'L'                               => '4',
+        'M' | 'N'                         => '5',
+        'R'                               => '6',
+        'F' | 'V'                         => '7',
+        'S' | 'X' | 'Z'                   => '8',
+        _                                 => '0',
+    }
+}
+
+fn encode(word: &str, max_length: usize) -> String {
+    let mut word = word
+        .chars()
+        .flat_map(|c| c.to_uppercase())
+        .filter(|c| c.is_ascii_uppercase())
+        .collect::<String>();
+
+    let mut code = String::new();
+
+    if !word.is_empty() {
+        for rule in SUBSTITUTIONS {
+            word = substitute(&word, rule);
+        }
+
+        let mut chars = word.chars();
+        let mut last = None;
+        match chars.next() {
+            Some(c) if is_vowel(c as u8) => code.push('v'),
+            Some(c) => code.push(c),
+            None => ()
+        }
+        for c in chars.map(digit) {
+            if Some(c) != last && c != '0' {
+                code.push(c);
+            }
+            last = Some(c);
+        }
+    }
+
+    pad(code, max_length)
+}
+
+/// Phonix code of a name (Gadd), 4 chars long, padded with zeros
+///
+/// About a hundred letter group substitutions are applied, depending on their position and on
+/// the letters around them, then the letters are coded like soundex with finer classes. A name
+/// starting with a vowel is coded with a `v`.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::phonix::phonix;
+///
+/// assert_eq!("S530", phonix("Schmidt"));
+/// assert_eq!(phonix("Schneider"), phonix("Snyder"));
+/// assert_eq!("v475", phonix("Alphonse"));
+/// ```
+pub fn phonix(word: &str) -> String {
+    encode(word, MAX_LEN)
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Phonix phonetic encoder
+pub struct Phonix {
+    max_length: usize,
+}
+
+impl Default for Phonix {
+    fn default() -> Phonix {
+        Phonix::new()
+    }
+}
+
+impl Phonix {
+    /// Phonix with codes of 4 chars, padded with zeros
+    pub fn new() -> Phonix {
+        Phonix::with_max_length(MAX_LEN)
+    }
+
+    /// Phonix with codes of `max_length` chars, padded with zeros, `0` means unlimited
+    pub fn with_max_length(max_length: usize) -> Phonix {
+        Phonix { max_length }
+    }
+
+    /// Phonix code of a name
+    pub fn phonix(&self, word: &str) -> String {
+        encode(word, self.max_length)
+    }
+}
+
+impl PhoneticEncoder for Phonix {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        encode_letters(word, |word| self.phonix(word))
+    }
+}
@@ -0,0 +1,222 @@
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+
+static PARTICLES: &[&str] = &["van", "von", "vander", "der", "den", "de", "del", "della", "des", "di",
+                              "da", "du", "dos", "das", "la", "le", "al", "el", "bin", "ibn", "ten",
+                              "ter", "d'", "l'"];
+
+/// Whether a char can be part of a token
+fn is_token_char(c: char) -> bool {
+    c.is_alphanumeric() || is_apostrophe(c)
+}
+
+fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '\u{2019}'
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// Phonetic code of a single token of a phrase
+pub struct TokenCode {
+    /// Token, as found in the phrase
+    pub token: String,
+    /// Byte offset of the token start in the phrase
+    pub start: usize,
+    /// Byte offset of the token end in the phrase
+    pub end: usize,
+    /// Phonetic code of the token
+    pub code: PhoneticCode,
+    /// Whether the token is a name particle
+    pub is_particle: bool,
+}
+
+#[derive(Clone, Debug)]
+/// Encode each token of a phrase with a phonetic encoder
+///
+/// Tokens are separated by anything but letters, digits and apostrophes, so hyphenated names
+/// give one token per part. Apostrophes are kept in the token but ignored by the encoder, unless
+/// they end a particle ("d'Artagnan").
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::phrase::PhraseEncoder;
+/// use nlp::phonetics::soundex::Soundex;
+///
+/// let codes = PhraseEncoder::new(Soundex::new()).encode("Mary Ann O'Brien-Smith");
+/// let tokens = codes.iter().map(|c| &c.token[..]).collect::<Vec<_>>();
+/// assert_eq!(vec!["Mary", "Ann", "O'Brien", "Smith"], tokens);
+/// assert_eq!(Some("O165"), codes[2].code.primary());
+/// assert_eq!((9, 16), (codes[2].start, codes[2].end));
+///
+/// let codes = PhraseEncoder::new(Soundex::new()).drop_particles(true).encode("Ludwig van Beethoven");
+/// assert_eq!(2, codes.len());
+/// assert_eq!(Some("B315"), codes[1].code.primary());
+/// ```
+pub struct PhraseEncoder<E: PhoneticEncoder> {
+    encoder: E,
+    particles: Vec<String>,
+    drop_particles: bool,
+}
+
+impl<E: PhoneticEncoder> PhraseEncoder<E> {
+    /// Phrase encoder keeping the particles, with the default particle list
+    pub fn new(encoder: E) -> PhraseEncoder<E> {
+        PhraseEncoder {
+            encoder,
+            particles: PARTICLES.iter().map(|p| p.to_string()).collect(),
+            drop_particles: false,
+        }
+    }
+
+    /// Replace the particle list, particles ending with an apostrophe are matched as a token prefix
+    pub fn particles(mut self, particles: &[&str]) -> PhraseEncoder<E> {
+        self.particles = particles.iter().map(|p| p.to_lowercase()).collect();
+        self
+    }
+
+    /// Drop the particles from the result, unless the phrase is made of particles only
+    pub fn drop_particles(mut self, drop_particles: bool) -> PhraseEncoder<E> {
+        self.drop_particles = drop_particles;
+        self
+    }
+
+    /// Encode each token of a phrase, in order
+    pub fn encode(&self, phrase: &str) -> Vec<TokenCode> {
+        let mut codes = Vec::new();
+
+        for (start, end) in self.spans(phrase) {
+            let token = &phrase[start..end];
+            let word = token.chars().filter(|c| !is_apostrophe(*c)).collect::<String>();
+
+            codes.push(TokenCode {
+                token: token.to_owned(),
+                start,
+                end,
+                code: self.encoder.encode(&word),
+                is_particle: self.is_particle(token),
+            });
+        }
+
+        if self.drop_particles && codes.iter().any(|c| !c.is_particle) {
+            codes.retain(|c| !c.is_particle);
+        }
+
+        codes
+    }
+
+    /// Codes of the phrase, one per token, particles excluded when they are dropped
+    pub fn codes(&self, phrase: &str) -> Vec<PhoneticCode> {
+        self.encode(phrase).into_iter().map(|c| c.code).collect()
+    }
+
+    fn is_particle(&self, token: &str) -> bool {
+        let token = token.to_lowercase();
+        self.particles.contains(&token)
+    }
+
+    /// Length of the elided particle starting the token, if any
+    fn elided_particle(&self, token: &str) -> Option<usize> {
+        let lower = token.to_lowercase();
+        self.particles
+            .iter()
+            .filter(|p| p.ends_with(is_apostrophe) && lower.len() > p.len() && lower.starts_with(&p[..]))
+            .map(|p| p.len())
+            .next()
+            .filter(|len| token.is_char_boundary(*len))
+    }
+
+    /// Byte spans of the tokens of a phrase
+    fn spans(&self, phrase: &str) -> Vec<(usize, usize)> {
+        let mut spans = Vec::new();
+        let mut start = None;
+
+        for (i, c) in phrase.char_indices().chain(Some((phrase.len(), ' '))) {
+            match (start, is_token_char(c)) {
+                (None, true) => start = Some(i),
+                (Some(s), false) => {
+                    start = None;
+                    let token = phrase[s..i].trim_matches(is_apostrophe);
+                    if token.is_empty() {
+                        continue;
+                    }
+                    let s = s + phrase[s..i].find(token).unwrap_or(0);
+                    let e = s + token.len();
+
+                    match self.elided_particle(token) {
+                        Some(len) => {
+                            spans.push((s, s + len));
+                            spans.push((s + len, e));
+                        },
+                        None => spans.push((s, e)),
+                    }
+                },
+                _ => (),
+            }
+        }
+
+        spans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use phonetics::soundex::Soundex;
+    use phonetics::metaphone::double_metaphone::DoubleMetaphone;
+    use phonetics::encoder::encoder_by_name;
+
+    fn tokens<E: PhoneticEncoder>(encoder: &PhraseEncoder<E>, phrase: &str) -> Vec<String> {
+        encoder.encode(phrase).into_iter().map(|c| c.token).collect()
+    }
+
+    #[test]
+    fn phrase_tokens() {
+        let encoder = PhraseEncoder::new(Soundex::new());
+        assert_eq!(vec!["Mary", "Ann", "O'Brien", "Smith"], tokens(&encoder, "Mary Ann O'Brien-Smith"));
+        assert_eq!(vec!["Jean", "Luc"], tokens(&encoder, "  Jean--Luc,  "));
+        assert_eq!(vec!["rock", "n", "roll"], tokens(&encoder, "rock 'n' roll"));
+        assert!(tokens(&encoder, " - ").is_empty());
+    }
+
+    #[test]
+    fn phrase_spans() {
+        let phrase = "Zoë d'Artagnan";
+        let codes = PhraseEncoder::new(Soundex::new()).encode(phrase);
+        assert_eq!(3, codes.len());
+        for code in &codes {
+            assert_eq!(code.token, &phrase[code.start..code.end]);
+        }
+        assert_eq!("d'", codes[1].token);
+        assert!(codes[1].is_particle);
+        assert_eq!(Some("A632"), codes[2].code.primary());
+    }
+
+    #[test]
+    fn phrase_particles() {
+        let encoder = PhraseEncoder::new(Soundex::new()).drop_particles(true);
+        assert_eq!(vec!["Beethoven"], tokens(&encoder, "van Beethoven"));
+        assert_eq!(vec!["Rashid"], tokens(&encoder, "al-Rashid"));
+        assert_eq!(vec!["Artagnan"], tokens(&encoder, "D'Artagnan"));
+        assert_eq!(vec!["De", "La"], tokens(&encoder, "De La"));
+        assert_eq!(vec!["O'Brien"], tokens(&encoder, "O'Brien"));
+    }
+
+    #[test]
+    fn phrase_custom_particles() {
+        let encoder = PhraseEncoder::new(Soundex::new()).particles(&["Mac"]).drop_particles(true);
+        assert_eq!(vec!["Gregor"], tokens(&encoder, "Mac Gregor"));
+        assert_eq!(vec!["van", "Dyke"], tokens(&encoder, "van Dyke"));
+    }
+
+    #[test]
+    fn phrase_codes() {
+        let encoder = PhraseEncoder::new(DoubleMetaphone::new());
+        let codes = encoder.codes("Smith-Schmidt");
+        assert_eq!(2, codes.len());
+        assert!(codes[0].matches(&codes[1]));
+
+        let encoder = PhraseEncoder::new(encoder_by_name("metaphone").unwrap());
+        let codes = encoder.codes("Mary O'Brien");
+        assert_eq!(Some("MR"), codes[0].primary());
+        assert_eq!(Some("OBRN"), codes[1].primary());
+    }
+}
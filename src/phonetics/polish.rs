@@ -0,0 +1,91 @@
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+use phonetics::utils::rules::{fold, scan_sounds};
+
+/// Vowels, before which `i` only softens the consonant before it
+const VOWELS: &str = "AEIOUYĄĘÓ";
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Polish phonetic encoder, a key of the Polish sounds of a word
+pub struct Polish;
+
+impl Polish {
+    /// Polish key of a word
+    ///
+    /// The hard and soft sibilants are merged: `sz`, `ś` and `si` are coded `X`, `cz`, `ć` and
+    /// `ci` are coded `C`, `rz`, `ż`, `ź`, `zi`, `dż`, `dź` and `dzi` are coded `J`. `ł` and `ó`
+    /// are coded `U`, `w` is coded `V`, `ch` and `h` are coded `H`, `ń` and `ni` are coded `N`,
+    /// `y` is coded `I`, and the nasal `ą` and `ę` are coded `ON` and `EN`.
+    pub fn polish(&self, word: &str) -> String {
+        let chars = fold(word, "ĄĆĘŁŃÓŚŹŻ").chars().collect::<Vec<_>>();
+        let at = |i: usize| chars.get(i).cloned();
+        // Whether the `i` after a soft consonant is followed by a vowel, it is then not a sound
+        let soft_i = |i: usize| at(i) == Some('I') && VOWELS.contains(at(i + 1).unwrap_or('_'));
+
+        scan_sounds(&chars, |i| match (chars[i], at(i + 1)) {
+            ('D', Some('Ż')) | ('D', Some('Ź')) => Some(("J", 2)),
+            ('D', Some('Z')) if at(i + 2) == Some('I') => Some(("J", if soft_i(i + 2) { 3 } else { 2 })),
+            ('D', Some('Z'))                    => Some(("Z", 2)),
+            ('S', Some('Z'))                    => Some(("X", 2)),
+            ('C', Some('Z'))                    => Some(("C", 2)),
+            ('R', Some('Z'))                    => Some(("J", 2)),
+            ('C', Some('H'))                    => Some(("H", 2)),
+            ('S', Some('I'))                    => Some(("X", if soft_i(i + 1) { 2 } else { 1 })),
+            ('C', Some('I'))                    => Some(("C", if soft_i(i + 1) { 2 } else { 1 })),
+            ('Z', Some('I'))                    => Some(("J", if soft_i(i + 1) { 2 } else { 1 })),
+            ('N', Some('I'))                    => Some(("N", if soft_i(i + 1) { 2 } else { 1 })),
+            ('Ż', _) | ('Ź', _)                 => Some(("J", 1)),
+            ('Ś', _)                            => Some(("X", 1)),
+            ('Ć', _)                            => Some(("C", 1)),
+            ('C', _) | ('Q', _)                 => Some(("K", 1)),
+            ('Ł', _) | ('Ó', _)                 => Some(("U", 1)),
+            ('W', _)                            => Some(("V", 1)),
+            ('Ń', _)                            => Some(("N", 1)),
+            ('Ą', _)                            => Some(("ON", 1)),
+            ('Ę', _)                            => Some(("EN", 1)),
+            ('Y', _)                            => Some(("I", 1)),
+            ('X', _)                            => Some(("KS", 1)),
+            _                                   => None,
+        })
+    }
+}
+
+/// Polish key of a word
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::polish::polish;
+///
+/// assert_eq!("JEH", polish("Rzech"));
+/// assert_eq!(polish("Rzepka"), polish("Żepka"));
+/// assert_eq!(polish("Szymański"), polish("Śimanski"));
+/// assert_eq!(polish("Michał"), polish("Mihau"));
+/// ```
+pub fn polish(word: &str) -> String {
+    Polish.polish(word)
+}
+
+impl PhoneticEncoder for Polish {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        PhoneticCode::new(self.polish(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polish_keys() {
+        assert_eq!("XIMANSKI", polish("Szymański"));
+        assert_eq!("VUJCIK", polish("Wójcik"));
+        assert_eq!(polish("Wójcik"), polish("Vujczik"));
+        assert_eq!(polish("Kowalczyk"), polish("Kovalczik"));
+        assert_eq!(polish("Dziedzic"), polish("Dżedżic"));
+        assert_eq!(polish("Sienkiewicz"), polish("Śenkievicz"));
+        assert_eq!(polish("Wąsowski"), polish("Wonsowski"));
+        assert_eq!(polish("Łukasz"), polish("Ukasz"));
+        assert_eq!(polish("Hrabia"), polish("Chrabia"));
+        assert_eq!("", polish(""));
+    }
+}
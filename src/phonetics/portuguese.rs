@@ -0,0 +1,140 @@
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+use phonetics::utils::rules::{after, before, fold, is_one_of, replace_if, squeeze, LETTERS};
+
+const VOWELS: &str = "AEIOU";
+
+/// Groups of letters replaced by their sound, in order
+static GROUPS: &[(&str, &str)] = &[
+    ("BL", "B"), ("BR", "B"), ("PH", "F"),
+    ("GL", "G"), ("GR", "G"), ("MG", "G"), ("NG", "G"), ("RG", "G"),
+    ("Y", "I"),
+    ("GE", "J"), ("GI", "J"), ("RJ", "J"), ("MJ", "J"),
+    ("NH", "N"), ("LH", "L"),
+    ("CA", "K"), ("CO", "K"), ("CU", "K"), ("CK", "K"), ("Q", "K"),
+    ("CE", "S"), ("CI", "S"), ("CH", "S"), ("CT", "T"), ("Ç", "S"), ("C", "K"),
+    ("PR", "P"),
+    ("TS", "S"), ("X", "S"), ("Z", "S"), ("RS", "S"),
+    ("TR", "T"), ("TL", "T"), ("LT", "T"), ("RT", "T"), ("ST", "T"),
+    ("W", "V"),
+];
+
+/// Silent or weak endings, at most one is removed
+static ENDINGS: &[&str] = &["AO", "S", "R", "M", "N", "L"];
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Portuguese pronunciation used to encode the words
+pub enum PortugueseRegion {
+    /// Brazilian Portuguese: `l` before a consonant sounds like `u` ("Silva"), `di` and `ti` are
+    /// palatalized and sound like `ji` and `chi`
+    Brazil,
+    /// European Portuguese
+    Portugal,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// BuscaBR phonetic encoder
+pub struct BuscaBr {
+    region: PortugueseRegion,
+}
+
+impl Default for BuscaBr {
+    fn default() -> BuscaBr {
+        BuscaBr::new(PortugueseRegion::Brazil)
+    }
+}
+
+impl BuscaBr {
+    /// BuscaBR with the pronunciation of a region
+    pub fn new(region: PortugueseRegion) -> BuscaBr {
+        BuscaBr { region }
+    }
+
+    /// BuscaBR code of a Portuguese word
+    ///
+    /// The code is the first letter of the word followed by its consonant sounds, once `ç`, `nh`,
+    /// `lh`, `ch`, soft `c` and `g` and the consonant clusters are simplified and the weak
+    /// endings (`-s`, `-r`, `-m`, `-n`, `-l`, `-ão`) removed.
+    pub fn buscabr(&self, word: &str) -> String {
+        let mut w = fold(word, "Ç");
+
+        // H is silent, except in ch, lh, nh and ph
+        w = replace_if(&w, "H", "", |w, s, _| !is_one_of(before(w, s), "CLNP"));
+
+        if self.region == PortugueseRegion::Brazil {
+            w = replace_if(&w, "L", "U", |w, _, e| is_one_of(after(w, e), "BCDFGJKMNPQRSTVWXZÇ"));
+            w = w.replace("DI", "JI").replace("TI", "CHI");
+        }
+
+        for &(src, tar) in GROUPS {
+            w = w.replace(src, tar);
+        }
+
+        for ending in ENDINGS {
+            if w.len() > ending.len() && w.ends_with(ending) {
+                w.truncate(w.len() - ending.len());
+                break;
+            }
+        }
+
+        // Vowels are dropped, except the first letter
+        let code = w
+            .chars()
+            .enumerate()
+            .filter(|&(i, c)| i == 0 || !VOWELS.contains(c))
+            .map(|(_, c)| c)
+            .collect::<String>();
+
+        squeeze(&code, LETTERS)
+    }
+}
+
+/// BuscaBR code of a word, with the Brazilian pronunciation
+///
+/// BuscaBR is a phonetic code for Portuguese names, made of the first letter and the consonant
+/// sounds of the word.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::portuguese::buscabr;
+///
+/// assert_eq!("GNSV", buscabr("Gonçalves"));
+/// assert_eq!(buscabr("Souza"), buscabr("Sousa"));
+/// assert_eq!(buscabr("Thiago"), buscabr("Tiago"));
+/// ```
+pub fn buscabr(word: &str) -> String {
+    BuscaBr::default().buscabr(word)
+}
+
+impl PhoneticEncoder for BuscaBr {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        PhoneticCode::new(self.buscabr(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buscabr_codes() {
+        assert_eq!("KNS", buscabr("Conceição"));
+        assert_eq!(buscabr("Carvalho"), buscabr("Carvalio"));
+        assert_eq!(buscabr("Magalhães"), buscabr("Magalaes"));
+        assert_eq!(buscabr("Nascimento"), buscabr("Nasimento"));
+        assert_eq!(buscabr("Assunção"), buscabr("Asunsão"));
+        assert_eq!(buscabr("Cunha"), buscabr("Cuna"));
+        assert_eq!(buscabr("Queiroz"), buscabr("Keiros"));
+        assert_eq!(buscabr("Heitor"), buscabr("Eitor"));
+        assert_eq!("", buscabr(""));
+    }
+
+    #[test]
+    fn buscabr_regions() {
+        let portugal = BuscaBr::new(PortugueseRegion::Portugal);
+        assert_eq!("SV", buscabr("Silva"));
+        assert_eq!("SLV", portugal.buscabr("Silva"));
+        assert_eq!(buscabr("Diniz"), buscabr("Jiniz"));
+        assert_ne!(portugal.buscabr("Diniz"), portugal.buscabr("Jiniz"));
+    }
+}
@@ -1,4 +1,5 @@
 use itertools::Itertools;
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
 
 #[derive(PartialEq, Hash, Clone, Copy, Debug)]
 /// Soundex char mapping
@@ -23,8 +24,23 @@ pub enum SoundexChar {
     Space,
 }
 
+impl SoundexChar {
+    /// Soundex digit of the char class, `None` for vowels, H, W and spaces
+    pub fn digit(self) -> Option<char> {
+        match self {
+            SoundexChar::S1 => Some('1'),
+            SoundexChar::S2 => Some('2'),
+            SoundexChar::S3 => Some('3'),
+            SoundexChar::S4 => Some('4'),
+            SoundexChar::S5 => Some('5'),
+            SoundexChar::S6 => Some('6'),
+            _               => None,
+        }
+    }
+}
+
 /// Encode a normal char into a soundex char
-fn encode(ch: char) -> SoundexChar {
+pub(crate) fn encode(ch: char) -> SoundexChar {
     if let Some(c) = ch.to_lowercase().next() {
         match c {
             'b' | 'f' | 'p' | 'v'                         => SoundexChar::S1,
@@ -42,6 +58,47 @@ fn encode(ch: char) -> SoundexChar {
     }
 }
 
+#[derive(PartialEq, Hash, Clone, Copy, Debug)]
+/// Soundex flavour used by the `Soundex` builder
+pub enum SoundexVariant {
+    /// Official American Soundex (commons-codec `Soundex`): H, W and punctuation do not separate
+    /// consonants with the same code, vowels and spaces do
+    American,
+    /// Legacy simplified Soundex (PostgreSQL fuzzystrmatch `soundex()`): H, W and every non-letter
+    /// separate consonants exactly like vowels
+    Simplified,
+    /// SQL Server `SOUNDEX()`: American rules, but coding stops at the first non-letter
+    SqlServer,
+    /// Refined Soundex: finer letter classes, vowels are coded, no length limit by default
+    Refined,
+    /// Census "special" coding: American rules, plus a second code for names with a prefix
+    /// (Van, Von, Con, De, Di, Du, La, Le) followed by a separator or a capital ("Van Dyke", "VanDyke")
+    Special,
+}
+
+static SPECIAL_PREFIXES: &[&str] = &["VANDER", "VAN", "VON", "CON", "DELA", "DE", "DI", "DU", "LA", "LE"];
+
+/// Encode a normal char into a refined soundex digit
+fn encode_refined(ch: char) -> Option<char> {
+    if let Some(c) = ch.to_lowercase().next() {
+        match c {
+            'a' | 'e' | 'h' | 'i' | 'o' | 'u' | 'w' | 'y' => Some('0'),
+            'b' | 'p'                                     => Some('1'),
+            'f' | 'v'                                     => Some('2'),
+            'c' | 'k' | 's'                               => Some('3'),
+            'g' | 'j'                                     => Some('4'),
+            'q' | 'x' | 'z'                               => Some('5'),
+            'd' | 't'                                     => Some('6'),
+            'l'                                           => Some('7'),
+            'm' | 'n'                                     => Some('8'),
+            'r'                                           => Some('9'),
+            _                                             => None,
+        }
+    } else {
+        None
+    }
+}
+
 #[derive(PartialEq, Hash, Clone, Debug)]
 /// A soundex word
 pub struct SoundexWord {
@@ -51,19 +108,35 @@ pub struct SoundexWord {
 
 impl SoundexWord {
     /// Generate a soundex word base on a string
-    fn new(word: &str) -> SoundexWord {
-        let first_char = word
-            .chars().clone().nth(0)
-            .unwrap_or('_');
-
-        let soundex_chars = word
-            .chars()
-            .map(encode)
-            .skip(1)
-            .filter(|x| *x != SoundexChar::HW)
-            .dedup()
-            .filter(|x| *x != SoundexChar::Vowel)
-            .collect::<Vec<_>>();
+    ///
+    /// The word starts with a letter. The first letter takes part in the adjacency rule, so
+    /// "Pfister" gives P236, not P123.
+    fn new(word: &str, variant: SoundexVariant) -> SoundexWord {
+        let mut chars = word.chars();
+        let first_char = chars.next().unwrap_or('_');
+
+        let mut soundex_chars = Vec::new();
+        let mut last = encode(first_char);
+
+        for ch in chars {
+            if variant == SoundexVariant::SqlServer && !ch.is_alphabetic() {
+                break;
+            }
+            if !ch.is_alphabetic() && ch != ' ' && variant != SoundexVariant::Simplified {
+                continue;
+            }
+
+            let current = match encode(ch) {
+                SoundexChar::HW if variant == SoundexVariant::Simplified => SoundexChar::Vowel,
+                SoundexChar::HW => continue,
+                c => c,
+            };
+
+            if current != last && current != SoundexChar::Vowel && current != SoundexChar::Space {
+                soundex_chars.push(current);
+            }
+            last = current;
+        }
 
         SoundexWord {
             first_char: first_char,
@@ -89,28 +162,189 @@ impl SoundexWord {
     }
 }
 
-/// Try soundex
+/// Refined soundex representation of a word: every letter is coded, only adjacent duplicates
+/// are collapsed.
+fn refined_soundex(word: &str) -> String {
+    let mut code = String::new();
+    let mut last = None;
+
+    if let Some(first_char) = word.chars().next() {
+        code.push(first_char);
+    }
+
+    for ch in word.chars() {
+        let current = encode_refined(ch);
+        if current == last {
+            continue;
+        }
+        if let Some(c) = current {
+            code.push(c);
+        }
+        last = current;
+    }
+
+    code
+}
+
+#[derive(PartialEq, Hash, Clone, Copy, Debug)]
+/// Configurable soundex encoder
 ///
 /// Examples:
 ///
 /// ```
-/// use nlp::phonetics::soundex::soundex;
-/// assert_eq!("S550", soundex("SIMON "));
+/// use nlp::phonetics::soundex::{Soundex, SoundexVariant};
+///
+/// assert_eq!("A261", Soundex::new().soundex("Ashcraft"));
+/// assert_eq!("A226", Soundex::new().variant(SoundexVariant::Simplified).soundex("Ashcraft"));
+/// assert_eq!("S530", Soundex::new().variant(SoundexVariant::SqlServer).soundex("Smith Jones"));
+/// assert_eq!("T6036084", Soundex::refined().soundex("testing"));
+/// assert_eq!("R15000", Soundex::new().length(6).soundex("Rubin"));
+/// assert_eq!("R15", Soundex::new().length(0).soundex("Rubin"));
+/// assert_eq!(vec!["V532", "D200"], Soundex::new().variant(SoundexVariant::Special).encode_all("Van Dyke"));
 /// ```
-pub fn soundex(a: &str) -> String {
-    let soundex_a = SoundexWord::new(a).to_string();
+pub struct Soundex {
+    variant: SoundexVariant,
+    length: usize,
+    padding: bool,
+}
+
+impl Default for Soundex {
+    fn default() -> Soundex {
+        Soundex::new()
+    }
+}
 
-    let mut s = String::new();
-    s.push_str(&soundex_a[..]);
+impl Soundex {
+    /// American soundex, 4 chars long, padded with zeros
+    pub fn new() -> Soundex {
+        Soundex {
+            variant: SoundexVariant::American,
+            length: 4,
+            padding: true,
+        }
+    }
 
-    if s.len() < 4 {
-        for _ in (0..(4 - s.len())).enumerate() {
-            s.push('0');
+    /// Refined soundex, unlimited length, no padding
+    pub fn refined() -> Soundex {
+        Soundex {
+            variant: SoundexVariant::Refined,
+            length: 0,
+            padding: false,
         }
     }
 
-    s.truncate(4);
-    s.to_uppercase()
+    /// Select the soundex variant
+    pub fn variant(mut self, variant: SoundexVariant) -> Soundex {
+        self.variant = variant;
+        self
+    }
+
+    /// Set the maximum code length (first letter included), `0` means unlimited
+    pub fn length(mut self, length: usize) -> Soundex {
+        self.length = length;
+        self
+    }
+
+    /// Pad codes shorter than the code length with zeros
+    pub fn padding(mut self, padding: bool) -> Soundex {
+        self.padding = padding;
+        self
+    }
+
+    /// Soundex code of a word
+    ///
+    /// The code starts at the first letter of the word, a word without letters has an empty code.
+    pub fn soundex(&self, word: &str) -> String {
+        let letters = word.trim_start_matches(|c: char| !c.is_alphabetic());
+        if letters.is_empty() {
+            return String::new();
+        }
+
+        let code = match self.variant {
+            SoundexVariant::Refined => refined_soundex(letters),
+            _                       => SoundexWord::new(letters, self.variant).to_string(),
+        };
+
+        let mut s = code.to_uppercase();
+        let size = s.chars().count();
+
+        if self.length > 0 {
+            if self.padding && size < self.length {
+                for _ in size..self.length {
+                    s.push('0');
+                }
+            }
+            if let Some((index, _)) = s.char_indices().nth(self.length) {
+                s.truncate(index);
+            }
+        }
+
+        s
+    }
+
+    /// Encode a word, returning every code the variant defines for it
+    ///
+    /// Only the `Special` variant can return more than one code: the code of the full name,
+    /// followed by the code of the name without its prefix.
+    pub fn encode_all(&self, word: &str) -> Vec<String> {
+        let mut codes = vec![self.soundex(word)];
+
+        if self.variant == SoundexVariant::Special {
+            if let Some(stripped) = strip_prefix(word) {
+                let code = self.soundex(stripped);
+                if !codes.contains(&code) {
+                    codes.push(code);
+                }
+            }
+        }
+
+        codes
+    }
+}
+
+impl PhoneticEncoder for Soundex {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        if !word.chars().any(char::is_alphabetic) {
+            return PhoneticCode::default();
+        }
+        PhoneticCode::with_alternates(self.encode_all(word))
+    }
+}
+
+/// Remove a census prefix (and the separators following it) from a name
+///
+/// The prefix must be followed by a separator ("Van Dyke", "De-La Cruz") or, in a mixed case name,
+/// by an uppercase letter ("VanDyke"), so that "Dennis" or "Lee" are left alone. What remains must
+/// have at least two letters.
+fn strip_prefix(word: &str) -> Option<&str> {
+    let word = word.trim();
+    let mixed_case = word.chars().any(char::is_lowercase) && word.chars().any(char::is_uppercase);
+
+    SPECIAL_PREFIXES
+        .iter()
+        .filter_map(|prefix| match word.get(..prefix.len()) {
+            Some(head) if head.eq_ignore_ascii_case(prefix) => Some(&word[prefix.len()..]),
+            _ => None,
+        })
+        .find(|rest| match rest.chars().next() {
+            Some(c) if c.is_alphabetic() => mixed_case && c.is_uppercase(),
+            Some(_)                      => true,
+            None                         => false,
+        })
+        .map(|rest| rest.trim_start_matches(|c: char| !c.is_alphabetic()))
+        .filter(|rest| rest.chars().filter(|c| c.is_alphabetic()).count() >= 2)
+}
+
+/// Try soundex
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::soundex::soundex;
+/// assert_eq!("S550", soundex("SIMON "));
+/// ```
+pub fn soundex(a: &str) -> String {
+    Soundex::new().soundex(a)
 }
 
 /// Compare soundex words
@@ -160,6 +394,84 @@ mod tests {
         assert_eq!("P300", soundex("putée"))
     }
 
+    #[test]
+    fn soundex_first_letter_adjacency() {
+        assert_eq!("P236", soundex("Pfister"));
+        assert_eq!("T522", soundex("Tymczak"));
+        assert_eq!("J250", soundex("Jackson"));
+    }
+
+    #[test]
+    fn soundex_hw_separator() {
+        assert_eq!("A261", soundex("Ashcraft"));
+        assert_eq!("A226", Soundex::new().variant(SoundexVariant::Simplified).soundex("Ashcraft"));
+    }
+
+    #[test]
+    fn soundex_leading_non_letters() {
+        assert_eq!("S530", soundex(" Smith"));
+        assert_eq!("A120", soundex("123abc"));
+        assert_eq!("", soundex(" "));
+        assert_eq!("", Soundex::refined().soundex("42"));
+    }
+
+    #[test]
+    fn soundex_sql_server() {
+        let sql_server = Soundex::new().variant(SoundexVariant::SqlServer);
+        assert_eq!("S530", sql_server.soundex("Smith Jones"));
+        assert_eq!("S530", sql_server.soundex("  Smith"));
+        assert_eq!("O000", sql_server.soundex("O'Brien"));
+        assert_eq!("S530", sql_server.soundex("-Smith"));
+        assert_eq!("", sql_server.soundex(""));
+        assert_eq!("", sql_server.soundex("   "));
+        assert_eq!("", sql_server.soundex("42"));
+    }
+
+    #[test]
+    fn soundex_refined() {
+        let refined = Soundex::refined();
+        assert_eq!("T6036084", refined.soundex("testing"));
+        assert_eq!("T60", refined.soundex("The"));
+        assert_eq!("Q503", refined.soundex("quick"));
+        assert_eq!("B1908", refined.soundex("brown"));
+        assert_eq!("F205", refined.soundex("fox"));
+        assert_eq!("J408106", refined.soundex("jumped"));
+        assert_eq!("O0209", refined.soundex("over"));
+        assert_eq!("L7050", refined.soundex("lazy"));
+        assert_eq!("D6043", refined.soundex("dogs"));
+        assert_eq!("T603", refined.length(4).soundex("testing"));
+    }
+
+    #[test]
+    fn soundex_length_and_padding() {
+        assert_eq!("S5", Soundex::new().length(2).soundex("Simon"));
+        assert_eq!("S55", Soundex::new().length(0).soundex("Simon"));
+        assert_eq!("S55", Soundex::new().padding(false).soundex("Simon"));
+        assert_eq!("W25235", Soundex::new().length(0).soundex("Washington"));
+        assert_eq!("W252", Soundex::new().length(4).soundex("Washington"));
+    }
+
+    #[test]
+    fn soundex_special_prefixes() {
+        let special = Soundex::new().variant(SoundexVariant::Special);
+        assert_eq!(vec!["V532"], Soundex::new().encode_all("Van Dyke"));
+        assert_eq!(vec!["V532", "D200"], special.encode_all("Van Dyke"));
+        assert_eq!(vec!["V532", "D200"], special.encode_all("VanDyke"));
+        assert_eq!(vec!["D462", "R200"], special.encode_all("DeLaRosa"));
+        assert_eq!(vec!["S530"], special.encode_all("Smith"));
+        assert_eq!("V532", special.soundex("Van Dyke"));
+        assert_eq!(vec!["D426", "L262"], special.encode_all("De-La Cruz"));
+        assert_eq!(vec!["D120", "B200"], special.encode_all("DuBois"));
+    }
+
+    #[test]
+    fn soundex_special_no_prefix() {
+        let special = Soundex::new().variant(SoundexVariant::Special);
+        for name in &["Dennis", "Lee", "Lane", "Dixon", "Conrad", "Dubois", "DENNIS", "LEE", "Le", "Van", "De A"] {
+            assert_eq!(vec![Soundex::new().soundex(name)], special.encode_all(name), "codes of {:?}", name);
+        }
+    }
+
     #[test]
     fn soundex_6() {
         assert!(compare_soundex_words("putée", "putée"))
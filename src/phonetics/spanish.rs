@@ -0,0 +1,237 @@
+use phonetics::encoder::{PhoneticCode, PhoneticEncoder};
+use phonetics::utils::rules::{fold, squeeze, LETTERS};
+
+const SOUNDEX_LEN: usize = 4;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Spanish pronunciation used to encode the words
+pub enum SpanishRegion {
+    /// Latin American Spanish: seseo (`c` before `e`/`i`, `z` and `s` sound alike) and yeísmo
+    /// (`ll` and `y` sound alike)
+    LatinAmerica,
+    /// Spanish of Spain: distinción between `z` and `s`, but yeísmo
+    Spain,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Fonético phonetic encoder, a key of the Spanish sounds of a word
+pub struct Fonetico {
+    seseo: bool,
+    yeismo: bool,
+}
+
+impl Default for Fonetico {
+    fn default() -> Fonetico {
+        Fonetico::new(SpanishRegion::LatinAmerica)
+    }
+}
+
+impl Fonetico {
+    /// Fonético with the pronunciation of a region
+    pub fn new(region: SpanishRegion) -> Fonetico {
+        Fonetico {
+            seseo: region == SpanishRegion::LatinAmerica,
+            yeismo: true,
+        }
+    }
+
+    /// Whether `c` before `e`/`i` and `z` sound like `s` (coded `S`), or like "th" (coded `Z`)
+    pub fn seseo(mut self, seseo: bool) -> Fonetico {
+        self.seseo = seseo;
+        self
+    }
+
+    /// Whether `ll` sounds like `y` (coded `Y`), or is a distinct sound (coded `LY`)
+    pub fn yeismo(mut self, yeismo: bool) -> Fonetico {
+        self.yeismo = yeismo;
+        self
+    }
+
+    /// Fonético key of a Spanish word
+    ///
+    /// The key is written with the vowels `A E I O U` and the consonants `B D F G J K L M N P R S T
+    /// Y Z`, `C` being the sound of `ch`; repeated sounds are written once.
+    pub fn fonetico(&self, word: &str) -> String {
+        let soft_c = if self.seseo { "S" } else { "Z" };
+        let chars = fold(word, "ÑÜ").chars().collect::<Vec<_>>();
+        let at = |i: usize| chars.get(i).cloned();
+        let is_vowel = |i: usize| "AEIOUÜ".contains(at(i).unwrap_or('_'));
+
+        let mut key = String::with_capacity(chars.len());
+        let mut i = 0;
+        while i < chars.len() {
+            let next = at(i + 1);
+            let (sound, len) = match chars[i] {
+                c @ 'A' | c @ 'E' | c @ 'I' | c @ 'O' | c @ 'U' => (c.to_string(), 1),
+                'Ü'                                       => ("U".to_owned(), 1),
+                'B' | 'V' | 'W'                           => ("B".to_owned(), 1),
+                'C' if next == Some('H')                  => ("C".to_owned(), 2),
+                'C' if next == Some('E') || next == Some('I') => (soft_c.to_owned(), 1),
+                'G' if next == Some('E') || next == Some('I') => ("J".to_owned(), 1),
+                // Silent u of gue, gui, que and qui
+                'G' | 'Q' if next == Some('U') && (at(i + 2) == Some('E') || at(i + 2) == Some('I')) => {
+                    (if chars[i] == 'G' { "G" } else { "K" }.to_owned(), 2)
+                },
+                'C' | 'K' | 'Q'                           => ("K".to_owned(), 1),
+                'H'                                       => (String::new(), 1),
+                'L' if next == Some('L')                  => (if self.yeismo { "Y" } else { "LY" }.to_owned(), 2),
+                'N' if "BVPM".contains(next.unwrap_or('_')) => ("M".to_owned(), 1),
+                'Ñ'                                       => ("NY".to_owned(), 1),
+                'P' if i == 0 && next == Some('S')        => (String::new(), 1),
+                'X' if i == 0                             => ("S".to_owned(), 1),
+                'X'                                       => ("KS".to_owned(), 1),
+                'Y' if is_vowel(i + 1)                    => ("Y".to_owned(), 1),
+                'Y'                                       => ("I".to_owned(), 1),
+                'Z'                                       => (soft_c.to_owned(), 1),
+                c                                         => (c.to_string(), 1),
+            };
+            key.push_str(&sound);
+            i += len;
+        }
+
+        squeeze(&key, LETTERS)
+    }
+
+    /// Spanish soundex code of a word, the first sound and 3 digits, padded with zeros
+    ///
+    /// The digits code the consonants of the fonético key: `1` B F P, `2` G J K, `3` D T, `4` L Y,
+    /// `5` M N, `6` R, `7` S Z and `8` CH. Vowels are not coded but separate the consonants.
+    pub fn soundex(&self, word: &str) -> String {
+        let key = self.fonetico(word);
+        let mut chars = key.chars();
+        let first = match chars.next() {
+            Some(c) => c,
+            None => return String::new(),
+        };
+
+        let mut code = first.to_string();
+        let mut last = digit(first);
+        for c in chars {
+            let d = digit(c);
+            if let Some(d) = d {
+                if Some(d) != last {
+                    code.push(d);
+                }
+            }
+            last = d;
+        }
+
+        code.truncate(SOUNDEX_LEN);
+        while code.len() < SOUNDEX_LEN {
+            code.push('0');
+        }
+
+        code
+    }
+}
+
+/// Digit of a sound of the key in the Spanish soundex
+fn digit(c: char) -> Option<char> {
+    match c {
+        'B' | 'F' | 'P'        => Some('1'),
+        'G' | 'J' | 'K'        => Some('2'),
+        'D' | 'T'              => Some('3'),
+        'L' | 'Y'              => Some('4'),
+        'M' | 'N'              => Some('5'),
+        'R'                    => Some('6'),
+        'S' | 'Z'              => Some('7'),
+        'C'                    => Some('8'),
+        _                      => None,
+    }
+}
+
+/// Fonético key of a word, with the Latin American pronunciation
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::spanish::fonetico;
+///
+/// assert_eq!("BAYEJO", fonetico("Vallejo"));
+/// assert_eq!(fonetico("Vallejo"), fonetico("Bayejo"));
+/// assert_eq!(fonetico("Zárate"), fonetico("Sarate"));
+/// assert_eq!(fonetico("Hernández"), fonetico("Ernandes"));
+/// ```
+pub fn fonetico(word: &str) -> String {
+    Fonetico::default().fonetico(word)
+}
+
+/// Spanish soundex code of a word, with the Latin American pronunciation
+///
+/// Examples:
+///
+/// ```
+/// use nlp::phonetics::spanish::soundex_es;
+///
+/// assert_eq!("G574", soundex_es("González"));
+/// assert_eq!(soundex_es("González"), soundex_es("Gonsales"));
+/// ```
+pub fn soundex_es(word: &str) -> String {
+    Fonetico::default().soundex(word)
+}
+
+impl PhoneticEncoder for Fonetico {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        PhoneticCode::new(self.fonetico(word))
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Spanish soundex phonetic encoder
+pub struct SoundexEs {
+    fonetico: Fonetico,
+}
+
+impl SoundexEs {
+    /// Spanish soundex with the pronunciation of a region
+    pub fn new(region: SpanishRegion) -> SoundexEs {
+        SoundexEs { fonetico: Fonetico::new(region) }
+    }
+}
+
+impl PhoneticEncoder for SoundexEs {
+    fn encode(&self, word: &str) -> PhoneticCode {
+        PhoneticCode::new(self.fonetico.soundex(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fonetico_codes() {
+        assert_eq!("CABES", fonetico("Chávez"));
+        assert_eq!("KESADA", fonetico("Quesada"));
+        assert_eq!("GERA", fonetico("Guerra"));
+        assert_eq!("ARGUEYES", fonetico("Argüelles"));
+        assert_eq!("NYONYO", fonetico("Ñoño"));
+        assert_eq!("MUNYOS", fonetico("Muñoz"));
+        assert_eq!("REI", fonetico("Rey"));
+        assert_eq!("JIMENES", fonetico("Gimenez"));
+        assert_eq!("JIMENES", fonetico("Jiménez"));
+        assert_eq!("SIKOLOJIA", fonetico("psicología"));
+        assert_eq!("SIMENA", fonetico("Ximena"));
+        assert_eq!("AKSEL", fonetico("Axel"));
+        assert_eq!("AMBIERNO", fonetico("Anvierno"));
+        assert_eq!("", fonetico("--"));
+    }
+
+    #[test]
+    fn fonetico_spain() {
+        let spain = Fonetico::new(SpanishRegion::Spain);
+        assert_eq!("ZARATE", spain.fonetico("Zárate"));
+        assert_eq!("ZEZILIA", spain.fonetico("Cecilia"));
+        assert_ne!(spain.fonetico("Zárate"), spain.fonetico("Sarate"));
+        assert_eq!("BALYEJO", spain.yeismo(false).fonetico("Vallejo"));
+    }
+
+    #[test]
+    fn soundex_es_codes() {
+        assert_eq!("B420", soundex_es("Vallejo"));
+        assert_eq!("R000", soundex_es("Rey"));
+        assert_eq!("C170", soundex_es("Chávez"));
+        assert_eq!("", soundex_es(""));
+        assert_eq!(soundex_es("Vázquez"), soundex_es("Basques"));
+    }
+}
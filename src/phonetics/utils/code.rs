@@ -0,0 +1,27 @@
+use phonetics::encoder::PhoneticCode;
+
+/// Truncate a code to `max_length` chars, padding it with zeros
+///
+/// With a `max_length` of 0 the code is kept whole, and an empty code becomes `0`.
+pub fn pad(mut code: String, max_length: usize) -> String {
+    if max_length > 0 {
+        while code.len() < max_length {
+            code.push('0');
+        }
+        code.truncate(max_length);
+    } else if code.is_empty() {
+        code.push('0');
+    }
+
+    code
+}
+
+/// Code of a word for a `PhoneticEncoder`, the empty code when the word has no letters
+pub fn encode_letters<F>(word: &str, encode: F) -> PhoneticCode
+    where F: FnOnce(&str) -> String
+{
+    if !word.chars().any(char::is_alphabetic) {
+        return PhoneticCode::default();
+    }
+    PhoneticCode::new(encode(word))
+}
@@ -1,2 +1,4 @@
 /// Module metaphone utils
+pub mod code;
 pub mod metaphone_utils;
+pub mod rules;
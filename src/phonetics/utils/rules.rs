@@ -0,0 +1,151 @@
+use normalize::transliterate::transliterate_char;
+
+/// Replace the occurrences of `src` by `tar` when `cond(chars, start, end)` holds
+///
+/// Occurrences are searched from the left and never overlap; the condition is evaluated on the
+/// chars of the original word, like a regex lookaround would.
+pub fn replace_if<F>(word: &str, src: &str, tar: &str, cond: F) -> String
+    where F: Fn(&[char], usize, usize) -> bool
+{
+    let chars = word.chars().collect::<Vec<_>>();
+    let src = src.chars().collect::<Vec<_>>();
+    let mut result = String::with_capacity(word.len());
+
+    let mut i = 0;
+    while i < chars.len() {
+        let end = i + src.len();
+        if !src.is_empty() && end <= chars.len() && chars[i..end] == src[..] && cond(&chars, i, end) {
+            result.push_str(tar);
+            i = end;
+        } else {
+            result.push(chars[i]);
+            i += 1;
+        }
+    }
+
+    result
+}
+
+/// Replace `src` by `tar` at the start of the word
+pub fn replace_start(word: &str, src: &str, tar: &str) -> String {
+    match word.strip_prefix(src) {
+        Some(rest) => tar.to_owned() + rest,
+        None => word.to_owned()
+    }
+}
+
+/// Replace `src` by `tar` at the end of the word
+pub fn replace_end(word: &str, src: &str, tar: &str) -> String {
+    match word.strip_suffix(src) {
+        Some(rest) => rest.to_owned() + tar,
+        None => word.to_owned()
+    }
+}
+
+/// Char before an occurrence starting at `start`
+pub fn before(chars: &[char], start: usize) -> Option<char> {
+    if start > 0 { chars.get(start - 1).cloned() } else { None }
+}
+
+/// Char after an occurrence ending at `end`
+pub fn after(chars: &[char], end: usize) -> Option<char> {
+    chars.get(end).cloned()
+}
+
+/// Whether the char exists and is one of `set`
+pub fn is_one_of(c: Option<char>, set: &str) -> bool {
+    match c {
+        Some(c) => set.contains(c),
+        None => false
+    }
+}
+
+/// Uppercase letters, squeezed in the keys of the language encoders
+pub const LETTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Key of the sounds of a word, scanned from the left
+///
+/// `sound(i)` gives the code of the sound starting at the char `i` and its length in chars, or
+/// `None` to keep the char as it is. The repeated letters of the key are then squeezed.
+pub fn scan_sounds<F>(chars: &[char], sound: F) -> String
+    where F: Fn(usize) -> Option<(&'static str, usize)>
+{
+    let mut key = String::with_capacity(chars.len());
+    let mut i = 0;
+    while i < chars.len() {
+        match sound(i) {
+            Some((sound, len)) => {
+                key.push_str(sound);
+                i += len;
+            },
+            None => {
+                key.push(chars[i]);
+                i += 1;
+            },
+        }
+    }
+
+    squeeze(&key, LETTERS)
+}
+
+/// Remove the consecutive duplicated chars which are in `set`
+pub fn squeeze(word: &str, set: &str) -> String {
+    let mut result = String::with_capacity(word.len());
+    let mut last = None;
+    for c in word.chars() {
+        if last != Some(c) || !set.contains(c) {
+            result.push(c);
+        }
+        last = Some(c);
+    }
+
+    result
+}
+
+/// Uppercase the word, transliterate its letters except the ones of `keep`, and drop everything else
+pub fn fold(word: &str, keep: &str) -> String {
+    let mut result = String::with_capacity(word.len());
+    for c in word.chars().flat_map(char::to_uppercase) {
+        if c.is_ascii_uppercase() || keep.contains(c) {
+            result.push(c);
+        } else if let Some(ascii) = transliterate_char(c) {
+            result.push_str(&ascii.to_uppercase());
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_replace_if() {
+        let vowel_around = |w: &[char], s: usize, e: usize| is_one_of(before(w, s), "AEIOU") && is_one_of(after(w, e), "AEIOU");
+        assert_eq!("ROZE", replace_if("ROSE", "S", "Z", vowel_around));
+        assert_eq!("SASSA", replace_if("SASSA", "S", "Z", vowel_around));
+        assert_eq!("KC", replace_if("CCC", "CC", "K", |_, _, _| true));
+    }
+
+    #[test]
+    fn rules_replace_start_end() {
+        assert_eq!("NNIGHT", replace_start("KNIGHT", "KN", "NN"));
+        assert_eq!("KNIGHT", replace_start("KNIGHT", "GN", "NN"));
+        assert_eq!("BERNAR", replace_end("BERNARD", "RD", "R"));
+    }
+
+    #[test]
+    fn rules_squeeze() {
+        assert_eq!("BALON", squeeze("BALLON", "L"));
+        assert_eq!("BALLON", squeeze("BALLON", "N"));
+        assert_eq!("ABC", squeeze("AABBBC", "ABC"));
+    }
+
+    #[test]
+    fn rules_fold_keep() {
+        assert_eq!("PURÉE", fold("purée", "ÉÈÊ"));
+        assert_eq!("FRERE", fold("frère", "É"));
+        assert_eq!("ACAO", fold("ação", ""));
+    }
+}
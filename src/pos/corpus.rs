@@ -0,0 +1,169 @@
+use std::io::{self, BufRead};
+
+/// Sentence of words and their part of speech tags
+pub type TaggedSentence = Vec<(String, String)>;
+
+fn invalid(format: &str, line: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("invalid {} line: {}", format, line))
+}
+
+/// Sentences of a CoNLL file: one token per line, its columns separated by tabs or spaces, and an
+/// empty line after each sentence
+///
+/// The word is the first column and the tag the column at `tag_column`: 1 for the CoNLL-2000
+/// chunking files, or for two column files. The lines starting with `#` and the `-DOCSTART-` lines
+/// are skipped.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::pos::corpus::read_conll;
+///
+/// let sentences = read_conll(&b"He PRP B-NP\nslept VBD B-VP\n. . O\n"[..], 1).unwrap();
+/// assert_eq!(vec![("He".to_owned(), "PRP".to_owned()), ("slept".to_owned(), "VBD".to_owned()),
+///                 (".".to_owned(), ".".to_owned())], sentences[0]);
+/// ```
+pub fn read_conll<R: BufRead>(reader: R, tag_column: usize) -> io::Result<Vec<TaggedSentence>> {
+    read_columns(reader, "conll", |line| {
+        if line.starts_with('#') || line.starts_with("-DOCSTART-") {
+            return Ok(None);
+        }
+        let fields = split_columns(line);
+        match (fields.first(), fields.get(tag_column)) {
+            (Some(word), Some(tag)) if tag_column > 0 => Ok(Some(((*word).to_owned(), (*tag).to_owned()))),
+            _                                         => Err(invalid("conll", line)),
+        }
+    })
+}
+
+/// Sentences of a CoNLL-U file, as the Universal Dependencies treebanks
+///
+/// The words are the FORM column, and the tags the XPOS column, the language specific tags (Penn
+/// Treebank for English), or the UPOS column when there's no XPOS. The multiword tokens and the
+/// empty nodes are skipped: their words are also given one by one.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::pos::corpus::read_conllu;
+///
+/// let conllu = "# text = I'm here\n\
+///               1-2\tI'm\t_\t_\t_\t_\t_\t_\t_\t_\n\
+///               1\tI\tI\tPRON\tPRP\t_\t3\tnsubj\t_\t_\n\
+///               2\t'm\tbe\tAUX\tVBP\t_\t3\tcop\t_\t_\n\
+///               3\there\there\tADV\t_\t_\t0\troot\t_\t_\n";
+/// let sentences = read_conllu(conllu.as_bytes()).unwrap();
+/// let tags = sentences[0].iter().map(|(word, tag)| format!("{}/{}", word, tag)).collect::<Vec<_>>();
+/// assert_eq!(vec!["I/PRP", "'m/VBP", "here/ADV"], tags);
+/// ```
+pub fn read_conllu<R: BufRead>(reader: R) -> io::Result<Vec<TaggedSentence>> {
+    read_columns(reader, "conllu", |line| {
+        if line.starts_with('#') {
+            return Ok(None);
+        }
+        let fields = line.split('\t').collect::<Vec<_>>();
+        if fields.len() < 5 {
+            return Err(invalid("conllu", line));
+        }
+        if fields[0].contains(['-', '.']) {
+            return Ok(None);
+        }
+        let tag = if fields[4] == "_" { fields[3] } else { fields[4] };
+        Ok(Some((fields[1].to_owned(), tag.to_owned())))
+    })
+}
+
+/// Sentences of a Penn Treebank style file: one sentence per line, its tokens written `word/TAG`
+///
+/// The tag follows the last slash, and the slashes of the words are escaped as `\/`. The brackets
+/// of the chunked `.pos` files are skipped.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::pos::corpus::read_penn;
+///
+/// let sentences = read_penn(&b"[ The/DT 1\\/2/CD ] share/NN\n\nrose/VBD ./.\n"[..]).unwrap();
+/// assert_eq!(2, sentences.len());
+/// assert_eq!(("1/2".to_owned(), "CD".to_owned()), sentences[0][1]);
+/// ```
+pub fn read_penn<R: BufRead>(reader: R) -> io::Result<Vec<TaggedSentence>> {
+    let mut sentences = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        let mut sentence = Vec::new();
+        for token in line.split_whitespace().filter(|&token| token != "[" && token != "]") {
+            match token.rfind('/') {
+                Some(slash) if slash > 0 && slash + 1 < token.len() => {
+                    sentence.push((token[..slash].replace("\\/", "/"), token[slash + 1..].to_owned()));
+                },
+                _ => return Err(invalid("penn", &line)),
+            }
+        }
+        if !sentence.is_empty() {
+            sentences.push(sentence);
+        }
+    }
+
+    Ok(sentences)
+}
+
+fn split_columns(line: &str) -> Vec<&str> {
+    if line.contains('\t') {
+        line.split('\t').collect()
+    } else {
+        line.split_whitespace().collect()
+    }
+}
+
+/// Sentences of a file with one token per line, the sentences separated by empty lines
+fn read_columns<R, F>(reader: R, format: &str, mut token: F) -> io::Result<Vec<TaggedSentence>>
+where
+    R: BufRead,
+    F: FnMut(&str) -> io::Result<Option<(String, String)>>,
+{
+    let mut sentences = Vec::new();
+    let mut sentence = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            if !sentence.is_empty() {
+                sentences.push(sentence);
+                sentence = Vec::new();
+            }
+        } else if let Some(pair) = token(&line)? {
+            if pair.0.is_empty() || pair.1.is_empty() {
+                return Err(invalid(format, &line));
+            }
+            sentence.push(pair);
+        }
+    }
+    if !sentence.is_empty() {
+        sentences.push(sentence);
+    }
+
+    Ok(sentences)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corpus_read_conll() {
+        let sentences = read_conll(&b"-DOCSTART- -X- O\n\nParis\tNNP\n\n\nrose\tVBD\n"[..], 1).unwrap();
+        assert_eq!(vec![vec![("Paris".to_owned(), "NNP".to_owned())], vec![("rose".to_owned(), "VBD".to_owned())]],
+                   sentences);
+        assert!(read_conll(&b"Paris NNP\nrose\n"[..], 1).is_err());
+        assert!(read_conll(&b"Paris NNP\n"[..], 0).is_err());
+        assert!(read_conllu(&b"1\tParis\tParis\n"[..]).is_err());
+        assert!(read_conllu(&b"1.1\t_\t_\t_\t_\t_\t_\t_\t_\t_\n"[..]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn corpus_read_penn() {
+        assert!(read_penn(&b"Paris/NNP rose\n"[..]).is_err());
+        assert!(read_penn(&b"Paris/\n"[..]).is_err());
+        assert_eq!(vec![vec![("and/or".to_owned(), "CC".to_owned())]], read_penn(&b" and\\/or/CC \n"[..]).unwrap());
+    }
+}
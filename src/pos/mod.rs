@@ -0,0 +1,4 @@
+/// Module tagged corpus readers (CoNLL, CoNLL-U, Penn Treebank)
+pub mod corpus;
+/// Module averaged perceptron tagger
+pub mod perceptron;
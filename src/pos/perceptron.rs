@@ -0,0 +1,465 @@
+use pos::corpus::TaggedSentence;
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+
+/// First bytes of a model file, followed by its format version
+const MAGIC: &[u8; 8] = b"nlp-pos\x01";
+/// Number of times a word must be seen to enter the tag dictionary
+const TAGDICT_MIN_COUNT: usize = 20;
+/// Share of its occurrences a word must have with a single tag to enter the tag dictionary
+const TAGDICT_MIN_RATIO: f64 = 0.97;
+/// Contexts of the words before the first one and after the last one
+const START: [&str; 2] = ["-START-", "-START2-"];
+const END: [&str; 2] = ["-END-", "-END2-"];
+
+/// Whether a tag is a proper noun: `NNP` and `NNPS` of the Penn Treebank, `PROPN` of the
+/// Universal Dependencies
+pub fn is_proper_noun(tag: &str) -> bool {
+    tag == "NNP" || tag == "NNPS" || tag == "PROPN"
+}
+
+/// Word as seen by the context features: lowercased, numbers and hyphenated words merged
+fn normalize(word: &str) -> String {
+    if word.contains('-') && !word.starts_with('-') {
+        "!HYPHEN".to_owned()
+    } else if word.len() == 4 && word.chars().all(|c| c.is_ascii_digit()) {
+        "!YEAR".to_owned()
+    } else if word.starts_with(|c: char| c.is_ascii_digit()) {
+        "!DIGITS".to_owned()
+    } else {
+        word.to_lowercase()
+    }
+}
+
+/// Last chars of a word, the whole word when it's shorter
+fn suffix(word: &str, length: usize) -> &str {
+    match word.char_indices().rev().nth(length - 1) {
+        Some((i, _)) => &word[i..],
+        None         => word,
+    }
+}
+
+/// Words of a sentence, normalized and padded with the start and end contexts
+fn context<S: AsRef<str>>(words: &[S]) -> Vec<String> {
+    START.iter().map(|&s| s.to_owned())
+         .chain(words.iter().map(|word| normalize(word.as_ref())))
+         .chain(END.iter().map(|&s| s.to_owned()))
+         .collect()
+}
+
+/// Features of the `i`th word, knowing the tags of the two previous words
+fn features(i: usize, word: &str, context: &[String], prev: &str, prev2: &str) -> Vec<String> {
+    let i = i + START.len();
+    let first = word.chars().next().map(|c| c.to_string()).unwrap_or_default();
+    vec![
+        "bias".to_owned(),
+        format!("i suffix {}", suffix(word, 3)),
+        format!("i pref1 {}", first),
+        format!("i-1 tag {}", prev),
+        format!("i-2 tag {}", prev2),
+        format!("i tag+i-2 tag {} {}", prev, prev2),
+        format!("i word {}", context[i]),
+        format!("i-1 tag+i word {} {}", prev, context[i]),
+        format!("i-1 word {}", context[i - 1]),
+        format!("i-1 suffix {}", suffix(&context[i - 1], 3)),
+        format!("i-2 word {}", context[i - 2]),
+        format!("i+1 word {}", context[i + 1]),
+        format!("i+1 suffix {}", suffix(&context[i + 1], 3)),
+        format!("i+2 word {}", context[i + 2]),
+    ]
+}
+
+/// Index of the best score, the first one on ties
+fn best(scores: &[f64]) -> usize {
+    let mut best = 0;
+    for (i, &score) in scores.iter().enumerate() {
+        if score > scores[best] {
+            best = i;
+        }
+    }
+
+    best
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Weight of a feature for a tag, with the sum of its past values for the averaging
+struct Param {
+    weight: f64,
+    total: f64,
+    stamp: usize,
+}
+
+#[derive(Debug)]
+/// Perceptron being trained
+struct Trainer {
+    classes: usize,
+    params: HashMap<String, HashMap<usize, Param>>,
+    instances: usize,
+}
+
+impl Trainer {
+    fn predict(&self, features: &[String]) -> usize {
+        let mut scores = vec![0.0; self.classes];
+        for params in features.iter().filter_map(|feature| self.params.get(feature)) {
+            for (&class, param) in params {
+                scores[class] += param.weight;
+            }
+        }
+
+        best(&scores)
+    }
+
+    fn update(&mut self, truth: usize, guess: usize, features: &[String]) {
+        self.instances += 1;
+        if truth == guess {
+            return;
+        }
+        let instances = self.instances;
+        for feature in features {
+            let params = self.params.entry(feature.clone()).or_default();
+            for &(class, delta) in &[(truth, 1.0), (guess, -1.0)] {
+                let param = params.entry(class).or_default();
+                param.total += (instances - param.stamp) as f64 * param.weight;
+                param.stamp = instances;
+                param.weight += delta;
+            }
+        }
+    }
+
+    /// Weights averaged over all the training instances, the null ones dropped
+    fn average(self) -> HashMap<String, Vec<(u32, f32)>> {
+        let instances = self.instances.max(1);
+        let mut weights = HashMap::new();
+        for (feature, params) in self.params {
+            let mut averaged = params.into_iter().filter_map(|(class, param)| {
+                let total = param.total + (instances - param.stamp) as f64 * param.weight;
+                let weight = (total / instances as f64) as f32;
+                if weight == 0.0 { None } else { Some((class as u32, weight)) }
+            }).collect::<Vec<_>>();
+            if !averaged.is_empty() {
+                averaged.sort_by_key(|&(class, _)| class);
+                weights.insert(feature, averaged);
+            }
+        }
+
+        weights
+    }
+}
+
+/// Deterministic shuffle of the training sentences, a xorshift generator with a fixed seed
+fn shuffle<T>(items: &mut [T], state: &mut u64) {
+    for i in (1..items.len()).rev() {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        items.swap(i, (*state % (i as u64 + 1)) as usize);
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// Averaged perceptron part of speech tagger, after [Honnibal](https://explosion.ai/blog/part-of-speech-pos-tagger-in-python)
+///
+/// Each word is tagged from its suffix, its first letter, the words around it and the tags of the
+/// two previous words. The frequent words which always had the same tag in the training sentences
+/// are tagged from a dictionary. The tags are the ones of the training sentences, usually the Penn
+/// Treebank ones, so that `lemma::Pos::from_tag` reads them.
+///
+/// Train the model once, from the sentences of `corpus`, and `save` it: `load` is much faster.
+/// The tagger expects words split the Penn Treebank way, as done by
+/// `WordTokenizer::new().split_contractions(true)`.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::pos::corpus::read_penn;
+/// use nlp::pos::perceptron::PerceptronTagger;
+/// use nlp::tokenize::word::WordTokenizer;
+///
+/// let corpus = "The/DT dog/NN barked/VBD ./.\nMary/NNP saw/VBD the/DT cat/NN ./.\n\
+///               A/DT cat/NN saw/VBD John/NNP ./.\nJohn/NNP walked/VBD the/DT dog/NN ./.";
+/// let tagger = PerceptronTagger::train(&read_penn(corpus.as_bytes()).unwrap(), 5);
+///
+/// let tokens = WordTokenizer::new().split_contractions(true).tokenize("The cat saw Mary.");
+/// assert_eq!(vec!["DT", "NN", "VBD", "NNP", "."], tagger.tag(&tokens));
+/// ```
+pub struct PerceptronTagger {
+    classes: Vec<String>,
+    tagdict: HashMap<String, u32>,
+    weights: HashMap<String, Vec<(u32, f32)>>,
+}
+
+impl PerceptronTagger {
+    /// Train a tagger over tagged sentences, `iterations` times (5 is usually enough)
+    ///
+    /// The sentences are shuffled between the iterations, always the same way.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the sentences have no word.
+    pub fn train(sentences: &[TaggedSentence], iterations: usize) -> PerceptronTagger {
+        let mut classes = sentences.iter().flat_map(|sentence| sentence.iter().map(|(_, tag)| &tag[..]))
+                                   .collect::<Vec<_>>();
+        classes.sort();
+        classes.dedup();
+        assert!(!classes.is_empty(), "no tagged word to train the tagger");
+        let index = classes.iter().enumerate().map(|(i, &tag)| (tag, i)).collect::<HashMap<_, _>>();
+
+        let mut counts = HashMap::new();
+        for (word, tag) in sentences.iter().flat_map(|sentence| sentence.iter()) {
+            *counts.entry(&word[..]).or_insert_with(HashMap::new).entry(index[&tag[..]]).or_insert(0) += 1;
+        }
+        let mut tagdict = HashMap::new();
+        for (word, tags) in counts {
+            let total = tags.values().sum::<usize>();
+            let (&class, &count) = tags.iter().max_by_key(|&(&class, &count)| (count, class)).unwrap();
+            if total >= TAGDICT_MIN_COUNT && count as f64 / total as f64 >= TAGDICT_MIN_RATIO {
+                tagdict.insert(word.to_owned(), class as u32);
+            }
+        }
+
+        let mut trainer = Trainer { classes: classes.len(), params: HashMap::new(), instances: 0 };
+        let mut order = sentences.iter().collect::<Vec<_>>();
+        let mut state = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..iterations {
+            for sentence in &order {
+                let words = sentence.iter().map(|(word, _)| &word[..]).collect::<Vec<_>>();
+                let context = context(&words);
+                let (mut prev, mut prev2) = (START[0], START[1]);
+                for (i, (word, tag)) in sentence.iter().enumerate() {
+                    let guess = match tagdict.get(word) {
+                        Some(&class) => class as usize,
+                        None         => {
+                            let features = features(i, word, &context, prev, prev2);
+                            let guess = trainer.predict(&features);
+                            trainer.update(index[&tag[..]], guess, &features);
+                            guess
+                        },
+                    };
+                    prev2 = prev;
+                    prev = classes[guess];
+                }
+            }
+            shuffle(&mut order, &mut state);
+        }
+
+        PerceptronTagger {
+            classes: classes.into_iter().map(str::to_owned).collect(),
+            tagdict,
+            weights: trainer.average(),
+        }
+    }
+
+    /// Tags of the model, sorted
+    pub fn tags(&self) -> Vec<&str> {
+        self.classes.iter().map(|tag| &tag[..]).collect()
+    }
+
+    /// Tags of the words of a sentence: strings, or the tokens of `WordTokenizer`
+    pub fn tag<S: AsRef<str>>(&self, words: &[S]) -> Vec<&str> {
+        let context = context(words);
+        let mut tags: Vec<&str> = Vec::with_capacity(words.len());
+        for (i, word) in words.iter().enumerate() {
+            let word = word.as_ref();
+            let class = match self.tagdict.get(word) {
+                Some(&class) => class as usize,
+                None         => {
+                    let prev = if i > 0 { tags[i - 1] } else { START[0] };
+                    let prev2 = match i { 0 => START[1], 1 => START[0], _ => tags[i - 2] };
+                    self.predict(&features(i, word, &context, prev, prev2))
+                },
+            };
+            tags.push(&self.classes[class]);
+        }
+
+        tags
+    }
+
+    /// Words tagged as proper nouns, to encode the names of a text
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use nlp::pos::corpus::read_penn;
+    /// use nlp::pos::perceptron::PerceptronTagger;
+    ///
+    /// let corpus = "Mary/NNP met/VBD John/NNP ./.\nThe/DT dog/NN met/VBD Mary/NNP ./.";
+    /// let tagger = PerceptronTagger::train(&read_penn(corpus.as_bytes()).unwrap(), 5);
+    /// assert_eq!(vec![&"John"], tagger.proper_nouns(&["John", "met", "the", "dog"]));
+    /// ```
+    pub fn proper_nouns<'a, S: AsRef<str>>(&self, words: &'a [S]) -> Vec<&'a S> {
+        words.iter().zip(self.tag(words)).filter(|&(_, tag)| is_proper_noun(tag)).map(|(word, _)| word).collect()
+    }
+
+    /// Share of the words of tagged sentences which get their tag, between 0 and 1
+    pub fn accuracy(&self, sentences: &[TaggedSentence]) -> f64 {
+        let mut total = 0;
+        let mut correct = 0;
+        for sentence in sentences {
+            let words = sentence.iter().map(|(word, _)| &word[..]).collect::<Vec<_>>();
+            for (tag, (_, truth)) in self.tag(&words).into_iter().zip(sentence) {
+                total += 1;
+                if tag == truth {
+                    correct += 1;
+                }
+            }
+        }
+
+        if total == 0 { 1.0 } else { correct as f64 / total as f64 }
+    }
+
+    fn predict(&self, features: &[String]) -> usize {
+        let mut scores = vec![0.0; self.classes.len()];
+        for weights in features.iter().filter_map(|feature| self.weights.get(feature)) {
+            for &(class, weight) in weights {
+                scores[class as usize] += f64::from(weight);
+            }
+        }
+
+        best(&scores)
+    }
+
+    /// Write the model in a compact binary format: the tags, the tag dictionary and the weights,
+    /// as little endian integers and floats, and length prefixed strings
+    pub fn save<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        fn write_u32<W: Write>(writer: &mut W, n: usize) -> io::Result<()> {
+            writer.write_all(&(n as u32).to_le_bytes())
+        }
+        fn write_str<W: Write>(writer: &mut W, s: &str) -> io::Result<()> {
+            write_u32(writer, s.len())?;
+            writer.write_all(s.as_bytes())
+        }
+
+        writer.write_all(MAGIC)?;
+        write_u32(&mut writer, self.classes.len())?;
+        for class in &self.classes {
+            write_str(&mut writer, class)?;
+        }
+
+        let mut tagdict = self.tagdict.iter().collect::<Vec<_>>();
+        tagdict.sort();
+        write_u32(&mut writer, tagdict.len())?;
+        for (word, &class) in tagdict {
+            write_str(&mut writer, word)?;
+            write_u32(&mut writer, class as usize)?;
+        }
+
+        let mut weights = self.weights.iter().collect::<Vec<_>>();
+        weights.sort_by(|a, b| a.0.cmp(b.0));
+        write_u32(&mut writer, weights.len())?;
+        for (feature, weights) in weights {
+            write_str(&mut writer, feature)?;
+            write_u32(&mut writer, weights.len())?;
+            for &(class, weight) in weights {
+                write_u32(&mut writer, class as usize)?;
+                writer.write_all(&weight.to_le_bytes())?;
+            }
+        }
+
+        writer.flush()
+    }
+
+    /// Read a model written by `save`
+    pub fn load<R: Read>(mut reader: R) -> io::Result<PerceptronTagger> {
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, format!("invalid pos model: {}", message));
+        fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+            let mut bytes = [0; 4];
+            reader.read_exact(&mut bytes)?;
+            Ok(u32::from_le_bytes(bytes))
+        }
+        let read_str = |reader: &mut R| -> io::Result<String> {
+            let length = read_u32(reader)? as usize;
+            let mut bytes = Vec::new();
+            if reader.take(length as u64).read_to_end(&mut bytes)? < length {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+            }
+            String::from_utf8(bytes).map_err(|_| invalid("string is not UTF-8"))
+        };
+
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid("unknown format"));
+        }
+
+        let classes = (0..read_u32(&mut reader)?).map(|_| read_str(&mut reader)).collect::<io::Result<Vec<_>>>()?;
+        if classes.is_empty() {
+            return Err(invalid("no tag"));
+        }
+        let class = |class: u32| if (class as usize) < classes.len() { Ok(class) } else { Err(invalid("unknown tag")) };
+
+        let mut tagdict = HashMap::new();
+        for _ in 0..read_u32(&mut reader)? {
+            let word = read_str(&mut reader)?;
+            tagdict.insert(word, class(read_u32(&mut reader)?)?);
+        }
+
+        let mut weights = HashMap::new();
+        for _ in 0..read_u32(&mut reader)? {
+            let feature = read_str(&mut reader)?;
+            let mut feature_weights = Vec::new();
+            for _ in 0..read_u32(&mut reader)? {
+                let tag = class(read_u32(&mut reader)?)?;
+                let mut bytes = [0; 4];
+                reader.read_exact(&mut bytes)?;
+                feature_weights.push((tag, f32::from_le_bytes(bytes)));
+            }
+            weights.insert(feature, feature_weights);
+        }
+
+        Ok(PerceptronTagger { classes, tagdict, weights })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pos::corpus::read_penn;
+
+    fn tagger() -> PerceptronTagger {
+        let corpus = "The/DT book/NN is/VBZ new/JJ ./.\nI/PRP will/MD book/VB a/DT room/NN ./.\n\
+                      We/PRP book/VBP the/DT room/NN ./.\nHe/PRP read/VBD the/DT book/NN ./.\n\
+                      They/PRP will/MD read/VB a/DT book/NN ./.\nThe/DT room/NN is/VBZ old/JJ ./.\n\
+                      She/PRP will/MD book/VB the/DT room/NN ./.\nWe/PRP will/MD read/VB the/DT book/NN ./.";
+        PerceptronTagger::train(&read_penn(corpus.as_bytes()).unwrap(), 10)
+    }
+
+    #[test]
+    fn perceptron_normalize() {
+        assert_eq!("!HYPHEN", normalize("well-known"));
+        assert_eq!("-", normalize("-"));
+        assert_eq!("!YEAR", normalize("1999"));
+        assert_eq!("!DIGITS", normalize("3.5"));
+        assert_eq!("straße", normalize("Straße"));
+        assert_eq!("ing", suffix("booking", 3));
+        assert_eq!("ça", suffix("ça", 3));
+    }
+
+    #[test]
+    fn perceptron_tag() {
+        let tagger = tagger();
+        assert_eq!(vec![".", "DT", "JJ", "MD", "NN", "PRP", "VB", "VBD", "VBP", "VBZ"], tagger.tags());
+        assert_eq!(vec!["PRP", "MD", "VB", "DT", "NN"], tagger.tag(&["I", "will", "book", "the", "room"]));
+        assert_eq!(vec!["DT", "NN", "VBZ", "JJ", "."], tagger.tag(&["The", "book", "is", "old", "."]));
+        assert!(tagger.tag::<&str>(&[]).is_empty());
+        assert_eq!(1.0, tagger.accuracy(&read_penn(&b"The/DT room/NN is/VBZ new/JJ ./."[..]).unwrap()));
+    }
+
+    #[test]
+    fn perceptron_save_load() {
+        let tagger = tagger();
+        let mut model = Vec::new();
+        tagger.save(&mut model).unwrap();
+        assert_eq!(tagger, PerceptronTagger::load(&model[..]).unwrap());
+
+        assert!(PerceptronTagger::load(&b"nlp-pos\x02"[..]).is_err());
+        assert!(PerceptronTagger::load(&model[..model.len() - 1]).is_err());
+        model[12] = 0xff;
+        assert!(PerceptronTagger::load(&model[..]).is_err());
+    }
+
+    #[test]
+    fn perceptron_proper_nouns() {
+        assert!(is_proper_noun("NNP") && is_proper_noun("NNPS") && is_proper_noun("PROPN"));
+        assert!(!is_proper_noun("NN"));
+    }
+}
@@ -0,0 +1,117 @@
+use stem::snowball::Env;
+use stem::stemmer::Stemmer;
+
+static MAIN_SUFFIXES: &[&str] = &[
+    "hed", "ethed", "ered", "e", "erede", "ende", "erende", "ene", "erne", "ere", "en", "heden", "eren", "er",
+    "heder", "erer", "heds", "es", "endes", "erendes", "enes", "ernes", "eres", "ens", "hedens", "erens", "ers",
+    "ets", "erets", "et", "eret", "s",
+];
+
+fn is_vowel(c: char) -> bool {
+    "aeiouyæåø".contains(c)
+}
+
+/// R1, starting after the third char at least
+fn mark_region(env: &mut Env) -> usize {
+    if env.len() < 3 {
+        return env.len();
+    }
+    let p1 = env.region(is_vowel);
+    env.cursor = 0;
+    p1.max(3)
+}
+
+fn main_suffix(env: &mut Env, p1: usize) -> bool {
+    match env.substring_b_from(p1, MAIN_SUFFIXES) {
+        Some("s") => {
+            if !env.in_grouping_b(|c| "abcdfghjklmnoprtvyzå".contains(c)) {
+                return false;
+            }
+            env.slice_del();
+        },
+        Some(_) => env.slice_del(),
+        None => return false,
+    }
+    true
+}
+
+/// Remove the t or d of a final gd, dt, gt or kt
+fn consonant_pair(env: &mut Env, p1: usize) -> bool {
+    if !env.test_b(|env| env.substring_b_from(p1, &["gd", "dt", "gt", "kt"]).is_some()) || !env.next_b() {
+        return false;
+    }
+    env.bra = env.cursor;
+    env.slice_del();
+    true
+}
+
+fn other_suffix(env: &mut Env, p1: usize) -> bool {
+    env.test_b(|env| {
+        if !env.slice_eq_b("st") || !env.eq_b("ig") {
+            return false;
+        }
+        env.slice_del();
+        true
+    });
+
+    match env.substring_b_from(p1, &["ig", "lig", "elig", "els", "løst"]) {
+        Some("løst") => env.slice_from("løs"),
+        Some(_) => {
+            env.slice_del();
+            env.test_b(|env| consonant_pair(env, p1));
+        },
+        None => return false,
+    }
+    true
+}
+
+/// Undouble a final consonant
+fn undouble(env: &mut Env, p1: usize) -> bool {
+    let mut consonant = None;
+    let found = env.set_limit_b(p1, |env| {
+        env.ket = env.cursor;
+        if !env.out_grouping_b(is_vowel) {
+            return false;
+        }
+        env.bra = env.cursor;
+        consonant = Some(env.slice_to());
+        true
+    });
+    if !found || !consonant.is_some_and(|consonant| env.eq_b(&consonant)) {
+        return false;
+    }
+    env.slice_del();
+    true
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Snowball Danish stemmer
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stem::stemmer::Stemmer;
+/// use nlp::stem::danish::Danish;
+///
+/// assert_eq!("bil", Danish.stem("bilerne"));
+/// assert_eq!("hus", Danish.stem("husets"));
+/// assert_eq!("kend", Danish.stem("kendte"));
+/// assert!(Danish.is_match("venlighed", "venlig"));
+/// ```
+pub struct Danish;
+
+impl Stemmer for Danish {
+    fn stem(&self, word: &str) -> String {
+        let mut env = Env::new(&word.to_lowercase());
+        let p1 = mark_region(&mut env);
+
+        env.backwards();
+        env.test_b(|env| main_suffix(env, p1));
+        env.test_b(|env| consonant_pair(env, p1));
+        env.test_b(|env| other_suffix(env, p1));
+        env.test_b(|env| undouble(env, p1));
+        env.forwards();
+
+        env.text()
+    }
+}
@@ -0,0 +1,192 @@
+use stem::snowball::Env;
+use stem::stemmer::Stemmer;
+
+fn is_vowel(c: char) -> bool {
+    "aeiouyè".contains(c)
+}
+
+/// Remove the accents, and mark the initial y, the y after a vowel and the i between vowels as
+/// consonants
+fn prelude(env: &mut Env) {
+    env.replace_chars(&[
+        ('ä', "a"), ('á', "a"), ('ë', "e"), ('é', "e"), ('ï', "i"), ('í', "i"), ('ö', "o"), ('ó', "o"), ('ü', "u"),
+        ('ú', "u"),
+    ]);
+    if env.at(0) == Some('y') {
+        env.chars[0] = 'Y';
+    }
+
+    for index in 0..env.len() {
+        let vowel = |index: usize| env.at(index).is_some_and(is_vowel);
+        if !vowel(index) {
+            continue;
+        }
+        match env.at(index + 1) {
+            Some('i') if vowel(index + 2) => env.chars[index + 1] = 'I',
+            Some('y') => env.chars[index + 1] = 'Y',
+            _ => {},
+        }
+    }
+}
+
+/// R1 and R2, R1 starting after the third char at least
+fn mark_regions(env: &mut Env) -> (usize, usize) {
+    env.cursor = 0;
+    let p1 = env.region(is_vowel);
+    let p2 = env.region(is_vowel);
+    env.cursor = 0;
+
+    (p1.max(3), p2)
+}
+
+/// Remove the last char of a final kk, dd or tt
+fn undouble(env: &mut Env) -> bool {
+    if !env.test_b(|env| env.among_b(&["kk", "dd", "tt"]).is_some()) {
+        return false;
+    }
+    env.ket = env.cursor;
+    if !env.next_b() {
+        return false;
+    }
+    env.bra = env.cursor;
+    env.slice_del();
+    true
+}
+
+/// Final e in R1 after a consonant, whether it has been removed
+fn e_ending(env: &mut Env, p1: usize) -> bool {
+    if !env.slice_eq_b("e") || env.cursor < p1 || !env.test_b(|env| env.out_grouping_b(is_vowel)) {
+        return false;
+    }
+    env.slice_del();
+    undouble(env);
+    true
+}
+
+/// Final -en in R1 after a consonant, but not after gem
+fn en_ending(env: &mut Env, p1: usize) -> bool {
+    if env.cursor < p1 || !env.test_b(|env| env.out_grouping_b(is_vowel)) || env.test_b(|env| env.eq_b("gem")) {
+        return false;
+    }
+    env.slice_del();
+    undouble(env)
+}
+
+fn standard_suffix(env: &mut Env, p1: usize, p2: usize) {
+    env.test_b(|env| {
+        match env.substring_b(&["heden", "en", "ene", "s", "se"]) {
+            Some("heden") => {
+                if env.cursor < p1 {
+                    return false;
+                }
+                env.slice_from("heid");
+            },
+            Some("en") | Some("ene") => return en_ending(env, p1),
+            Some(_) => {
+                if env.cursor < p1 || !env.out_grouping_b(|c| is_vowel(c) || c == 'j') {
+                    return false;
+                }
+                env.slice_del();
+            },
+            None => return false,
+        }
+        true
+    });
+
+    let mut e_found = false;
+    env.test_b(|env| {
+        e_found = e_ending(env, p1);
+        e_found
+    });
+
+    env.test_b(|env| {
+        if !env.slice_eq_b("heid") || env.cursor < p2 || env.test_b(|env| env.eq_b("c")) {
+            return false;
+        }
+        env.slice_del();
+        env.slice_eq_b("en") && en_ending(env, p1)
+    });
+
+    env.test_b(|env| {
+        match env.substring_b(&["end", "ing", "ig", "lijk", "baar", "bar"]) {
+            Some(_) if env.cursor < p2 => return false,
+            Some("end") | Some("ing") => {
+                env.slice_del();
+                let undoubled = env.try_b(|env| {
+                    if !env.slice_eq_b("ig") || env.cursor < p2 || env.test_b(|env| env.eq_b("e")) {
+                        return false;
+                    }
+                    env.slice_del();
+                    true
+                });
+                return undoubled || undouble(env);
+            },
+            Some("ig") => {
+                if env.test_b(|env| env.eq_b("e")) {
+                    return false;
+                }
+                env.slice_del();
+            },
+            Some("lijk") => {
+                env.slice_del();
+                return e_ending(env, p1);
+            },
+            Some("baar") => env.slice_del(),
+            Some(_) => {
+                if !e_found {
+                    return false;
+                }
+                env.slice_del();
+            },
+            None => return false,
+        }
+        true
+    });
+
+    // Undouble the vowel of a closed syllable: "maan" becomes "man"
+    env.test_b(|env| {
+        if !env.out_grouping_b(|c| is_vowel(c) || c == 'I') ||
+            !env.test_b(|env| env.among_b(&["aa", "ee", "oo", "uu"]).is_some() && env.out_grouping_b(is_vowel)) {
+            return false;
+        }
+        env.ket = env.cursor;
+        if !env.next_b() {
+            return false;
+        }
+        env.bra = env.cursor;
+        env.slice_del();
+        true
+    });
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Snowball Dutch stemmer
+///
+/// The accents are removed from the stems.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stem::stemmer::Stemmer;
+/// use nlp::stem::dutch::Dutch;
+///
+/// assert_eq!("lop", Dutch.stem("lopen"));
+/// assert_eq!("mogelijk", Dutch.stem("mogelijkheden"));
+/// assert_eq!("man", Dutch.stem("manen"));
+/// assert!(Dutch.is_match("lopen", "loop"));
+/// ```
+pub struct Dutch;
+
+impl Stemmer for Dutch {
+    fn stem(&self, word: &str) -> String {
+        let mut env = Env::new(&word.to_lowercase());
+        prelude(&mut env);
+        let (p1, p2) = mark_regions(&mut env);
+
+        env.backwards();
+        standard_suffix(&mut env, p1, p2);
+        env.forwards();
+
+        env.text().replace('Y', "y").replace('I', "i")
+    }
+}
@@ -0,0 +1,246 @@
+use stem::snowball::Env;
+use stem::stemmer::Stemmer;
+
+static EXCEPTIONS: &[(&str, &str)] = &[
+    ("skis", "ski"), ("skies", "sky"), ("dying", "die"), ("lying", "lie"), ("tying", "tie"),
+    ("idly", "idl"), ("gently", "gentl"), ("ugly", "ugli"), ("early", "earli"), ("only", "onli"),
+    ("singly", "singl"), ("sky", "sky"), ("news", "news"), ("howe", "howe"), ("atlas", "atlas"),
+    ("cosmos", "cosmos"), ("bias", "bias"), ("andes", "andes"),
+];
+// Words left alone after step 1a
+static INVARIANTS: &[&str] = &["inning", "outing", "canning", "herring", "earring", "proceed", "exceed", "succeed"];
+static PREFIXES: &[&str] = &["gener", "commun", "arsen"];
+static DOUBLES: &[&str] = &["bb", "dd", "ff", "gg", "mm", "nn", "pp", "rr", "tt"];
+
+static STEP_2: &[(&str, &str)] = &[
+    ("tional", "tion"), ("enci", "ence"), ("anci", "ance"), ("abli", "able"), ("entli", "ent"),
+    ("izer", "ize"), ("ization", "ize"), ("ational", "ate"), ("ation", "ate"), ("ator", "ate"),
+    ("alism", "al"), ("aliti", "al"), ("alli", "al"), ("fulness", "ful"), ("ousli", "ous"),
+    ("ousness", "ous"), ("iveness", "ive"), ("iviti", "ive"), ("biliti", "ble"), ("bli", "ble"),
+    ("ogi", "og"), ("fulli", "ful"), ("lessli", "less"), ("li", ""),
+];
+static STEP_3: &[(&str, &str)] = &[
+    ("tional", "tion"), ("ational", "ate"), ("alize", "al"), ("icate", "ic"), ("iciti", "ic"),
+    ("ical", "ic"), ("ful", ""), ("ness", ""), ("ative", ""),
+];
+static STEP_4: &[&str] = &["al", "ance", "ence", "er", "ic", "able", "ible", "ant", "ement", "ment", "ent", "ism",
+                           "ate", "iti", "ous", "ive", "ize", "ion"];
+
+fn is_vowel(c: char) -> bool {
+    "aeiouy".contains(c)
+}
+
+/// Short syllable before the cursor: a vowel between two consonants, the last one not being w, x
+/// or Y, or a vowel and a consonant starting the word
+fn short_syllable(env: &mut Env) -> bool {
+    env.test_b(|env| {
+        env.out_grouping_b(|c| is_vowel(c) || "wxY".contains(c)) && env.in_grouping_b(is_vowel) && env.out_grouping_b(is_vowel)
+    }) || env.test_b(|env| {
+        env.out_grouping_b(is_vowel) && env.in_grouping_b(is_vowel) && env.cursor == env.limit_backward
+    })
+}
+
+/// Remove the initial apostrophe, and mark the y at the start of the word or after a vowel as
+/// consonants
+fn prelude(env: &mut Env) {
+    if env.at(0) == Some('\'') {
+        env.bra = 0;
+        env.ket = 1;
+        env.slice_del();
+    }
+
+    for index in 0..env.len() {
+        if env.chars[index] == 'y' && (index == 0 || is_vowel(env.chars[index - 1])) {
+            env.chars[index] = 'Y';
+        }
+    }
+}
+
+/// R1 and R2, R1 starting after "gener", "commun" and "arsen"
+fn mark_regions(env: &mut Env) -> (usize, usize) {
+    env.cursor = 0;
+    let p1 = if PREFIXES.iter().any(|prefix| env.eq(prefix)) { env.cursor } else { env.region(is_vowel) };
+    let p2 = env.region(is_vowel);
+    env.cursor = 0;
+
+    (p1, p2)
+}
+
+/// Step 1a: plurals and apostrophes
+fn step_1a(env: &mut Env) -> bool {
+    if env.substring_b(&["'", "'s", "'s'"]).is_some() {
+        env.slice_del();
+    }
+
+    match env.substring_b(&["sses", "ied", "ies", "s", "us", "ss"]) {
+        Some("sses") => env.slice_from("ss"),
+        Some("ied") | Some("ies") => {
+            let replacement = if env.cursor >= 2 { "i" } else { "ie" };
+            env.slice_from(replacement);
+        },
+        Some("s") => {
+            if !env.test_b(|env| env.next_b() && env.go_past_b(is_vowel)) {
+                return false;
+            }
+            env.slice_del();
+        },
+        Some(_) => {},
+        None => return false,
+    }
+    true
+}
+
+/// Step 1b: -ed and -ing
+fn step_1b(env: &mut Env, p1: usize) -> bool {
+    match env.substring_b(&["eed", "eedly", "ed", "edly", "ing", "ingly"]) {
+        Some("eed") | Some("eedly") => {
+            if env.cursor < p1 {
+                return false;
+            }
+            env.slice_from("ee");
+        },
+        Some(_) => {
+            if !env.test_b(|env| env.go_past_b(is_vowel)) {
+                return false;
+            }
+            env.slice_del();
+
+            let mut ending = None;
+            env.test_b(|env| {
+                ending = env.among_b(&["at", "bl", "iz"]).or_else(|| env.among_b(DOUBLES));
+                true
+            });
+            match ending {
+                Some("at") | Some("bl") | Some("iz") => env.insert("e"),
+                Some(_) => {
+                    env.ket = env.cursor;
+                    env.next_b();
+                    env.bra = env.cursor;
+                    env.slice_del();
+                },
+                None if env.cursor == p1 && short_syllable(env) => env.insert("e"),
+                None => {},
+            }
+        },
+        None => return false,
+    }
+    true
+}
+
+/// Step 1c: final y after a consonant
+fn step_1c(env: &mut Env) -> bool {
+    if !(env.slice_eq_b("y") || env.slice_eq_b("Y")) || !env.out_grouping_b(is_vowel) || env.cursor == env.limit_backward {
+        return false;
+    }
+    env.slice_from("i");
+    true
+}
+
+/// Step 2: double suffixes in R1
+fn step_2(env: &mut Env, p1: usize) -> bool {
+    let suffixes = STEP_2.iter().map(|&(suffix, _)| suffix).collect::<Vec<_>>();
+    let suffix = match env.substring_b(&suffixes) {
+        Some(suffix) if env.cursor >= p1 => suffix,
+        _ => return false,
+    };
+    let replacement = STEP_2.iter().find(|&&(s, _)| s == suffix).map_or("", |&(_, r)| r);
+
+    match suffix {
+        "ogi" if !env.eq_b("l")                                      => false,
+        "li" if !env.in_grouping_b(|c| "cdeghkmnrt".contains(c))     => false,
+        _                                                            => {
+            env.slice_from(replacement);
+            true
+        },
+    }
+}
+
+/// Step 3: -ful, -ness, -ical in R1
+fn step_3(env: &mut Env, p1: usize, p2: usize) -> bool {
+    let suffixes = STEP_3.iter().map(|&(suffix, _)| suffix).collect::<Vec<_>>();
+    match env.substring_b(&suffixes) {
+        Some(_) if env.cursor < p1 => false,
+        Some("ative") if env.cursor < p2 => false,
+        Some(suffix) => {
+            let replacement = STEP_3.iter().find(|&&(s, _)| s == suffix).map_or("", |&(_, r)| r);
+            env.slice_from(replacement);
+            true
+        },
+        None => false,
+    }
+}
+
+/// Step 4: suffixes in R2
+fn step_4(env: &mut Env, p2: usize) -> bool {
+    match env.substring_b(STEP_4) {
+        Some(_) if env.cursor < p2 => return false,
+        Some("ion") => {
+            if !env.in_grouping_b(|c| c == 's' || c == 't') {
+                return false;
+            }
+            env.slice_del();
+        },
+        Some(_) => env.slice_del(),
+        None => return false,
+    }
+    true
+}
+
+/// Step 5: final e and double l
+fn step_5(env: &mut Env, p1: usize, p2: usize) -> bool {
+    match env.substring_b(&["e", "l"]) {
+        Some("e") if env.cursor >= p2 || (env.cursor >= p1 && !short_syllable(env)) => env.slice_del(),
+        Some("l") if env.cursor >= p2 && env.eq_b("l")                            => env.slice_del(),
+        _ => return false,
+    }
+    true
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Snowball English stemmer, also known as Porter2
+///
+/// The successor of the Porter stemmer, by Martin Porter as well. It handles apostrophes, a few
+/// exceptions ("skies", "dying", "news"), and fixes some rules of the original ("generously").
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stem::stemmer::Stemmer;
+/// use nlp::stem::english::English;
+///
+/// assert_eq!("run", English.stem("running"));
+/// assert_eq!("generous", English.stem("generously"));
+/// assert_eq!("sky", English.stem("skies"));
+/// assert_eq!("dog", English.stem("dog's"));
+/// ```
+pub struct English;
+
+impl Stemmer for English {
+    fn stem(&self, word: &str) -> String {
+        let word = word.to_lowercase();
+        if let Some(&(_, stem)) = EXCEPTIONS.iter().find(|&&(exception, _)| exception == word) {
+            return stem.to_owned();
+        }
+        if word.chars().count() < 3 {
+            return word;
+        }
+
+        let mut env = Env::new(&word);
+        prelude(&mut env);
+        let (p1, p2) = mark_regions(&mut env);
+
+        env.backwards();
+        env.test_b(step_1a);
+        if !INVARIANTS.iter().any(|invariant| env.chars.iter().cloned().eq(invariant.chars())) {
+            env.test_b(|env| step_1b(env, p1));
+            env.test_b(step_1c);
+            env.test_b(|env| step_2(env, p1));
+            env.test_b(|env| step_3(env, p1, p2));
+            env.test_b(|env| step_4(env, p2));
+            env.test_b(|env| step_5(env, p1, p2));
+        }
+        env.forwards();
+
+        env.text().replace('Y', "y")
+    }
+}
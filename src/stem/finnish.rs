@@ -0,0 +1,269 @@
+use stem::snowball::Env;
+use stem::stemmer::Stemmer;
+
+static PARTICLES: &[&str] = &["kin", "kaan", "kään", "ko", "kö", "han", "hän", "pa", "pä", "sti"];
+static POSSESSIVES: &[&str] = &["si", "ni", "nsa", "nsä", "mme", "nne", "an", "än", "en"];
+static CASE_ENDINGS: &[&str] = &[
+    "han", "hen", "hin", "hon", "hän", "hön", "siin", "seen", "den", "tten", "n", "a", "ä", "tta", "ttä", "ta", "tä",
+    "ssa", "ssä", "sta", "stä", "lla", "llä", "lta", "ltä", "lle", "na", "nä", "ksi", "ine",
+];
+static OTHER_ENDINGS: &[&str] = &[
+    "mpi", "mpa", "mpä", "mmi", "mma", "mmä", "impi", "impa", "impä", "immi", "imma", "immä", "eja", "ejä",
+];
+static LONG_VOWELS: &[&str] = &["aa", "ee", "ii", "oo", "uu", "ää", "öö"];
+
+fn is_vowel(c: char) -> bool {
+    "aeiouyäö".contains(c)
+}
+
+/// A long vowel before the cursor
+fn long(env: &mut Env) -> bool {
+    env.among_b(LONG_VOWELS).is_some()
+}
+
+/// An i after a vowel other than y before the cursor
+fn vi(env: &mut Env) -> bool {
+    env.eq_b("i") && env.in_grouping_b(|c| "aeiouäö".contains(c))
+}
+
+/// Particles -kin, -kaan, -ko, -han, -pa and -sti
+fn particle_etc(env: &mut Env, p1: usize, p2: usize) -> bool {
+    match env.substring_b_from(p1, PARTICLES) {
+        Some("sti") if env.cursor < p2 => return false,
+        Some("sti") => {},
+        Some(_) => {
+            if !env.in_grouping_b(|c| is_vowel(c) || c == 'n' || c == 't') {
+                return false;
+            }
+        },
+        None => return false,
+    }
+    env.slice_del();
+    true
+}
+
+/// Possessive suffixes
+fn possessive(env: &mut Env, p1: usize) -> bool {
+    match env.substring_b_from(p1, POSSESSIVES) {
+        // -ksi is the comitative case
+        Some("si") => {
+            if env.test_b(|env| env.eq_b("k")) {
+                return false;
+            }
+            env.slice_del();
+        },
+        // -kseni is -ksi followed by -ni
+        Some("ni") => {
+            env.slice_del();
+            if !env.slice_eq_b("kse") {
+                return false;
+            }
+            env.slice_from("ksi");
+        },
+        Some(possessive @ "an") | Some(possessive @ "än") | Some(possessive @ "en") => {
+            let cases: &[&str] = match possessive {
+                "an" => &["ta", "ssa", "sta", "lla", "lta", "na"],
+                "än" => &["tä", "ssä", "stä", "llä", "ltä", "nä"],
+                _    => &["lle", "ine"],
+            };
+            if env.among_b(cases).is_none() {
+                return false;
+            }
+            env.slice_del();
+        },
+        Some(_) => env.slice_del(),
+        None => return false,
+    }
+    true
+}
+
+/// Case endings, whether one has been removed
+fn case_ending(env: &mut Env, p1: usize) -> bool {
+    let mut ending = None;
+    let found = env.set_limit_b(p1, |env| {
+        ending = env.substring_b(CASE_ENDINGS);
+        let condition = match ending {
+            Some("siin") | Some("den") | Some("tten") => env.test_b(vi),
+            Some("seen") => env.test_b(long),
+            _ => true,
+        };
+        // Otherwise it's a genitive -n
+        if !condition {
+            env.cursor = env.ket - 1;
+            env.bra = env.cursor;
+            ending = Some("n");
+        }
+        ending.is_some()
+    });
+    if !found {
+        return false;
+    }
+
+    let valid = match ending {
+        Some("han") | Some("hen") | Some("hin") | Some("hon") | Some("hän") | Some("hön") => {
+            let vowel = ending.map_or("", |ending| &ending[1..ending.len() - 1]);
+            env.eq_b(vowel)
+        },
+        Some("tta") | Some("ttä") => env.eq_b("e"),
+        Some("a") | Some("ä") => env.in_grouping_b(is_vowel) && env.out_grouping_b(is_vowel),
+        // Illative after a long vowel, genitive after ie
+        Some("n") => {
+            env.try_b(|env| {
+                if !env.test_b(|env| long(env) || env.eq_b("ie")) || !env.next_b() {
+                    return false;
+                }
+                env.bra = env.cursor;
+                true
+            });
+            true
+        },
+        _ => true,
+    };
+    if !valid {
+        return false;
+    }
+    env.slice_del();
+    true
+}
+
+/// Comparative and superlative forms, and -eja
+fn other_endings(env: &mut Env, p2: usize) -> bool {
+    match env.substring_b_from(p2, OTHER_ENDINGS) {
+        Some(ending) if ending.starts_with('m') && env.test_b(|env| env.eq_b("po")) => false,
+        Some(_) => {
+            env.slice_del();
+            true
+        },
+        None => false,
+    }
+}
+
+/// Plural -i and -j
+fn i_plural(env: &mut Env, p1: usize) -> bool {
+    if env.substring_b_from(p1, &["i", "j"]).is_none() {
+        return false;
+    }
+    env.slice_del();
+    true
+}
+
+/// Plural -t, and -mmat and -immat
+fn t_plural(env: &mut Env, p1: usize, p2: usize) -> bool {
+    let removed = env.set_limit_b(p1, |env| {
+        if !env.slice_eq_b("t") || !env.test_b(|env| env.in_grouping_b(is_vowel)) {
+            return false;
+        }
+        env.slice_del();
+        true
+    });
+    if !removed {
+        return false;
+    }
+
+    match env.substring_b_from(p2, &["mma", "imma"]) {
+        Some("mma") if env.test_b(|env| env.eq_b("po")) => false,
+        Some(_) => {
+            env.slice_del();
+            true
+        },
+        None => false,
+    }
+}
+
+/// Undouble the vowels and the consonants, remove the final a, ä, e and i after a consonant
+fn tidy(env: &mut Env, p1: usize) -> bool {
+    env.set_limit_b(p1, |env| {
+        env.test_b(|env| {
+            if !env.test_b(long) {
+                return false;
+            }
+            env.ket = env.cursor;
+            env.next_b();
+            env.bra = env.cursor;
+            env.slice_del();
+            true
+        });
+        env.test_b(|env| {
+            env.ket = env.cursor;
+            if !env.in_grouping_b(|c| "aäei".contains(c)) {
+                return false;
+            }
+            env.bra = env.cursor;
+            if !env.out_grouping_b(is_vowel) {
+                return false;
+            }
+            env.slice_del();
+            true
+        });
+        env.test_b(|env| {
+            if !env.slice_eq_b("j") || !(env.eq_b("o") || env.eq_b("u")) {
+                return false;
+            }
+            env.slice_del();
+            true
+        });
+        env.test_b(|env| {
+            if !env.slice_eq_b("o") || !env.eq_b("j") {
+                return false;
+            }
+            env.slice_del();
+            true
+        });
+        true
+    });
+
+    while env.cursor > env.limit_backward && is_vowel(env.chars[env.cursor - 1]) {
+        env.cursor -= 1;
+    }
+    env.ket = env.cursor;
+    if !env.next_b() {
+        return false;
+    }
+    env.bra = env.cursor;
+    let consonant = env.slice_to();
+    if !env.eq_b(&consonant) {
+        return false;
+    }
+    env.slice_del();
+    true
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Snowball Finnish stemmer
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stem::stemmer::Stemmer;
+/// use nlp::stem::finnish::Finnish;
+///
+/// assert_eq!("talo", Finnish.stem("taloissa"));
+/// assert_eq!("kirj", Finnish.stem("kirjastaan"));
+/// assert_eq!("kauniimp", Finnish.stem("kauniimpi"));
+/// assert!(Finnish.is_match("taloon", "talossa"));
+/// ```
+pub struct Finnish;
+
+impl Stemmer for Finnish {
+    fn stem(&self, word: &str) -> String {
+        let mut env = Env::new(&word.to_lowercase());
+        let p1 = env.region(is_vowel);
+        let p2 = env.region(is_vowel);
+        env.cursor = 0;
+
+        env.backwards();
+        env.test_b(|env| particle_etc(env, p1, p2));
+        env.test_b(|env| possessive(env, p1));
+        let ending_removed = env.test_b(|env| case_ending(env, p1));
+        env.test_b(|env| other_endings(env, p2));
+        if ending_removed {
+            env.test_b(|env| i_plural(env, p1));
+        } else {
+            env.test_b(|env| t_plural(env, p1, p2));
+        }
+        env.test_b(|env| tidy(env, p1));
+        env.forwards();
+
+        env.text()
+    }
+}
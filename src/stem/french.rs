@@ -0,0 +1,388 @@
+use stem::snowball::Env;
+use stem::stemmer::Stemmer;
+
+static STANDARD_SUFFIXES: &[&str] = &[
+    "ance", "iqUe", "isme", "able", "iste", "eux", "ances", "iqUes", "ismes", "ables", "istes",
+    "atrice", "ateur", "ation", "atrices", "ateurs", "ations", "logie", "logies", "usion", "ution",
+    "usions", "utions", "ence", "ences", "ement", "ements", "ité", "ités", "if", "ive", "ifs", "ives",
+    "eaux", "aux", "euse", "euses", "issement", "issements", "amment", "emment", "ment", "ments",
+];
+static I_VERB_SUFFIXES: &[&str] = &[
+    "îmes", "ît", "îtes", "i", "ie", "ies", "ir", "ira", "irai", "iraIent", "irais", "irait", "iras",
+    "irent", "irez", "iriez", "irions", "irons", "iront", "is", "issaIent", "issais", "issait",
+    "issant", "issante", "issantes", "issants", "isse", "issent", "isses", "issez", "issiez",
+    "issions", "issons", "it",
+];
+static VERB_SUFFIXES: &[&str] = &[
+    "ions", "é", "ée", "ées", "és", "èrent", "er", "era", "erai", "eraIent", "erais", "erait", "eras",
+    "erez", "eriez", "erions", "erons", "eront", "ez", "iez", "âmes", "ât", "âtes", "a", "ai", "aIent",
+    "ais", "ait", "ant", "ante", "antes", "ants", "as", "asse", "assent", "asses", "assiez", "assions",
+];
+
+fn is_vowel(c: char) -> bool {
+    "aeiouyâàëéêèïîôûù".contains(c)
+}
+
+/// Regions of the word: RV, R1 and R2 start at `pv`, `p1` and `p2`
+#[derive(Debug)]
+struct Regions {
+    pv: usize,
+    p1: usize,
+    p2: usize,
+}
+
+/// Mark the u, i and y between vowels, the y next to a vowel and the u after q as consonants
+fn prelude(env: &mut Env) {
+    for index in 0..env.len() {
+        let vowel = |index: usize| env.at(index).is_some_and(is_vowel);
+        let (c, next) = (env.chars[index], env.at(index + 1));
+
+        if vowel(index) && (next == Some('u') || next == Some('i')) && vowel(index + 2) {
+            env.chars[index + 1] = if next == Some('u') { 'U' } else { 'I' };
+        } else if vowel(index) && next == Some('y') {
+            env.chars[index + 1] = 'Y';
+        } else if c == 'y' && vowel(index + 1) {
+            env.chars[index] = 'Y';
+        } else if c == 'q' && next == Some('u') {
+            env.chars[index + 1] = 'U';
+        }
+    }
+}
+
+fn mark_regions(env: &mut Env) -> Regions {
+    let len = env.len();
+    let vowel = |index: usize| env.at(index).is_some_and(is_vowel);
+
+    let prefix = env.chars.iter().take(3).collect::<String>();
+
+    // Paris, colis and tapis are exceptions
+    let pv = if (vowel(0) && vowel(1) && len >= 3) || ["par", "col", "tap"].contains(&&prefix[..]) {
+        3
+    } else {
+        (1..len).find(|&index| vowel(index)).map_or(len, |index| index + 1)
+    };
+
+    env.cursor = 0;
+    let p1 = env.region(is_vowel);
+    let p2 = env.region(is_vowel);
+    env.cursor = 0;
+
+    Regions { pv, p1, p2 }
+}
+
+/// `(R2 delete) or <- replacement`
+fn delete_in_r2_or_replace(env: &mut Env, regions: &Regions, replacement: &str) {
+    if env.cursor >= regions.p2 {
+        env.slice_del();
+    } else {
+        env.slice_from(replacement);
+    }
+}
+
+/// Noun and adjective suffixes, and adverbs in -ment
+fn standard_suffix(env: &mut Env, regions: &Regions) -> bool {
+    let r1 = |env: &Env| env.cursor >= regions.p1;
+    let r2 = |env: &Env| env.cursor >= regions.p2;
+    let rv = |env: &Env| env.cursor >= regions.pv;
+
+    let suffix = match env.substring_b(STANDARD_SUFFIXES) {
+        Some(suffix) => suffix,
+        None => return false,
+    };
+
+    match suffix {
+        "ance" | "iqUe" | "isme" | "able" | "iste" | "eux" | "ances" | "iqUes" | "ismes" | "ables" | "istes" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+        },
+        "atrice" | "ateur" | "ation" | "atrices" | "ateurs" | "ations" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+            env.try_b(|env| {
+                if !env.slice_eq_b("ic") {
+                    return false;
+                }
+                delete_in_r2_or_replace(env, regions, "iqU");
+                true
+            });
+        },
+        "logie" | "logies" | "usion" | "ution" | "usions" | "utions" | "ence" | "ences" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_from(match suffix {
+                "logie" | "logies" => "log",
+                "ence" | "ences"   => "ent",
+                _                  => "u",
+            });
+        },
+        "ement" | "ements" => {
+            if !rv(env) {
+                return false;
+            }
+            env.slice_del();
+            env.try_b(|env| {
+                match env.substring_b(&["iv", "eus", "abl", "iqU", "ièr", "Ièr"]) {
+                    Some("iv") => {
+                        if !r2(env) {
+                            return false;
+                        }
+                        env.slice_del();
+                        if !env.slice_eq_b("at") || !r2(env) {
+                            return false;
+                        }
+                        env.slice_del();
+                    },
+                    Some("eus") => {
+                        if r2(env) {
+                            env.slice_del();
+                        } else if r1(env) {
+                            env.slice_from("eux");
+                        } else {
+                            return false;
+                        }
+                    },
+                    Some("abl") | Some("iqU") => {
+                        if !r2(env) {
+                            return false;
+                        }
+                        env.slice_del();
+                    },
+                    Some(_) => {
+                        if !rv(env) {
+                            return false;
+                        }
+                        env.slice_from("i");
+                    },
+                    None => return false,
+                }
+                true
+            });
+        },
+        "ité" | "ités" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+            env.try_b(|env| {
+                match env.substring_b(&["abil", "ic", "iv"]) {
+                    Some("abil") => delete_in_r2_or_replace(env, regions, "abl"),
+                    Some("ic")   => delete_in_r2_or_replace(env, regions, "iqU"),
+                    Some(_) if r2(env) => env.slice_del(),
+                    _ => return false,
+                }
+                true
+            });
+        },
+        "if" | "ive" | "ifs" | "ives" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+            env.try_b(|env| {
+                if !env.slice_eq_b("at") || !r2(env) {
+                    return false;
+                }
+                env.slice_del();
+                if !env.slice_eq_b("ic") {
+                    return false;
+                }
+                delete_in_r2_or_replace(env, regions, "iqU");
+                true
+            });
+        },
+        "eaux" => env.slice_from("eau"),
+        "aux" => {
+            if !r1(env) {
+                return false;
+            }
+            env.slice_from("al");
+        },
+        "euse" | "euses" => {
+            if r2(env) {
+                env.slice_del();
+            } else if r1(env) {
+                env.slice_from("eux");
+            } else {
+                return false;
+            }
+        },
+        "issement" | "issements" => {
+            if !r1(env) || !env.out_grouping_b(is_vowel) {
+                return false;
+            }
+            env.slice_del();
+        },
+        // The replacement is made, but the verb suffixes are still looked for
+        "amment" | "emment" => {
+            if rv(env) {
+                env.slice_from(if suffix == "amment" { "ant" } else { "ent" });
+            }
+            return false;
+        },
+        _ => {
+            if env.test_b(|env| env.in_grouping_b(is_vowel) && rv(env)) {
+                env.slice_del();
+            }
+            return false;
+        },
+    }
+
+    true
+}
+
+/// Verb suffixes starting with i, after a consonant
+fn i_verb_suffix(env: &mut Env, regions: &Regions) -> bool {
+    env.set_limit_b(regions.pv, |env| {
+        if env.substring_b(I_VERB_SUFFIXES).is_none() || !env.out_grouping_b(is_vowel) {
+            return false;
+        }
+        env.slice_del();
+        true
+    })
+}
+
+/// Other verb suffixes
+fn verb_suffix(env: &mut Env, regions: &Regions) -> bool {
+    env.set_limit_b(regions.pv, |env| {
+        match env.substring_b(VERB_SUFFIXES) {
+            Some("ions") => {
+                if env.cursor < regions.p2 {
+                    return false;
+                }
+                env.slice_del();
+            },
+            Some(suffix) if suffix.starts_with('â') || suffix.starts_with('a') => {
+                env.slice_del();
+                env.try_b(|env| {
+                    if !env.slice_eq_b("e") {
+                        return false;
+                    }
+                    env.slice_del();
+                    true
+                });
+            },
+            Some(_) => env.slice_del(),
+            None => return false,
+        }
+        true
+    })
+}
+
+/// Final s and e, -ion, -ier
+fn residual_suffix(env: &mut Env, regions: &Regions) -> bool {
+    env.try_b(|env| {
+        if !env.slice_eq_b("s") || !env.test_b(|env| env.out_grouping_b(|c| "aiouès".contains(c))) {
+            return false;
+        }
+        env.slice_del();
+        true
+    });
+
+    env.set_limit_b(regions.pv, |env| {
+        match env.substring_b(&["ion", "ier", "ière", "Ier", "Ière", "e", "ë"]) {
+            Some("ion") => {
+                if env.cursor < regions.p2 || !(env.eq_b("s") || env.eq_b("t")) {
+                    return false;
+                }
+                env.slice_del();
+            },
+            Some("e") => env.slice_del(),
+            Some("ë") => {
+                if !env.eq_b("gu") {
+                    return false;
+                }
+                env.slice_del();
+            },
+            Some(_) => env.slice_from("i"),
+            None => return false,
+        }
+        true
+    })
+}
+
+/// Undouble the final consonant of -enn, -onn, -ett, -ell and -eill
+fn un_double(env: &mut Env) {
+    if env.test_b(|env| env.among_b(&["enn", "onn", "ett", "ell", "eill"]).is_some()) {
+        env.ket = env.cursor;
+        if env.next_b() {
+            env.bra = env.cursor;
+            env.slice_del();
+        }
+    }
+}
+
+/// Unaccent the é or è before the final consonants
+fn un_accent(env: &mut Env) {
+    let mut consonants = 0;
+    while env.out_grouping_b(is_vowel) {
+        consonants += 1;
+    }
+    if consonants > 0 && (env.slice_eq_b("é") || env.slice_eq_b("è")) {
+        env.slice_from("e");
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Snowball French stemmer
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stem::stemmer::Stemmer;
+/// use nlp::stem::french::French;
+///
+/// assert_eq!("continuel", French.stem("continuellement"));
+/// assert_eq!("pur", French.stem("purées"));
+/// assert_eq!("cheval", French.stem("chevaux"));
+/// assert!(French.is_match("nationale", "nationaux"));
+/// ```
+pub struct French;
+
+impl Stemmer for French {
+    fn stem(&self, word: &str) -> String {
+        let mut env = Env::new(&word.to_lowercase());
+        prelude(&mut env);
+        let regions = mark_regions(&mut env);
+
+        env.backwards();
+        env.test_b(|env| {
+            let removed = env.test_b(|env| {
+                env.try_b(|env| standard_suffix(env, &regions)) ||
+                    env.try_b(|env| i_verb_suffix(env, &regions)) ||
+                    verb_suffix(env, &regions)
+            });
+            if removed {
+                env.try_b(|env| {
+                    if env.slice_eq_b("Y") {
+                        env.slice_from("i");
+                        true
+                    } else if env.slice_eq_b("ç") {
+                        env.slice_from("c");
+                        true
+                    } else {
+                        false
+                    }
+                });
+                true
+            } else {
+                residual_suffix(env, &regions)
+            }
+        });
+        env.test_b(|env| {
+            un_double(env);
+            true
+        });
+        env.test_b(|env| {
+            un_accent(env);
+            true
+        });
+        env.forwards();
+
+        env.text().replace('I', "i").replace('U', "u").replace('Y', "y")
+    }
+}
@@ -0,0 +1,172 @@
+use stem::snowball::Env;
+use stem::stemmer::Stemmer;
+
+fn is_vowel(c: char) -> bool {
+    "aeiouyäöü".contains(c)
+}
+
+fn is_s_ending(c: char) -> bool {
+    "bdfghklmnrt".contains(c)
+}
+
+fn is_st_ending(c: char) -> bool {
+    "bdfghklmnt".contains(c)
+}
+
+/// Replace ß by ss, and mark the u and y between vowels as consonants
+fn prelude(env: &mut Env) {
+    env.replace_chars(&[('ß', "ss")]);
+
+    for index in 0..env.len() {
+        let vowel = |index: usize| env.at(index).is_some_and(is_vowel);
+        if vowel(index) && vowel(index + 2) {
+            match env.at(index + 1) {
+                Some('u') => env.chars[index + 1] = 'U',
+                Some('y') => env.chars[index + 1] = 'Y',
+                _ => {},
+            }
+        }
+    }
+}
+
+/// R1 and R2, R1 starting after the third char at least
+fn mark_regions(env: &mut Env) -> (usize, usize) {
+    if env.len() < 3 {
+        return (env.len(), env.len());
+    }
+
+    env.cursor = 0;
+    let p1 = env.region(is_vowel);
+    let p2 = env.region(is_vowel);
+    env.cursor = 0;
+
+    (p1.max(3), p2)
+}
+
+/// Inflectional and derivational suffixes
+fn standard_suffix(env: &mut Env, p1: usize, p2: usize) {
+    env.test_b(|env| {
+        match env.substring_b(&["em", "ern", "er", "e", "en", "es", "s"]) {
+            Some(_) if env.cursor < p1 => return false,
+            Some("s") => {
+                if !env.out_grouping_b(|c| !is_s_ending(c)) {
+                    return false;
+                }
+                env.slice_del();
+            },
+            Some("e") | Some("en") | Some("es") => {
+                env.slice_del();
+                env.try_b(|env| {
+                    if !env.slice_eq_b("s") || !env.eq_b("nis") {
+                        return false;
+                    }
+                    env.slice_del();
+                    true
+                });
+            },
+            Some(_) => env.slice_del(),
+            None => return false,
+        }
+        true
+    });
+
+    env.test_b(|env| {
+        match env.substring_b(&["en", "er", "est", "st"]) {
+            Some(_) if env.cursor < p1 => return false,
+            Some("st") => {
+                if !env.out_grouping_b(|c| !is_st_ending(c)) || !env.hop_b(3) {
+                    return false;
+                }
+                env.slice_del();
+            },
+            Some(_) => env.slice_del(),
+            None => return false,
+        }
+        true
+    });
+
+    env.test_b(|env| {
+        match env.substring_b(&["end", "ung", "ig", "ik", "isch", "lich", "heit", "keit"]) {
+            Some(_) if env.cursor < p2 => return false,
+            Some("end") | Some("ung") => {
+                env.slice_del();
+                env.try_b(|env| {
+                    if !env.slice_eq_b("ig") || env.test_b(|env| env.eq_b("e")) || env.cursor < p2 {
+                        return false;
+                    }
+                    env.slice_del();
+                    true
+                });
+            },
+            Some("ig") | Some("ik") | Some("isch") => {
+                if env.test_b(|env| env.eq_b("e")) {
+                    return false;
+                }
+                env.slice_del();
+            },
+            Some("lich") | Some("heit") => {
+                env.slice_del();
+                env.try_b(|env| {
+                    if !(env.slice_eq_b("er") || env.slice_eq_b("en")) || env.cursor < p1 {
+                        return false;
+                    }
+                    env.slice_del();
+                    true
+                });
+            },
+            Some(_) => {
+                env.slice_del();
+                env.try_b(|env| {
+                    if env.substring_b(&["lich", "ig"]).is_none() || env.cursor < p2 {
+                        return false;
+                    }
+                    env.slice_del();
+                    true
+                });
+            },
+            None => return false,
+        }
+        true
+    });
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Snowball German stemmer
+///
+/// The umlauts are removed from the stems, and ß is written ss.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stem::stemmer::Stemmer;
+/// use nlp::stem::german::German;
+///
+/// assert_eq!("aufeinanderfolg", German.stem("aufeinanderfolgenden"));
+/// assert_eq!("haus", German.stem("Häuser"));
+/// assert_eq!("strass", German.stem("Straße"));
+/// assert!(German.is_match("Möglichkeit", "möglich"));
+/// ```
+pub struct German;
+
+impl Stemmer for German {
+    fn stem(&self, word: &str) -> String {
+        let mut env = Env::new(&word.to_lowercase());
+        prelude(&mut env);
+        let (p1, p2) = mark_regions(&mut env);
+
+        env.backwards();
+        standard_suffix(&mut env, p1, p2);
+        env.forwards();
+
+        env.chars
+            .iter()
+            .map(|&c| match c {
+                'Y' => 'y',
+                'U' | 'ü' => 'u',
+                'ä' => 'a',
+                'ö' => 'o',
+                _ => c,
+            })
+            .collect()
+    }
+}
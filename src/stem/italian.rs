@@ -0,0 +1,225 @@
+use stem::snowball::Env;
+use stem::stemmer::Stemmer;
+
+static PRONOUNS: &[&str] = &[
+    "ci", "gli", "la", "le", "li", "lo", "mi", "ne", "si", "ti", "vi", "sene", "gliela", "gliele", "glieli",
+    "glielo", "gliene", "mela", "mele", "meli", "melo", "mene", "tela", "tele", "teli", "telo", "tene", "cela",
+    "cele", "celi", "celo", "cene", "vela", "vele", "veli", "velo", "vene",
+];
+static STANDARD_SUFFIXES: &[&str] = &[
+    "anza", "anze", "ico", "ici", "ica", "ice", "iche", "ichi", "ismo", "ismi", "abile", "abili", "ibile",
+    "ibili", "ista", "iste", "isti", "istà", "istè", "istì", "oso", "osi", "osa", "ose", "mente", "atrice",
+    "atrici", "ante", "anti", "azione", "azioni", "atore", "atori", "logia", "logie", "uzione", "uzioni",
+    "usione", "usioni", "enza", "enze", "amento", "amenti", "imento", "imenti", "amente", "ità", "ivo", "ivi",
+    "iva", "ive",
+];
+static VERB_SUFFIXES: &[&str] = &[
+    "ammo", "ando", "ano", "are", "arono", "asse", "assero", "assi", "assimo", "ata", "ate", "ati", "ato",
+    "ava", "avamo", "avano", "avate", "avi", "avo", "emmo", "enda", "ende", "endi", "endo", "erà", "erai",
+    "eranno", "ere", "erebbe", "erebbero", "erei", "eremmo", "eremo", "ereste", "eresti", "erete", "erò",
+    "erono", "essero", "ete", "eva", "evamo", "evano", "evate", "evi", "evo", "Yamo", "iamo", "immo", "irà",
+    "irai", "iranno", "ire", "irebbe", "irebbero", "irei", "iremmo", "iremo", "ireste", "iresti", "irete",
+    "irò", "irono", "isca", "iscano", "isce", "isci", "isco", "iscono", "issero", "ita", "ite", "iti", "ito",
+    "iva", "ivamo", "ivano", "ivate", "ivi", "ivo", "ono", "uta", "ute", "uti", "uto", "ar", "ir",
+];
+
+fn is_vowel(c: char) -> bool {
+    "aeiouàèìòù".contains(c)
+}
+
+/// Write the accents grave, mark the u after q, and the u and i between vowels as consonants
+fn prelude(env: &mut Env) {
+    env.replace_chars(&[('á', "à"), ('é', "è"), ('í', "ì"), ('ó', "ò"), ('ú', "ù")]);
+    for index in 1..env.len() {
+        if env.chars[index - 1] == 'q' && env.chars[index] == 'u' {
+            env.chars[index] = 'U';
+        }
+    }
+
+    for index in 0..env.len() {
+        let vowel = |index: usize| env.at(index).is_some_and(is_vowel);
+        if vowel(index) && vowel(index + 2) {
+            match env.at(index + 1) {
+                Some('u') => env.chars[index + 1] = 'U',
+                Some('i') => env.chars[index + 1] = 'I',
+                _ => {},
+            }
+        }
+    }
+}
+
+/// Pronouns attached to an infinitive or a gerund
+fn attached_pronoun(env: &mut Env, pv: usize) -> bool {
+    if env.substring_b(PRONOUNS).is_none() {
+        return false;
+    }
+    match env.among_b(&["ando", "endo", "ar", "er", "ir"]) {
+        Some(_) if env.cursor < pv => return false,
+        Some("ando") | Some("endo") => env.slice_del(),
+        Some(_) => env.slice_from("e"),
+        None => return false,
+    }
+    true
+}
+
+/// `['s'] R2 delete`
+fn delete_in_r2(env: &mut Env, s: &str, p2: usize) -> bool {
+    if !env.slice_eq_b(s) || env.cursor < p2 {
+        return false;
+    }
+    env.slice_del();
+    true
+}
+
+fn standard_suffix(env: &mut Env, pv: usize, p1: usize, p2: usize) -> bool {
+    let r2 = |env: &Env| env.cursor >= p2;
+
+    let suffix = match env.substring_b(STANDARD_SUFFIXES) {
+        Some(suffix) => suffix,
+        None => return false,
+    };
+
+    match suffix {
+        "azione" | "azioni" | "atore" | "atori" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+            env.try_b(|env| delete_in_r2(env, "ic", p2));
+        },
+        "logia" | "logie" | "uzione" | "uzioni" | "usione" | "usioni" | "enza" | "enze" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_from(match suffix {
+                "logia" | "logie" => "log",
+                "enza" | "enze"   => "ente",
+                _                 => "u",
+            });
+        },
+        "amento" | "amenti" | "imento" | "imenti" => {
+            if env.cursor < pv {
+                return false;
+            }
+            env.slice_del();
+        },
+        "amente" => {
+            if env.cursor < p1 {
+                return false;
+            }
+            env.slice_del();
+            env.try_b(|env| {
+                let found = env.substring_b(&["iv", "os", "ic", "abil"]);
+                if found.is_none() || !r2(env) {
+                    return false;
+                }
+                env.slice_del();
+                found != Some("iv") || delete_in_r2(env, "at", p2)
+            });
+        },
+        "ità" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+            env.try_b(|env| {
+                if env.substring_b(&["abil", "ic", "iv"]).is_none() || !r2(env) {
+                    return false;
+                }
+                env.slice_del();
+                true
+            });
+        },
+        "ivo" | "ivi" | "iva" | "ive" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+            env.try_b(|env| delete_in_r2(env, "at", p2) && delete_in_r2(env, "ic", p2));
+        },
+        _ => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+        },
+    }
+    true
+}
+
+fn verb_suffix(env: &mut Env, pv: usize) -> bool {
+    env.set_limit_b(pv, |env| {
+        if env.substring_b(VERB_SUFFIXES).is_none() {
+            return false;
+        }
+        env.slice_del();
+        true
+    })
+}
+
+/// Final vowel, and h after c or g
+fn vowel_suffix(env: &mut Env, pv: usize) {
+    env.try_b(|env| {
+        env.ket = env.cursor;
+        if !env.in_grouping_b(|c| "aeioàèìò".contains(c)) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if env.cursor < pv {
+            return false;
+        }
+        env.slice_del();
+        if !env.slice_eq_b("i") || env.cursor < pv {
+            return false;
+        }
+        env.slice_del();
+        true
+    });
+    env.try_b(|env| {
+        if !env.slice_eq_b("h") || !env.in_grouping_b(|c| c == 'c' || c == 'g') || env.cursor < pv {
+            return false;
+        }
+        env.slice_del();
+        true
+    });
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Snowball Italian stemmer
+///
+/// The acute accents are written grave in the stems.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stem::stemmer::Stemmer;
+/// use nlp::stem::italian::Italian;
+///
+/// assert_eq!("abbandon", Italian.stem("abbandonata"));
+/// assert_eq!("capac", Italian.stem("capacità"));
+/// assert_eq!("mang", Italian.stem("mangiarla"));
+/// assert!(Italian.is_match("velocemente", "veloce"));
+/// ```
+pub struct Italian;
+
+impl Stemmer for Italian {
+    fn stem(&self, word: &str) -> String {
+        let mut env = Env::new(&word.to_lowercase());
+        prelude(&mut env);
+        let pv = env.rv(is_vowel);
+        let p1 = env.region(is_vowel);
+        let p2 = env.region(is_vowel);
+        env.cursor = 0;
+
+        env.backwards();
+        env.test_b(|env| attached_pronoun(env, pv));
+        env.test_b(|env| env.try_b(|env| standard_suffix(env, pv, p1, p2)) || verb_suffix(env, pv));
+        env.test_b(|env| {
+            vowel_suffix(env, pv);
+            true
+        });
+        env.forwards();
+
+        env.text().replace('I', "i").replace('U', "u")
+    }
+}
@@ -0,0 +1,30 @@
+mod snowball;
+
+/// Module stemmer
+pub mod stemmer;
+/// Module Porter stemmer
+pub mod porter;
+/// Module Snowball English (Porter2) stemmer
+pub mod english;
+/// Module Snowball French stemmer
+pub mod french;
+/// Module Snowball German stemmer
+pub mod german;
+/// Module Snowball Spanish stemmer
+pub mod spanish;
+/// Module Snowball Italian stemmer
+pub mod italian;
+/// Module Snowball Portuguese stemmer
+pub mod portuguese;
+/// Module Snowball Dutch stemmer
+pub mod dutch;
+/// Module Snowball Swedish stemmer
+pub mod swedish;
+/// Module Snowball Norwegian stemmer
+pub mod norwegian;
+/// Module Snowball Danish stemmer
+pub mod danish;
+/// Module Snowball Russian stemmer
+pub mod russian;
+/// Module Snowball Finnish stemmer
+pub mod finnish;
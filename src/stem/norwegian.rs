@@ -0,0 +1,88 @@
+use stem::snowball::Env;
+use stem::stemmer::Stemmer;
+
+static MAIN_SUFFIXES: &[&str] = &[
+    "a", "e", "ede", "ande", "ende", "ane", "ene", "hetene", "en", "heten", "ar", "er", "heter", "as", "es", "edes",
+    "endes", "enes", "hetenes", "ens", "hetens", "ers", "ets", "et", "het", "ast", "s", "erte", "ert",
+];
+static OTHER_SUFFIXES: &[&str] = &["leg", "eleg", "ig", "eig", "lig", "elig", "els", "lov", "elov", "slov", "hetslov"];
+
+fn is_vowel(c: char) -> bool {
+    "aeiouyæåø".contains(c)
+}
+
+/// R1, starting after the third char at least
+fn mark_region(env: &mut Env) -> usize {
+    if env.len() < 3 {
+        return env.len();
+    }
+    let p1 = env.region(is_vowel);
+    env.cursor = 0;
+    p1.max(3)
+}
+
+fn main_suffix(env: &mut Env, p1: usize) -> bool {
+    match env.substring_b_from(p1, MAIN_SUFFIXES) {
+        Some("s") => {
+            let valid = env.in_grouping_b(|c| "bcdfghjlmnoprtvyz".contains(c)) ||
+                env.try_b(|env| env.eq_b("k") && env.out_grouping_b(is_vowel));
+            if !valid {
+                return false;
+            }
+            env.slice_del();
+        },
+        Some("erte") | Some("ert") => env.slice_from("er"),
+        Some(_) => env.slice_del(),
+        None => return false,
+    }
+    true
+}
+
+/// Remove the t of a final dt or vt
+fn consonant_pair(env: &mut Env, p1: usize) -> bool {
+    if !env.test_b(|env| env.substring_b_from(p1, &["dt", "vt"]).is_some()) || !env.next_b() {
+        return false;
+    }
+    env.bra = env.cursor;
+    env.slice_del();
+    true
+}
+
+fn other_suffix(env: &mut Env, p1: usize) -> bool {
+    if env.substring_b_from(p1, OTHER_SUFFIXES).is_none() {
+        return false;
+    }
+    env.slice_del();
+    true
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Snowball Norwegian (Bokmål) stemmer
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stem::stemmer::Stemmer;
+/// use nlp::stem::norwegian::Norwegian;
+///
+/// assert_eq!("bil", Norwegian.stem("bilene"));
+/// assert_eq!("hus", Norwegian.stem("husets"));
+/// assert_eq!("virk", Norwegian.stem("virkeligheten"));
+/// assert!(Norwegian.is_match("kjøre", "kjører"));
+/// ```
+pub struct Norwegian;
+
+impl Stemmer for Norwegian {
+    fn stem(&self, word: &str) -> String {
+        let mut env = Env::new(&word.to_lowercase());
+        let p1 = mark_region(&mut env);
+
+        env.backwards();
+        env.test_b(|env| main_suffix(env, p1));
+        env.test_b(|env| consonant_pair(env, p1));
+        env.test_b(|env| other_suffix(env, p1));
+        env.forwards();
+
+        env.text()
+    }
+}
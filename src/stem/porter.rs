@@ -0,0 +1,192 @@
+use stem::snowball::Env;
+use stem::stemmer::Stemmer;
+
+static DOUBLES: &[&str] = &["bb", "dd", "ff", "gg", "mm", "nn", "pp", "rr", "tt"];
+static STEP_2: &[(&str, &str)] = &[
+    ("tional", "tion"), ("enci", "ence"), ("anci", "ance"), ("bli", "ble"), ("entli", "ent"),
+    ("eli", "e"), ("izer", "ize"), ("ization", "ize"), ("ational", "ate"), ("ation", "ate"),
+    ("ator", "ate"), ("alli", "al"), ("alism", "al"), ("aliti", "al"), ("fulness", "ful"),
+    ("ousli", "ous"), ("ousness", "ous"), ("iveness", "ive"), ("iviti", "ive"), ("biliti", "ble"),
+    ("logi", "log"),
+];
+static STEP_3: &[(&str, &str)] = &[
+    ("alize", "al"), ("icate", "ic"), ("iciti", "ic"), ("ical", "ic"), ("ative", ""), ("ful", ""),
+    ("ness", ""),
+];
+static STEP_4: &[&str] = &["al", "ance", "ence", "er", "ic", "able", "ible", "ant", "ement", "ment", "ent", "ou",
+                           "ism", "ate", "iti", "ous", "ive", "ize", "ion"];
+
+fn is_vowel(c: char) -> bool {
+    "aeiouy".contains(c)
+}
+
+/// Consonant, vowel, consonant before the cursor, the last consonant not being w, x or Y
+fn short_syllable(env: &mut Env) -> bool {
+    env.test_b(|env| {
+        env.out_grouping_b(|c| is_vowel(c) || "wxY".contains(c)) && env.in_grouping_b(is_vowel) && env.out_grouping_b(is_vowel)
+    })
+}
+
+/// Replace the longest suffix of the table if it starts at `from` or after
+fn replace_in_region(env: &mut Env, table: &[(&str, &str)], from: usize) -> bool {
+    let suffixes = table.iter().map(|&(suffix, _)| suffix).collect::<Vec<_>>();
+    match env.substring_b(&suffixes) {
+        Some(suffix) if env.cursor >= from => {
+            let replacement = table.iter().find(|&&(s, _)| s == suffix).map_or("", |&(_, r)| r);
+            env.slice_from(replacement);
+            true
+        },
+        _ => false,
+    }
+}
+
+/// Mark the y at the start of the word or after a vowel as consonants
+fn prelude(env: &mut Env) {
+    for index in 0..env.len() {
+        if env.chars[index] == 'y' && (index == 0 || is_vowel(env.chars[index - 1])) {
+            env.chars[index] = 'Y';
+        }
+    }
+}
+
+/// Step 1a: plurals
+fn step_1a(env: &mut Env) -> bool {
+    match env.substring_b(&["sses", "ies", "ss", "s"]) {
+        Some("sses") => env.slice_from("ss"),
+        Some("ies")  => env.slice_from("i"),
+        Some("s")    => env.slice_del(),
+        Some(_)      => {},
+        None         => return false,
+    }
+    true
+}
+
+/// Step 1b: -ed and -ing
+fn step_1b(env: &mut Env, p1: usize) -> bool {
+    match env.substring_b(&["eed", "ed", "ing"]) {
+        Some("eed") => {
+            if env.cursor < p1 {
+                return false;
+            }
+            env.slice_from("ee");
+        },
+        Some(_) => {
+            if !env.test_b(|env| env.go_past_b(is_vowel)) {
+                return false;
+            }
+            env.slice_del();
+
+            let mut ending = None;
+            env.test_b(|env| {
+                ending = env.among_b(&["at", "bl", "iz"]).or_else(|| env.among_b(DOUBLES));
+                true
+            });
+            match ending {
+                Some("at") | Some("bl") | Some("iz") => env.insert("e"),
+                Some(_) => {
+                    env.ket = env.cursor;
+                    env.next_b();
+                    env.bra = env.cursor;
+                    env.slice_del();
+                },
+                None if env.cursor == p1 && short_syllable(env) => env.insert("e"),
+                None => {},
+            }
+        },
+        None => return false,
+    }
+    true
+}
+
+/// Step 1c: final y after a vowel
+fn step_1c(env: &mut Env) -> bool {
+    if !(env.slice_eq_b("y") || env.slice_eq_b("Y")) || !env.go_past_b(is_vowel) {
+        return false;
+    }
+    env.slice_from("i");
+    true
+}
+
+/// Step 4: suffixes in R2
+fn step_4(env: &mut Env, p2: usize) -> bool {
+    match env.substring_b(STEP_4) {
+        Some(_) if env.cursor < p2 => return false,
+        Some("ion") => {
+            if !env.in_grouping_b(|c| c == 's' || c == 't') {
+                return false;
+            }
+            env.slice_del();
+        },
+        Some(_) => env.slice_del(),
+        None => return false,
+    }
+    true
+}
+
+/// Step 5a: final e
+fn step_5a(env: &mut Env, p1: usize, p2: usize) -> bool {
+    if !env.slice_eq_b("e") || !(env.cursor >= p2 || (env.cursor >= p1 && !short_syllable(env))) {
+        return false;
+    }
+    env.slice_del();
+    true
+}
+
+/// Step 5b: final double l
+fn step_5b(env: &mut Env, p2: usize) -> bool {
+    if !env.slice_eq_b("l") || env.cursor < p2 || !env.eq_b("l") {
+        return false;
+    }
+    env.slice_del();
+    true
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Porter stemmer, as published by Martin Porter in 1980
+///
+/// The rules are the ones of his reference implementation, which departs from the paper: `bli`
+/// becomes `ble` rather than `abli` becoming `able`, `logi` becomes `log`, and the words of one or
+/// two letters are left as they are. `English` is its successor.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stem::stemmer::Stemmer;
+/// use nlp::stem::porter::Porter;
+///
+/// assert_eq!("run", Porter.stem("running"));
+/// assert_eq!("gener", Porter.stem("generously"));
+/// assert_eq!("poni", Porter.stem("ponies"));
+/// assert_eq!("relat", Porter.stem("relational"));
+/// assert_eq!("apolog", Porter.stem("apology"));
+/// assert_eq!("is", Porter.stem("is"));
+/// ```
+pub struct Porter;
+
+impl Stemmer for Porter {
+    fn stem(&self, word: &str) -> String {
+        let word = word.to_lowercase();
+        if word.chars().count() <= 2 {
+            return word;
+        }
+
+        let mut env = Env::new(&word);
+        prelude(&mut env);
+        let p1 = env.region(is_vowel);
+        let p2 = env.region(is_vowel);
+        env.cursor = 0;
+
+        env.backwards();
+        env.test_b(step_1a);
+        env.test_b(|env| step_1b(env, p1));
+        env.test_b(step_1c);
+        env.test_b(|env| replace_in_region(env, STEP_2, p1));
+        env.test_b(|env| replace_in_region(env, STEP_3, p1));
+        env.test_b(|env| step_4(env, p2));
+        env.test_b(|env| step_5a(env, p1, p2));
+        env.test_b(|env| step_5b(env, p2));
+        env.forwards();
+
+        env.text().replace('Y', "y")
+    }
+}
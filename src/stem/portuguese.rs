@@ -0,0 +1,201 @@
+use stem::snowball::Env;
+use stem::stemmer::Stemmer;
+
+static STANDARD_SUFFIXES: &[&str] = &[
+    "eza", "ezas", "ico", "ica", "icos", "icas", "ismo", "ismos", "ável", "ível", "ista", "istas", "oso",
+    "osa", "osos", "osas", "amento", "amentos", "imento", "imentos", "adora", "ador", "aça~o", "adoras",
+    "adores", "aço~es", "ante", "antes", "ância", "logia", "logias", "uça~o", "uço~es", "ência", "ências",
+    "amente", "mente", "idade", "idades", "iva", "ivo", "ivas", "ivos", "ira", "iras",
+];
+static VERB_SUFFIXES: &[&str] = &[
+    "ada", "ida", "ia", "aria", "eria", "iria", "ará", "ara", "erá", "era", "irá", "ava", "asse", "esse",
+    "isse", "aste", "este", "iste", "ei", "arei", "erei", "irei", "am", "iam", "ariam", "eriam", "iriam",
+    "aram", "eram", "iram", "avam", "em", "arem", "erem", "irem", "assem", "essem", "issem", "ado", "ido",
+    "ando", "endo", "indo", "ara~o", "era~o", "ira~o", "ar", "er", "ir", "as", "adas", "idas", "ias", "arias",
+    "erias", "irias", "arás", "aras", "erás", "eras", "irás", "avas", "es", "ardes", "erdes", "irdes", "ares",
+    "eres", "ires", "asses", "esses", "isses", "astes", "estes", "istes", "is", "ais", "eis", "íeis", "aríeis",
+    "eríeis", "iríeis", "áreis", "areis", "éreis", "ereis", "íreis", "ireis", "ásseis", "ésseis", "ísseis",
+    "áveis", "ados", "idos", "ámos", "amos", "íamos", "aríamos", "eríamos", "iríamos", "áramos", "éramos",
+    "íramos", "ávamos", "emos", "aremos", "eremos", "iremos", "ássemos", "êssemos", "íssemos", "imos",
+    "armos", "ermos", "irmos", "eu", "iu", "ou", "ira", "iras",
+];
+
+fn is_vowel(c: char) -> bool {
+    "aeiouáéíóúâêô".contains(c)
+}
+
+fn standard_suffix(env: &mut Env, pv: usize, p1: usize, p2: usize) -> bool {
+    let r2 = |env: &Env| env.cursor >= p2;
+
+    let suffix = match env.substring_b(STANDARD_SUFFIXES) {
+        Some(suffix) => suffix,
+        None => return false,
+    };
+
+    match suffix {
+        "logia" | "logias" | "uça~o" | "uço~es" | "ência" | "ências" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_from(match suffix {
+                "logia" | "logias" => "log",
+                "uça~o" | "uço~es" => "u",
+                _ => "ente",
+            });
+        },
+        "amente" => {
+            if env.cursor < p1 {
+                return false;
+            }
+            env.slice_del();
+            env.try_b(|env| {
+                let found = env.substring_b(&["iv", "os", "ic", "ad"]);
+                if found.is_none() || !r2(env) {
+                    return false;
+                }
+                env.slice_del();
+                if found == Some("iv") {
+                    if !env.slice_eq_b("at") || !r2(env) {
+                        return false;
+                    }
+                    env.slice_del();
+                }
+                true
+            });
+        },
+        "mente" | "idade" | "idades" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+            let derived: &[&str] = if suffix == "mente" { &["ante", "avel", "ível"] } else { &["abil", "ic", "iv"] };
+            env.try_b(|env| {
+                if env.substring_b(derived).is_none() || !r2(env) {
+                    return false;
+                }
+                env.slice_del();
+                true
+            });
+        },
+        "iva" | "ivo" | "ivas" | "ivos" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+            env.try_b(|env| {
+                if !env.slice_eq_b("at") || !r2(env) {
+                    return false;
+                }
+                env.slice_del();
+                true
+            });
+        },
+        // -eira and -eiras are usually not verbal
+        "ira" | "iras" => {
+            if env.cursor < pv || !env.eq_b("e") {
+                return false;
+            }
+            env.slice_from("ir");
+        },
+        _ => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+        },
+    }
+    true
+}
+
+fn verb_suffix(env: &mut Env, pv: usize) -> bool {
+    env.set_limit_b(pv, |env| {
+        if env.substring_b(VERB_SUFFIXES).is_none() {
+            return false;
+        }
+        env.slice_del();
+        true
+    })
+}
+
+fn residual_suffix(env: &mut Env, pv: usize) -> bool {
+    if env.substring_b(&["os", "a", "i", "o", "á", "í", "ó"]).is_none() || env.cursor < pv {
+        return false;
+    }
+    env.slice_del();
+    true
+}
+
+/// Final e after gu or ci, and ç
+fn residual_form(env: &mut Env, pv: usize) -> bool {
+    match env.substring_b(&["e", "é", "ê", "ç"]) {
+        Some("ç") => env.slice_from("c"),
+        Some(_) => {
+            if env.cursor < pv {
+                return false;
+            }
+            env.slice_del();
+            env.ket = env.cursor;
+            let found = env.try_b(|env| env.eq_b("u") && {
+                env.bra = env.cursor;
+                env.test_b(|env| env.eq_b("g"))
+            }) || env.try_b(|env| env.eq_b("i") && {
+                env.bra = env.cursor;
+                env.test_b(|env| env.eq_b("c"))
+            });
+            if !found || env.cursor < pv {
+                return false;
+            }
+            env.slice_del();
+        },
+        None => return false,
+    }
+    true
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Snowball Portuguese stemmer
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stem::stemmer::Stemmer;
+/// use nlp::stem::portuguese::Portuguese;
+///
+/// assert_eq!("continu", Portuguese.stem("continuação"));
+/// assert_eq!("belez", Portuguese.stem("beleza"));
+/// assert_eq!("fal", Portuguese.stem("falávamos"));
+/// assert!(Portuguese.is_match("felizmente", "felizes"));
+/// ```
+pub struct Portuguese;
+
+impl Stemmer for Portuguese {
+    fn stem(&self, word: &str) -> String {
+        let mut env = Env::new(&word.to_lowercase());
+        env.replace_chars(&[('ã', "a~"), ('õ', "o~")]);
+        let pv = env.rv(is_vowel);
+        let p1 = env.region(is_vowel);
+        let p2 = env.region(is_vowel);
+        env.cursor = 0;
+
+        env.backwards();
+        env.test_b(|env| {
+            let removed = env.test_b(|env| env.try_b(|env| standard_suffix(env, pv, p1, p2)) || verb_suffix(env, pv));
+            if removed {
+                env.test_b(|env| {
+                    if !env.slice_eq_b("i") || !env.test_b(|env| env.eq_b("c")) || env.cursor < pv {
+                        return false;
+                    }
+                    env.slice_del();
+                    true
+                });
+                true
+            } else {
+                residual_suffix(env, pv)
+            }
+        });
+        env.test_b(|env| residual_form(env, pv));
+        env.forwards();
+
+        env.text().replace("a~", "ã").replace("o~", "õ")
+    }
+}
@@ -0,0 +1,131 @@
+use stem::snowball::Env;
+use stem::stemmer::Stemmer;
+
+static PERFECTIVE_GERUNDS: &[&str] = &["в", "вши", "вшись", "ив", "ивши", "ившись", "ыв", "ывши", "ывшись"];
+static ADJECTIVES: &[&str] = &[
+    "ее", "ие", "ые", "ое", "ими", "ыми", "ей", "ий", "ый", "ой", "ем", "им", "ым", "ом", "его", "ого", "ему", "ому",
+    "их", "ых", "ую", "юю", "ая", "яя", "ою", "ею",
+];
+static PARTICIPLES: &[&str] = &["ем", "нн", "вш", "ющ", "щ", "ивш", "ывш", "ующ"];
+static VERBS: &[&str] = &[
+    "ла", "на", "ете", "йте", "ли", "й", "л", "ем", "н", "ло", "но", "ет", "ют", "ны", "ть", "ешь", "нно", "ила",
+    "ыла", "ена", "ейте", "уйте", "ите", "или", "ыли", "ей", "уй", "ил", "ыл", "им", "ым", "ен", "ило", "ыло", "ено",
+    "ят", "ует", "уют", "ит", "ыт", "ены", "ить", "ыть", "ишь", "ую", "ю",
+];
+static NOUNS: &[&str] = &[
+    "а", "ев", "ов", "ие", "ье", "е", "иями", "ями", "ами", "еи", "ии", "и", "ией", "ей", "ой", "ий", "й", "иям",
+    "ям", "ием", "ем", "ам", "ом", "о", "у", "ах", "иях", "ях", "ы", "ь", "ию", "ью", "ю", "ия", "ья", "я",
+];
+// Suffixes only removed after а or я
+static AFTER_A: &[&str] = &[
+    "в", "вши", "вшись", "ем", "нн", "вш", "ющ", "щ", "ла", "на", "ете", "йте", "ли", "й", "л", "н", "ло", "но",
+    "ет", "ют", "ны", "ть", "ешь", "нно",
+];
+
+fn is_vowel(c: char) -> bool {
+    "аеиоуыэюя".contains(c)
+}
+
+/// Remove the longest suffix of the list, after а or я for the ones of `AFTER_A`
+fn remove_suffix(env: &mut Env, suffixes: &[&str], after_a: &[&str]) -> bool {
+    match env.substring_b(suffixes) {
+        Some(suffix) if after_a.contains(&suffix) => {
+            if !env.eq_b("а") && !env.eq_b("я") {
+                return false;
+            }
+            env.slice_del();
+        },
+        Some(_) => env.slice_del(),
+        None => return false,
+    }
+    true
+}
+
+/// Adjective, and participle before it
+fn adjectival(env: &mut Env) -> bool {
+    if !remove_suffix(env, ADJECTIVES, &[]) {
+        return false;
+    }
+    env.try_b(|env| remove_suffix(env, PARTICIPLES, AFTER_A));
+    true
+}
+
+/// Superlative -ейш(е), double н and soft sign
+fn tidy_up(env: &mut Env) -> bool {
+    match env.substring_b(&["ейш", "ейше", "н", "ь"]) {
+        Some("н") => {
+            if !env.eq_b("н") {
+                return false;
+            }
+            env.slice_del();
+        },
+        Some("ь") => env.slice_del(),
+        Some(_) => {
+            env.slice_del();
+            if !env.slice_eq_b("н") || !env.eq_b("н") {
+                return false;
+            }
+            env.slice_del();
+        },
+        None => return false,
+    }
+    true
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Snowball Russian stemmer
+///
+/// The letter ё is kept as is, its spelling as е is not restored.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stem::stemmer::Stemmer;
+/// use nlp::stem::russian::Russian;
+///
+/// assert_eq!("красив", Russian.stem("красивейший"));
+/// assert_eq!("книг", Russian.stem("книгами"));
+/// assert_eq!("говор", Russian.stem("говорили"));
+/// assert!(Russian.is_match("Москва", "москвой"));
+/// ```
+pub struct Russian;
+
+impl Stemmer for Russian {
+    fn stem(&self, word: &str) -> String {
+        let mut env = Env::new(&word.to_lowercase());
+        let pv = if env.go_past(is_vowel) { env.cursor } else { env.len() };
+        let p2 = if env.go_past(|c| !is_vowel(c)) { env.region(is_vowel) } else { env.len() };
+        env.cursor = 0;
+
+        env.backwards();
+        env.set_limit_b(pv, |env| {
+            env.test_b(|env| {
+                env.try_b(|env| remove_suffix(env, PERFECTIVE_GERUNDS, AFTER_A)) || {
+                    env.try_b(|env| remove_suffix(env, &["ся", "сь"], &[]));
+                    env.try_b(adjectival) ||
+                        env.try_b(|env| remove_suffix(env, VERBS, AFTER_A)) ||
+                        remove_suffix(env, NOUNS, &[])
+                }
+            });
+            env.try_b(|env| {
+                if !env.slice_eq_b("и") {
+                    return false;
+                }
+                env.slice_del();
+                true
+            });
+            env.test_b(|env| {
+                if env.substring_b(&["ост", "ость"]).is_none() || env.cursor < p2 {
+                    return false;
+                }
+                env.slice_del();
+                true
+            });
+            env.test_b(tidy_up);
+            true
+        });
+        env.forwards();
+
+        env.text()
+    }
+}
@@ -0,0 +1,276 @@
+/// State of a Snowball program: the word, the cursor and its limits, and the slice `[bra, ket)`
+/// the commands replace
+///
+/// The methods follow the Snowball commands. Most are the ones of the backward mode, with a `_b`
+/// suffix, reading the word from the end: `eq_b` is a string test, `in_grouping_b` and
+/// `out_grouping_b` test a char of the grouping or not, `among_b` finds the longest string of a
+/// list.
+#[derive(PartialEq, Clone, Debug)]
+pub(crate) struct Env {
+    pub chars: Vec<char>,
+    pub cursor: usize,
+    pub limit: usize,
+    pub limit_backward: usize,
+    pub bra: usize,
+    pub ket: usize,
+}
+
+impl Env {
+    pub fn new(word: &str) -> Env {
+        let chars = word.chars().collect::<Vec<_>>();
+        let len = chars.len();
+
+        Env { chars, cursor: 0, limit: len, limit_backward: 0, bra: 0, ket: len }
+    }
+
+    pub fn text(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// Enter the backward mode, from the end of the word
+    pub fn backwards(&mut self) {
+        self.limit_backward = self.cursor;
+        self.cursor = self.limit;
+    }
+
+    /// Leave the backward mode
+    pub fn forwards(&mut self) {
+        self.cursor = self.limit_backward;
+    }
+
+    /// Whether `s` precedes the cursor, moving before it
+    pub fn eq_b(&mut self, s: &str) -> bool {
+        let count = s.chars().count();
+        if self.cursor < self.limit_backward + count || !self.chars[self.cursor - count..self.cursor].iter().cloned().eq(s.chars()) {
+            return false;
+        }
+        self.cursor -= count;
+        true
+    }
+
+    pub fn in_grouping_b<G: Fn(char) -> bool>(&mut self, grouping: G) -> bool {
+        if self.cursor > self.limit_backward && grouping(self.chars[self.cursor - 1]) {
+            self.cursor -= 1;
+            return true;
+        }
+        false
+    }
+
+    pub fn out_grouping_b<G: Fn(char) -> bool>(&mut self, grouping: G) -> bool {
+        self.in_grouping_b(|c| !grouping(c))
+    }
+
+    /// Whether `s` follows the cursor, moving after it
+    pub fn eq(&mut self, s: &str) -> bool {
+        let count = s.chars().count();
+        if self.cursor + count > self.limit || !self.chars[self.cursor..self.cursor + count].iter().cloned().eq(s.chars()) {
+            return false;
+        }
+        self.cursor += count;
+        true
+    }
+
+    /// `next` in backward mode: move one char backward
+    pub fn next_b(&mut self) -> bool {
+        if self.cursor > self.limit_backward {
+            self.cursor -= 1;
+            return true;
+        }
+        false
+    }
+
+    /// `hop n` in backward mode
+    pub fn hop_b(&mut self, n: usize) -> bool {
+        if self.cursor < self.limit_backward + n {
+            return false;
+        }
+        self.cursor -= n;
+        true
+    }
+
+    /// `gopast grouping`: move after the next char of the grouping
+    pub fn go_past<G: Fn(char) -> bool>(&mut self, grouping: G) -> bool {
+        while self.cursor < self.limit {
+            self.cursor += 1;
+            if grouping(self.chars[self.cursor - 1]) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// `gopast grouping` in backward mode: move before the previous char of the grouping
+    pub fn go_past_b<G: Fn(char) -> bool>(&mut self, grouping: G) -> bool {
+        while self.cursor > self.limit_backward {
+            self.cursor -= 1;
+            if grouping(self.chars[self.cursor]) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Start of the region after the first non-vowel following a vowel, from the cursor, or the
+    /// limit: `gopast v gopast non-v setmark`
+    pub fn region<G: Fn(char) -> bool>(&mut self, vowel: G) -> usize {
+        if self.go_past(&vowel) && self.go_past(|c| !vowel(c)) {
+            self.cursor
+        } else {
+            self.cursor = self.limit;
+            self.limit
+        }
+    }
+
+    /// Start of RV in the Romance languages: after the next vowel when the word starts with a
+    /// vowel and a consonant or with two consonants, after the next consonant when it starts with
+    /// two vowels, after the third char when it starts with a consonant and a vowel
+    pub fn rv<G: Fn(char) -> bool>(&self, vowel: G) -> usize {
+        let len = self.len();
+        let is_vowel = |index: usize| self.at(index).map(&vowel);
+        let after = |vowels: bool| (2..len).find(|&index| is_vowel(index) == Some(vowels)).map_or(len, |index| index + 1);
+
+        match (is_vowel(0), is_vowel(1)) {
+            (Some(true), Some(true))              => after(false),
+            (Some(_), Some(false))                => after(true),
+            (Some(false), Some(true)) if len >= 3 => 3,
+            _                                     => len,
+        }
+    }
+
+    /// Longest string of the list preceding the cursor, moving before it
+    pub fn among_b<'s>(&mut self, strings: &[&'s str]) -> Option<&'s str> {
+        let found = strings
+            .iter()
+            .filter(|s| {
+                let count = s.chars().count();
+                self.cursor >= self.limit_backward + count &&
+                    self.chars[self.cursor - count..self.cursor].iter().cloned().eq(s.chars())
+            })
+            .max_by_key(|s| s.chars().count())
+            .cloned()?;
+        self.cursor -= found.chars().count();
+        Some(found)
+    }
+
+    /// `[substring] among(...)` in backward mode: the slice is the string found
+    pub fn substring_b<'s>(&mut self, strings: &[&'s str]) -> Option<&'s str> {
+        self.ket = self.cursor;
+        let found = self.among_b(strings)?;
+        self.bra = self.cursor;
+        Some(found)
+    }
+
+    /// `setlimit tomark p for ([substring]) among(...)` in backward mode: the slice is the longest
+    /// string of the list starting at `p` or after, the commands of the strings are run without limit
+    pub fn substring_b_from<'s>(&mut self, mark: usize, strings: &[&'s str]) -> Option<&'s str> {
+        if self.cursor < mark {
+            return None;
+        }
+        let limit_backward = self.limit_backward;
+        self.limit_backward = mark;
+        let found = self.substring_b(strings);
+        self.limit_backward = limit_backward;
+        found
+    }
+
+    /// `['s']` in backward mode
+    pub fn slice_eq_b(&mut self, s: &str) -> bool {
+        self.ket = self.cursor;
+        if !self.eq_b(s) {
+            return false;
+        }
+        self.bra = self.cursor;
+        true
+    }
+
+    fn replace(&mut self, bra: usize, ket: usize, s: &str) -> isize {
+        let replacement = s.chars().collect::<Vec<_>>();
+        let adjustment = replacement.len() as isize - (ket - bra) as isize;
+        self.chars.splice(bra..ket, replacement);
+
+        self.limit = (self.limit as isize + adjustment) as usize;
+        if self.cursor >= ket {
+            self.cursor = (self.cursor as isize + adjustment) as usize;
+        } else if self.cursor > bra {
+            self.cursor = bra;
+        }
+        adjustment
+    }
+
+    /// `<- s`: replace the slice
+    pub fn slice_from(&mut self, s: &str) {
+        let (bra, ket) = (self.bra, self.ket);
+        self.replace(bra, ket, s);
+    }
+
+    /// `delete`: remove the slice
+    pub fn slice_del(&mut self) {
+        self.slice_from("");
+    }
+
+    /// `<+ s`: insert `s` at the cursor, which stays before it
+    pub fn insert(&mut self, s: &str) {
+        let cursor = self.cursor;
+        self.replace(cursor, cursor, s);
+        self.cursor = cursor;
+    }
+
+    /// `-> s`: the text of the slice
+    pub fn slice_to(&self) -> String {
+        self.chars[self.bra..self.ket].iter().collect()
+    }
+
+    /// Char at `index`
+    pub fn at(&self, index: usize) -> Option<char> {
+        self.chars.get(index).cloned()
+    }
+
+    /// `try C` in backward mode: the cursor is restored when `C` fails
+    pub fn try_b<F: FnOnce(&mut Env) -> bool>(&mut self, command: F) -> bool {
+        let saved = self.limit - self.cursor;
+        let result = command(self);
+        if !result {
+            self.cursor = self.limit - saved;
+        }
+        result
+    }
+
+    /// `test C` and `do C` in backward mode: the cursor is always restored
+    pub fn test_b<F: FnOnce(&mut Env) -> bool>(&mut self, command: F) -> bool {
+        let saved = self.limit - self.cursor;
+        let result = command(self);
+        self.cursor = self.limit - saved;
+        result
+    }
+
+    /// `setlimit tomark p for C` in backward mode: `C` can't go before `p`
+    pub fn set_limit_b<F: FnOnce(&mut Env) -> bool>(&mut self, mark: usize, command: F) -> bool {
+        if self.cursor < mark {
+            return false;
+        }
+        let limit_backward = self.limit_backward;
+        self.limit_backward = mark;
+        let result = command(self);
+        self.limit_backward = limit_backward;
+        result
+    }
+
+    /// Replace every char of the table, in forward mode from the cursor
+    pub fn replace_chars(&mut self, table: &[(char, &str)]) {
+        let mut index = self.cursor;
+        while index < self.limit {
+            match table.iter().find(|&&(c, _)| c == self.chars[index]) {
+                Some(&(_, replacement)) => {
+                    let (bra, ket) = (index, index + 1);
+                    let adjustment = self.replace(bra, ket, replacement);
+                    index = (ket as isize + adjustment) as usize;
+                },
+                None => index += 1,
+            }
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use stem::snowball::Env;
+use stem::stemmer::Stemmer;
+
+static PRONOUNS: &[&str] = &["me", "se", "sela", "selo", "selas", "selos", "la", "le", "lo", "las", "les", "los", "nos"];
+static STANDARD_SUFFIXES: &[&str] = &[
+    "anza", "anzas", "ico", "ica", "icos", "icas", "ismo", "ismos", "able", "ables", "ible", "ibles", "ista",
+    "istas", "oso", "osa", "osos", "osas", "amiento", "amientos", "imiento", "imientos", "adora", "ador",
+    "ación", "adoras", "adores", "aciones", "ante", "antes", "ancia", "ancias", "logía", "logías", "ución",
+    "uciones", "encia", "encias", "amente", "mente", "idad", "idades", "iva", "ivo", "ivas", "ivos",
+];
+static Y_VERB_SUFFIXES: &[&str] = &["ya", "ye", "yan", "yen", "yeron", "yendo", "yo", "yó", "yas", "yes", "yais", "yamos"];
+static VERB_SUFFIXES: &[&str] = &[
+    "en", "es", "éis", "emos", "arían", "arías", "arán", "arás", "aríais", "aría", "aréis", "aríamos",
+    "aremos", "ará", "aré", "erían", "erías", "erán", "erás", "eríais", "ería", "eréis", "eríamos", "eremos",
+    "erá", "eré", "irían", "irías", "irán", "irás", "iríais", "iría", "iréis", "iríamos", "iremos", "irá",
+    "iré", "aba", "ada", "ida", "ía", "ara", "iera", "ad", "ed", "id", "ase", "iese", "aste", "iste", "an",
+    "aban", "ían", "aran", "ieran", "asen", "iesen", "aron", "ieron", "ado", "ido", "ando", "iendo", "ió",
+    "ar", "er", "ir", "as", "abas", "adas", "idas", "ías", "aras", "ieras", "ases", "ieses", "ís", "áis",
+    "abais", "íais", "arais", "ierais", "aseis", "ieseis", "asteis", "isteis", "ados", "idos", "amos",
+    "ábamos", "íamos", "imos", "áramos", "iéramos", "iésemos", "ásemos",
+];
+
+fn is_vowel(c: char) -> bool {
+    "aeiouáéíóúü".contains(c)
+}
+
+/// Pronouns attached to an infinitive or a gerund
+fn attached_pronoun(env: &mut Env, pv: usize) -> bool {
+    if env.substring_b(PRONOUNS).is_none() {
+        return false;
+    }
+
+    let verb = env.among_b(&["iéndo", "ándo", "ár", "ér", "ír", "ando", "iendo", "ar", "er", "ir", "yendo"]);
+    if env.cursor < pv {
+        return false;
+    }
+    match verb {
+        Some("iéndo") | Some("ándo") | Some("ár") | Some("ér") | Some("ír") => {
+            env.bra = env.cursor;
+            env.slice_from(match verb {
+                Some("iéndo") => "iendo",
+                Some("ándo")  => "ando",
+                Some("ár")    => "ar",
+                Some("ér")    => "er",
+                _             => "ir",
+            });
+        },
+        Some("yendo") => {
+            if !env.eq_b("u") {
+                return false;
+            }
+            env.slice_del();
+        },
+        Some(_) => env.slice_del(),
+        None => return false,
+    }
+    true
+}
+
+fn standard_suffix(env: &mut Env, p1: usize, p2: usize) -> bool {
+    let r2 = |env: &Env| env.cursor >= p2;
+
+    let suffix = match env.substring_b(STANDARD_SUFFIXES) {
+        Some(suffix) => suffix,
+        None => return false,
+    };
+
+    match suffix {
+        "adora" | "ador" | "ación" | "adoras" | "adores" | "aciones" | "ante" | "antes" | "ancia" | "ancias" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+            env.try_b(|env| {
+                if !env.slice_eq_b("ic") || !r2(env) {
+                    return false;
+                }
+                env.slice_del();
+                true
+            });
+        },
+        "logía" | "logías" | "ución" | "uciones" | "encia" | "encias" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_from(match suffix {
+                "logía" | "logías" => "log",
+                "ución" | "uciones" => "u",
+                _ => "ente",
+            });
+        },
+        "amente" => {
+            if env.cursor < p1 {
+                return false;
+            }
+            env.slice_del();
+            env.try_b(|env| {
+                let found = env.substring_b(&["iv", "os", "ic", "ad"]);
+                if found.is_none() || !r2(env) {
+                    return false;
+                }
+                env.slice_del();
+                if found == Some("iv") {
+                    if !env.slice_eq_b("at") || !r2(env) {
+                        return false;
+                    }
+                    env.slice_del();
+                }
+                true
+            });
+        },
+        "mente" | "idad" | "idades" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+            let derived: &[&str] = if suffix == "mente" { &["ante", "able", "ible"] } else { &["abil", "ic", "iv"] };
+            env.try_b(|env| {
+                if env.substring_b(derived).is_none() || !r2(env) {
+                    return false;
+                }
+                env.slice_del();
+                true
+            });
+        },
+        "iva" | "ivo" | "ivas" | "ivos" => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+            env.try_b(|env| {
+                if !env.slice_eq_b("at") || !r2(env) {
+                    return false;
+                }
+                env.slice_del();
+                true
+            });
+        },
+        _ => {
+            if !r2(env) {
+                return false;
+            }
+            env.slice_del();
+        },
+    }
+    true
+}
+
+/// Verb suffixes starting with y, after u
+fn y_verb_suffix(env: &mut Env, pv: usize) -> bool {
+    if env.substring_b_from(pv, Y_VERB_SUFFIXES).is_none() || !env.eq_b("u") {
+        return false;
+    }
+    env.slice_del();
+    true
+}
+
+fn verb_suffix(env: &mut Env, pv: usize) -> bool {
+    match env.substring_b_from(pv, VERB_SUFFIXES) {
+        Some("en") | Some("es") | Some("éis") | Some("emos") => {
+            env.try_b(|env| env.eq_b("u") && env.test_b(|env| env.eq_b("g")));
+            env.bra = env.cursor;
+            env.slice_del();
+        },
+        Some(_) => env.slice_del(),
+        None => return false,
+    }
+    true
+}
+
+fn residual_suffix(env: &mut Env, pv: usize) -> bool {
+    match env.substring_b(&["os", "a", "o", "á", "í", "ó", "e", "é"]) {
+        Some(_) if env.cursor < pv => return false,
+        Some("e") | Some("é") => {
+            env.slice_del();
+            env.try_b(|env| {
+                if !env.slice_eq_b("u") || !env.test_b(|env| env.eq_b("g")) || env.cursor < pv {
+                    return false;
+                }
+                env.slice_del();
+                true
+            });
+        },
+        Some(_) => env.slice_del(),
+        None => return false,
+    }
+    true
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Snowball Spanish stemmer
+///
+/// The acute accents are removed from the stems.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stem::stemmer::Stemmer;
+/// use nlp::stem::spanish::Spanish;
+///
+/// assert_eq!("cant", Spanish.stem("cantaríamos"));
+/// assert_eq!("nacional", Spanish.stem("nacionales"));
+/// assert_eq!("dic", Spanish.stem("diciéndoselo"));
+/// assert!(Spanish.is_match("cantaríamos", "cantar"));
+/// ```
+pub struct Spanish;
+
+impl Stemmer for Spanish {
+    fn stem(&self, word: &str) -> String {
+        let mut env = Env::new(&word.to_lowercase());
+        let pv = env.rv(is_vowel);
+        let p1 = env.region(is_vowel);
+        let p2 = env.region(is_vowel);
+        env.cursor = 0;
+
+        env.backwards();
+        env.test_b(|env| attached_pronoun(env, pv));
+        env.test_b(|env| {
+            env.try_b(|env| standard_suffix(env, p1, p2)) || env.try_b(|env| y_verb_suffix(env, pv)) || verb_suffix(env, pv)
+        });
+        env.test_b(|env| residual_suffix(env, pv));
+        env.forwards();
+
+        env.chars
+            .iter()
+            .map(|&c| match c {
+                'á' => 'a',
+                'é' => 'e',
+                'í' => 'i',
+                'ó' => 'o',
+                'ú' => 'u',
+                _ => c,
+            })
+            .collect()
+    }
+}
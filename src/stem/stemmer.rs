@@ -0,0 +1,45 @@
+/// Common interface of the stemming algorithms
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stem::stemmer::Stemmer;
+/// use nlp::stem::english::English;
+/// use nlp::stem::porter::Porter;
+/// use nlp::distance::levenshtein;
+///
+/// let stemmers: Vec<Box<dyn Stemmer>> = vec![Box::new(Porter), Box::new(English)];
+/// for stemmer in &stemmers {
+///     assert_eq!(0, levenshtein(&stemmer.stem("running"), &stemmer.stem("runs")));
+///     assert!(stemmer.is_match("Connected", "connection"));
+/// }
+/// ```
+pub trait Stemmer {
+    /// Stem of a word, lowercased first
+    fn stem(&self, word: &str) -> String;
+
+    /// Whether two words have the same stem
+    fn is_match(&self, a: &str, b: &str) -> bool {
+        self.stem(a) == self.stem(b)
+    }
+}
+
+impl<S: Stemmer + ?Sized> Stemmer for &S {
+    fn stem(&self, word: &str) -> String {
+        (**self).stem(word)
+    }
+
+    fn is_match(&self, a: &str, b: &str) -> bool {
+        (**self).is_match(a, b)
+    }
+}
+
+impl<S: Stemmer + ?Sized> Stemmer for Box<S> {
+    fn stem(&self, word: &str) -> String {
+        (**self).stem(word)
+    }
+
+    fn is_match(&self, a: &str, b: &str) -> bool {
+        (**self).is_match(a, b)
+    }
+}
@@ -0,0 +1,93 @@
+use stem::snowball::Env;
+use stem::stemmer::Stemmer;
+
+static MAIN_SUFFIXES: &[&str] = &[
+    "a", "arna", "erna", "heterna", "orna", "ad", "e", "ade", "ande", "arne", "are", "aste", "en", "anden", "aren",
+    "heten", "ern", "ar", "er", "heter", "or", "as", "arnas", "ernas", "ornas", "es", "ades", "andes", "ens",
+    "arens", "hetens", "erns", "at", "andet", "het", "ast", "s",
+];
+
+fn is_vowel(c: char) -> bool {
+    "aeiouyäåö".contains(c)
+}
+
+/// R1, starting after the third char at least
+fn mark_region(env: &mut Env) -> usize {
+    if env.len() < 3 {
+        return env.len();
+    }
+    let p1 = env.region(is_vowel);
+    env.cursor = 0;
+    p1.max(3)
+}
+
+fn main_suffix(env: &mut Env, p1: usize) -> bool {
+    match env.substring_b_from(p1, MAIN_SUFFIXES) {
+        Some("s") => {
+            if !env.in_grouping_b(|c| "bcdfghjklmnoprtvy".contains(c)) {
+                return false;
+            }
+            env.slice_del();
+        },
+        Some(_) => env.slice_del(),
+        None => return false,
+    }
+    true
+}
+
+/// Remove the last char of a final consonant pair
+fn consonant_pair(env: &mut Env, p1: usize) -> bool {
+    env.set_limit_b(p1, |env| {
+        if !env.test_b(|env| env.among_b(&["dd", "gd", "nn", "dt", "gt", "kt", "tt"]).is_some()) {
+            return false;
+        }
+        env.ket = env.cursor;
+        env.next_b();
+        env.bra = env.cursor;
+        env.slice_del();
+        true
+    })
+}
+
+fn other_suffix(env: &mut Env, p1: usize) -> bool {
+    env.set_limit_b(p1, |env| {
+        match env.substring_b(&["lig", "ig", "els", "löst", "fullt"]) {
+            Some("löst") => env.slice_from("lös"),
+            Some("fullt") => env.slice_from("full"),
+            Some(_) => env.slice_del(),
+            None => return false,
+        }
+        true
+    })
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+/// Snowball Swedish stemmer
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stem::stemmer::Stemmer;
+/// use nlp::stem::swedish::Swedish;
+///
+/// assert_eq!("flick", Swedish.stem("flickorna"));
+/// assert_eq!("hjälp", Swedish.stem("hjälper"));
+/// assert_eq!("kärleksfull", Swedish.stem("kärleksfullt"));
+/// assert!(Swedish.is_match("flickor", "flickorna"));
+/// ```
+pub struct Swedish;
+
+impl Stemmer for Swedish {
+    fn stem(&self, word: &str) -> String {
+        let mut env = Env::new(&word.to_lowercase());
+        let p1 = mark_region(&mut env);
+
+        env.backwards();
+        env.test_b(|env| main_suffix(env, p1));
+        env.test_b(|env| consonant_pair(env, p1));
+        env.test_b(|env| other_suffix(env, p1));
+        env.forwards();
+
+        env.text()
+    }
+}
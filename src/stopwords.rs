@@ -0,0 +1,225 @@
+use std::collections::HashSet;
+use std::io::{self, BufRead};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+/// Language of a built-in stopword list
+pub enum Language {
+    /// English
+    English,
+    /// French
+    French,
+    /// German
+    German,
+    /// Spanish
+    Spanish,
+    /// Italian
+    Italian,
+    /// Portuguese
+    Portuguese,
+    /// Dutch
+    Dutch,
+    /// Swedish
+    Swedish,
+    /// Norwegian
+    Norwegian,
+    /// Danish
+    Danish,
+    /// Russian
+    Russian,
+    /// Finnish
+    Finnish,
+}
+
+impl Language {
+    /// Built-in stopwords of the language, one per line
+    fn list(self) -> &'static str {
+        match self {
+            Language::English    => include_str!("stopwords/english.txt"),
+            Language::French     => include_str!("stopwords/french.txt"),
+            Language::German     => include_str!("stopwords/german.txt"),
+            Language::Spanish    => include_str!("stopwords/spanish.txt"),
+            Language::Italian    => include_str!("stopwords/italian.txt"),
+            Language::Portuguese => include_str!("stopwords/portuguese.txt"),
+            Language::Dutch      => include_str!("stopwords/dutch.txt"),
+            Language::Swedish    => include_str!("stopwords/swedish.txt"),
+            Language::Norwegian  => include_str!("stopwords/norwegian.txt"),
+            Language::Danish     => include_str!("stopwords/danish.txt"),
+            Language::Russian    => include_str!("stopwords/russian.txt"),
+            Language::Finnish    => include_str!("stopwords/finnish.txt"),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+/// Set of stopwords, compared without case
+///
+/// The built-in lists are the ones of the Snowball project, also shipped by PostgreSQL, and are
+/// embedded in the library. Custom lists have one word per line.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::stopwords::{Language, Stopwords};
+/// use nlp::distance::jaro_winkler;
+///
+/// let stopwords = Stopwords::for_language(Language::English);
+/// assert!(stopwords.contains("The"));
+/// assert!(!stopwords.contains("lord"));
+///
+/// let a = stopwords.filter("The Lord of the Rings".split(' ')).collect::<Vec<_>>().join(" ");
+/// let b = stopwords.filter("Lord of Rings".split(' ')).collect::<Vec<_>>().join(" ");
+/// assert_eq!("Lord Rings", a);
+/// assert_eq!(1.0, jaro_winkler(&a, &b));
+/// ```
+pub struct Stopwords {
+    words: HashSet<String>,
+}
+
+impl Stopwords {
+    /// Empty set
+    pub fn new() -> Stopwords {
+        Stopwords::default()
+    }
+
+    /// Built-in stopwords of a language
+    pub fn for_language(language: Language) -> Stopwords {
+        let mut stopwords = Stopwords::new();
+        stopwords.add_language(language);
+        stopwords
+    }
+
+    /// Stopwords of a reader, one per line. The empty lines and the lines starting with `#` are
+    /// skipped, and the white spaces around the words are trimmed.
+    pub fn load<R: BufRead>(reader: R) -> io::Result<Stopwords> {
+        let mut stopwords = Stopwords::new();
+        for line in reader.lines() {
+            let line = line?;
+            let word = line.trim();
+            if !word.is_empty() && !word.starts_with('#') {
+                stopwords.add(word);
+            }
+        }
+
+        Ok(stopwords)
+    }
+
+    /// Add the built-in stopwords of a language, for texts mixing languages
+    pub fn add_language(&mut self, language: Language) {
+        for word in language.list().lines() {
+            self.add(word);
+        }
+    }
+
+    /// Add a stopword
+    pub fn add(&mut self, word: &str) {
+        self.words.insert(word.to_lowercase());
+    }
+
+    /// Remove a stopword, whether it was one
+    pub fn remove(&mut self, word: &str) -> bool {
+        self.words.remove(&word.to_lowercase())
+    }
+
+    /// Whether the word is a stopword, whatever its case
+    pub fn contains(&self, word: &str) -> bool {
+        if word.chars().any(char::is_uppercase) {
+            self.words.contains(&word.to_lowercase())
+        } else {
+            self.words.contains(word)
+        }
+    }
+
+    /// Number of stopwords
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Whether there's no stopword
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Stopwords, lowercased and sorted
+    pub fn words(&self) -> Vec<&str> {
+        let mut words = self.words.iter().map(|word| &word[..]).collect::<Vec<_>>();
+        words.sort();
+        words
+    }
+
+    /// Tokens which are not stopwords: strings, or the tokens of `WordTokenizer`
+    pub fn filter<I>(&self, tokens: I) -> Filter<'_, I::IntoIter>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        Filter { stopwords: self, tokens: tokens.into_iter() }
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Iterator over the tokens which are not stopwords, see `Stopwords::filter`
+pub struct Filter<'a, I> {
+    stopwords: &'a Stopwords,
+    tokens: I,
+}
+
+impl<'a, I> Iterator for Filter<'a, I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let stopwords = self.stopwords;
+        self.tokens.find(|token| !stopwords.contains(token.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.tokens.size_hint().1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokenize::word::WordTokenizer;
+
+    #[test]
+    fn stopwords_languages() {
+        let languages = [(Language::English, "the"), (Language::French, "avec"), (Language::German, "und"),
+                         (Language::Spanish, "los"), (Language::Italian, "della"), (Language::Portuguese, "não"),
+                         (Language::Dutch, "het"), (Language::Swedish, "och"), (Language::Norwegian, "ikkje"),
+                         (Language::Danish, "ikke"), (Language::Russian, "когда"), (Language::Finnish, "että")];
+        for &(language, word) in &languages {
+            let stopwords = Stopwords::for_language(language);
+            assert!(stopwords.len() > 50, "{:?}", language);
+            assert!(stopwords.contains(word), "{:?} in {:?}", word, language);
+            assert!(stopwords.contains(&word.to_uppercase()), "{:?} in {:?}", word, language);
+        }
+    }
+
+    #[test]
+    fn stopwords_load() {
+        let mut stopwords = Stopwords::load(&b"# custom list\nInc\n\n ltd \n"[..]).unwrap();
+        assert_eq!(vec!["inc", "ltd"], stopwords.words());
+        assert!(stopwords.contains("INC"));
+        assert!(stopwords.remove("Ltd"));
+        assert!(!stopwords.remove("ltd"));
+        stopwords.add_language(Language::English);
+        assert!(stopwords.contains("inc") && stopwords.contains("and"));
+    }
+
+    #[test]
+    fn stopwords_filter() {
+        let stopwords = Stopwords::for_language(Language::French);
+        let tokens = vec!["Le".to_owned(), "Petit".to_owned(), "Prince".to_owned(), "de".to_owned(), "la".to_owned()];
+        assert_eq!(vec!["Petit", "Prince"], stopwords.filter(&tokens).collect::<Vec<_>>());
+        assert_eq!(0, Stopwords::new().filter(Vec::<&str>::new()).count());
+        assert_eq!(3, Stopwords::new().filter(vec!["a", "b", "c"]).count());
+
+        let tokens = WordTokenizer::new().punctuation(false).tokenize("Where the Wild Things Are");
+        let words = Stopwords::for_language(Language::English).filter(&tokens).map(|token| token.text).collect::<Vec<_>>();
+        assert_eq!(vec!["Wild", "Things"], words);
+    }
+}
@@ -0,0 +1,8 @@
+/// Module word tokenizer
+pub mod word;
+/// Module sentence splitter
+pub mod sentence;
+/// Module Punkt sentence boundary model
+pub mod punkt;
+/// Module n-grams, skip-grams and everygrams
+pub mod ngram;
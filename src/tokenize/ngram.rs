@@ -0,0 +1,346 @@
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Byte offsets of the grapheme clusters of a text, and of its end
+fn boundaries(text: &str) -> Vec<usize> {
+    text.grapheme_indices(true).map(|(index, _)| index).chain(Some(text.len())).collect()
+}
+
+#[derive(Clone, Debug)]
+/// Iterator over the character n-grams of a text, see `char_ngrams` and `char_everygrams`
+pub struct CharNgrams<'a> {
+    text: &'a str,
+    boundaries: Vec<usize>,
+    min: usize,
+    max: usize,
+    start: usize,
+    n: usize,
+}
+
+impl<'a> CharNgrams<'a> {
+    fn new(text: &'a str, min: usize, max: usize) -> CharNgrams<'a> {
+        CharNgrams { text, boundaries: boundaries(text), min: min.max(1), max, start: 0, n: min.max(1) }
+    }
+}
+
+impl<'a> Iterator for CharNgrams<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let units = self.boundaries.len() - 1;
+        while self.start + self.min <= units && self.min <= self.max {
+            let n = self.n;
+            if n > self.max || self.start + n > units {
+                self.start += 1;
+                self.n = self.min;
+                continue;
+            }
+            self.n += 1;
+            return Some(&self.text[self.boundaries[self.start]..self.boundaries[self.start + n]]);
+        }
+
+        None
+    }
+}
+
+/// Character n-grams of a text, as slices of the text
+///
+/// The characters are the grapheme clusters of Unicode: "é" is one character, precomposed or
+/// not. There's no n-gram if the text has less than `n` characters, or if `n` is `0`.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::tokenize::ngram::char_ngrams;
+///
+/// assert_eq!(vec!["pu", "ur", "ré", "ée"], char_ngrams("purée", 2).collect::<Vec<_>>());
+/// assert_eq!(vec!["e\u{301}t", "te"], char_ngrams("e\u{301}te", 2).collect::<Vec<_>>());
+/// assert_eq!(0, char_ngrams("pu", 3).count());
+/// ```
+pub fn char_ngrams(text: &str, n: usize) -> CharNgrams<'_> {
+    CharNgrams::new(text, n, n)
+}
+
+/// Character n-grams of a text for every n from 1 to `max_n`, grouped by start
+///
+/// Examples:
+///
+/// ```
+/// use nlp::tokenize::ngram::char_everygrams;
+///
+/// assert_eq!(vec!["a", "ab", "b", "bc", "c"], char_everygrams("abc", 2).collect::<Vec<_>>());
+/// ```
+pub fn char_everygrams(text: &str, max_n: usize) -> CharNgrams<'_> {
+    CharNgrams::new(text, 1, max_n)
+}
+
+#[derive(Clone, Debug)]
+/// Iterator over the character n-grams of a padded text, see `padded_char_ngrams`
+pub struct PaddedCharNgrams<'a> {
+    text: &'a str,
+    boundaries: Vec<usize>,
+    pad: char,
+    n: usize,
+    index: usize,
+}
+
+impl<'a> Iterator for PaddedCharNgrams<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Cow<'a, str>> {
+        let units = self.boundaries.len() - 1;
+        if self.n == 0 || units == 0 || self.index + 1 >= units + self.n {
+            return None;
+        }
+
+        // Window [index, index + n) of the text padded with n - 1 chars on both sides
+        let (n, index) = (self.n, self.index);
+        self.index += 1;
+        let left = (n - 1).saturating_sub(index);
+        let right = (index + 1).saturating_sub(units);
+        let first = index + left + 1 - n;
+        let last = index + 1 - right;
+        let slice = &self.text[self.boundaries[first]..self.boundaries[last]];
+
+        if left == 0 && right == 0 {
+            return Some(Cow::Borrowed(slice));
+        }
+        let mut ngram = String::with_capacity(slice.len() + (left + right) * self.pad.len_utf8());
+        ngram.extend((0..left).map(|_| self.pad));
+        ngram.push_str(slice);
+        ngram.extend((0..right).map(|_| self.pad));
+
+        Some(Cow::Owned(ngram))
+    }
+}
+
+/// Character n-grams of a text padded with `n - 1` times `pad` on both sides
+///
+/// The n-grams inside the text are borrowed, only the ones with padding are allocated.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::tokenize::ngram::padded_char_ngrams;
+///
+/// assert_eq!(vec!["_a", "ab", "b_"], padded_char_ngrams("ab", 2, '_').collect::<Vec<_>>());
+/// assert_eq!(vec!["##a", "#ab", "ab#", "b##"], padded_char_ngrams("ab", 3, '#').collect::<Vec<_>>());
+/// ```
+pub fn padded_char_ngrams(text: &str, n: usize, pad: char) -> PaddedCharNgrams<'_> {
+    PaddedCharNgrams { text, boundaries: boundaries(text), pad, n, index: 0 }
+}
+
+#[derive(Clone, Debug)]
+/// Iterator over the n-grams of a slice of tokens, see `word_ngrams` and `everygrams`
+pub struct Ngrams<'a, T: 'a> {
+    tokens: &'a [T],
+    min: usize,
+    max: usize,
+    start: usize,
+    n: usize,
+}
+
+impl<'a, T> Iterator for Ngrams<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        while self.start + self.min <= self.tokens.len() && self.min <= self.max {
+            let n = self.n;
+            if n > self.max || self.start + n > self.tokens.len() {
+                self.start += 1;
+                self.n = self.min;
+                continue;
+            }
+            self.n += 1;
+            return Some(&self.tokens[self.start..self.start + n]);
+        }
+
+        None
+    }
+}
+
+/// Word n-grams of a slice of tokens, as sub-slices
+///
+/// Any token type works: `&str`, `String`, or the tokens of `tokenize::word`.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::tokenize::ngram::word_ngrams;
+/// use nlp::tokenize::word::words;
+///
+/// let words = words("The cat sat down");
+/// let bigrams = word_ngrams(&words, 2).map(|ngram| ngram.join(" ")).collect::<Vec<_>>();
+/// assert_eq!(vec!["The cat", "cat sat", "sat down"], bigrams);
+/// ```
+pub fn word_ngrams<T>(tokens: &[T], n: usize) -> Ngrams<'_, T> {
+    Ngrams { tokens, min: n.max(1), max: n, start: 0, n: n.max(1) }
+}
+
+/// Word n-grams of a slice of tokens for every n from 1 to `max_n`, grouped by start
+///
+/// Examples:
+///
+/// ```
+/// use nlp::tokenize::ngram::everygrams;
+///
+/// let grams = everygrams(&["a", "b", "c"], 2).collect::<Vec<_>>();
+/// assert_eq!(vec![&["a"][..], &["a", "b"], &["b"], &["b", "c"], &["c"]], grams);
+/// ```
+pub fn everygrams<T>(tokens: &[T], max_n: usize) -> Ngrams<'_, T> {
+    Ngrams { tokens, min: 1, max: max_n, start: 0, n: 1 }
+}
+
+#[derive(Clone, Debug)]
+/// Iterator over the n-grams of a padded slice of tokens, see `padded_word_ngrams`
+pub struct PaddedNgrams<'a, T: 'a> {
+    tokens: &'a [T],
+    left: &'a str,
+    right: &'a str,
+    n: usize,
+    index: usize,
+}
+
+impl<'a, T: AsRef<str>> Iterator for PaddedNgrams<'a, T> {
+    type Item = Vec<&'a str>;
+
+    fn next(&mut self) -> Option<Vec<&'a str>> {
+        let len = self.tokens.len();
+        if self.n == 0 || len == 0 || self.index + 1 >= len + self.n {
+            return None;
+        }
+
+        let (n, index) = (self.n, self.index);
+        self.index += 1;
+        let ngram = (index..index + n)
+            .map(|i| match i.checked_sub(n - 1) {
+                None                    => self.left,
+                Some(i) if i >= len     => self.right,
+                Some(i)                 => self.tokens[i].as_ref(),
+            })
+            .collect();
+
+        Some(ngram)
+    }
+}
+
+/// Word n-grams of a slice of tokens padded with `n - 1` times `left` before and `right` after
+///
+/// Examples:
+///
+/// ```
+/// use nlp::tokenize::ngram::padded_word_ngrams;
+///
+/// let bigrams = padded_word_ngrams(&["I", "am"], 2, "<s>", "</s>").collect::<Vec<_>>();
+/// assert_eq!(vec![vec!["<s>", "I"], vec!["I", "am"], vec!["am", "</s>"]], bigrams);
+/// ```
+pub fn padded_word_ngrams<'a, T: AsRef<str>>(tokens: &'a [T], n: usize, left: &'a str, right: &'a str) -> PaddedNgrams<'a, T> {
+    PaddedNgrams { tokens, left, right, n, index: 0 }
+}
+
+#[derive(Clone, Debug)]
+/// Iterator over the skip-grams of a slice of tokens, see `skipgrams`
+pub struct Skipgrams<'a, T: 'a> {
+    tokens: &'a [T],
+    start: usize,
+    // Offsets of the tokens after the first one, from the start
+    offsets: Vec<usize>,
+    n: usize,
+    k: usize,
+}
+
+impl<'a, T> Skipgrams<'a, T> {
+    /// Highest offset from the start
+    fn last_offset(&self) -> usize {
+        (self.n - 1 + self.k).min(self.tokens.len().saturating_sub(self.start + 1))
+    }
+
+    /// Move to the next combination of offsets, or to the next start
+    fn advance(&mut self) {
+        let (last, count) = (self.last_offset(), self.offsets.len());
+        if let Some(j) = (0..count).rev().find(|&j| self.offsets[j] < last + j + 1 - count) {
+            self.offsets[j] += 1;
+            for i in j + 1..count {
+                self.offsets[i] = self.offsets[i - 1] + 1;
+            }
+        } else {
+            self.start += 1;
+            for (i, offset) in self.offsets.iter_mut().enumerate() {
+                *offset = i + 1;
+            }
+        }
+    }
+}
+
+impl<'a, T> Iterator for Skipgrams<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Vec<&'a T>> {
+        if self.n == 0 || self.start + self.n > self.tokens.len() {
+            return None;
+        }
+
+        let tokens = self.tokens;
+        let ngram = Some(&tokens[self.start])
+            .into_iter()
+            .chain(self.offsets.iter().map(|&offset| &tokens[self.start + offset]))
+            .collect();
+        self.advance();
+
+        Some(ngram)
+    }
+}
+
+/// Skip-grams of a slice of tokens: the n-grams skipping `k` tokens at most
+///
+/// Examples:
+///
+/// ```
+/// use nlp::tokenize::ngram::skipgrams;
+///
+/// let grams = skipgrams(&["a", "b", "c", "d"], 2, 1).collect::<Vec<_>>();
+/// assert_eq!(vec![vec![&"a", &"b"], vec![&"a", &"c"], vec![&"b", &"c"], vec![&"b", &"d"], vec![&"c", &"d"]], grams);
+/// ```
+pub fn skipgrams<T>(tokens: &[T], n: usize, k: usize) -> Skipgrams<'_, T> {
+    Skipgrams { tokens, start: 0, offsets: (1..n).collect(), n, k }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ngrams_chars() {
+        assert_eq!(vec!["naï", "aïv", "ïve"], char_ngrams("naïve", 3).collect::<Vec<_>>());
+        assert_eq!(vec!["👍🏽!"], char_ngrams("👍🏽!", 2).collect::<Vec<_>>());
+        assert_eq!(0, char_ngrams("abc", 0).count());
+        assert_eq!(0, char_everygrams("", 3).count());
+        assert_eq!(6, char_everygrams("abc", 5).count());
+    }
+
+    #[test]
+    fn ngrams_padding() {
+        let grams = padded_char_ngrams("é", 2, '$').collect::<Vec<_>>();
+        assert_eq!(vec!["$é", "é$"], grams);
+        let grams = padded_char_ngrams("abc", 2, '$').collect::<Vec<_>>();
+        assert!(matches!(grams[1], Cow::Borrowed("ab")));
+        assert_eq!(0, padded_char_ngrams("", 2, '$').count());
+        assert_eq!(vec!["a", "b"], padded_char_ngrams("ab", 1, '$').collect::<Vec<_>>());
+
+        let grams = padded_word_ngrams(&["a"], 3, "<", ">").collect::<Vec<_>>();
+        assert_eq!(vec![vec!["<", "<", "a"], vec!["<", "a", ">"], vec!["a", ">", ">"]], grams);
+    }
+
+    #[test]
+    fn ngrams_skipgrams() {
+        let tokens = ["a", "b", "c", "d", "e"];
+        assert_eq!(5, skipgrams(&tokens, 1, 2).count());
+        assert_eq!(4, skipgrams(&tokens, 2, 0).count());
+        let grams = skipgrams(&tokens, 3, 1).collect::<Vec<_>>();
+        assert_eq!(vec![&"a", &"b", &"c"], grams[0]);
+        assert_eq!(vec![&"a", &"c", &"d"], grams[2]);
+        assert_eq!(vec![&"c", &"d", &"e"], grams[grams.len() - 1]);
+        assert_eq!(7, grams.len());
+        assert_eq!(0, skipgrams(&tokens, 6, 1).count());
+    }
+}
@@ -0,0 +1,353 @@
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufRead, Write};
+
+/// Minimal score of an abbreviation
+const ABBREVIATION: f64 = 0.3;
+/// Minimal log likelihood of a collocation
+const COLLOCATION: f64 = 7.39;
+/// Minimal log likelihood of a frequent sentence starter
+const SENTENCE_STARTER: f64 = 30.0;
+/// Type of all the numbers
+pub(crate) const NUMBER: &str = "##number##";
+
+const BEG_UC: u8 = 1;
+const MID_UC: u8 = 1 << 1;
+const UNK_UC: u8 = 1 << 2;
+const BEG_LC: u8 = 1 << 3;
+const MID_LC: u8 = 1 << 4;
+const UNK_LC: u8 = 1 << 5;
+const ORTHO_UC: u8 = BEG_UC | MID_UC | UNK_UC;
+const ORTHO_LC: u8 = BEG_LC | MID_LC | UNK_LC;
+
+static OPENING: &str = "\"'([{\u{201C}\u{2018}\u{00AB}";
+static CLOSING: &str = "\"')]}\u{201D}\u{2019}\u{00BB},;:";
+
+#[derive(PartialEq, Clone, Debug, Default)]
+/// Punkt sentence boundary model (Kiss & Strunk, 2006)
+///
+/// The model is learnt without supervision from a plain text of the language and domain to split:
+/// it finds the abbreviations, the collocations hiding a sentence break ("3. Oktober"), the
+/// frequent sentence starters, and the case each word takes at the start and in the middle of the
+/// sentences.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::tokenize::punkt::PunktModel;
+///
+/// let text = "Call Tel. 555 0100 today. The desk is open. Tel. 555 0101 is the fax. Call it now. \
+///             Ask for Tel. 555 0102 later. We are here. Tel. 555 0103 works too. It is free. \
+///             Try Tel. 555 0104 first. Tel. 555 0105 is closed.";
+/// let model = PunktModel::train(text);
+/// assert!(model.is_abbreviation("Tel."));
+/// assert!(!model.is_abbreviation("free"));
+///
+/// let mut saved = Vec::new();
+/// model.save(&mut saved).unwrap();
+/// assert_eq!(model, PunktModel::load(&saved[..]).unwrap());
+/// ```
+pub struct PunktModel {
+    abbreviations: HashSet<String>,
+    collocations: HashSet<(String, String)>,
+    sentence_starters: HashSet<String>,
+    ortho_context: HashMap<String, u8>,
+}
+
+/// Token of the training text
+#[derive(Debug)]
+struct Token {
+    typ: String,
+    period_final: bool,
+    upper: bool,
+    lower: bool,
+    initial: bool,
+    abbreviation: bool,
+    sentence_break: bool,
+}
+
+impl Token {
+    fn new(word: &str) -> Option<Token> {
+        let word = word.trim_start_matches(|c| OPENING.contains(c)).trim_end_matches(|c| CLOSING.contains(c));
+        if !word.chars().any(char::is_alphanumeric) {
+            return None;
+        }
+
+        let period_final = word.ends_with('.');
+        let first = word.chars().next()?;
+        let typ = word_type(word);
+
+        Some(Token {
+            initial: period_final && word.chars().count() == 2 && first.is_alphabetic(),
+            sentence_break: word.ends_with('!') || word.ends_with('?'),
+            upper: first.is_uppercase(),
+            lower: first.is_lowercase(),
+            abbreviation: false,
+            period_final,
+            typ,
+        })
+    }
+
+    fn is_number(&self) -> bool {
+        self.typ == NUMBER
+    }
+}
+
+/// Type of a word: lowercased, without its final period, and the same for all the numbers
+pub(crate) fn word_type(word: &str) -> String {
+    let word = word
+        .trim_start_matches(|c| OPENING.contains(c))
+        .trim_end_matches(|c| CLOSING.contains(c) || "!?".contains(c));
+    let word = word.strip_suffix('.').unwrap_or(word);
+
+    let is_number = word.chars().next().is_some_and(|c| c.is_ascii_digit()) &&
+        word.chars().all(|c| c.is_ascii_digit() || ",.-".contains(c));
+    if is_number { NUMBER.to_owned() } else { word.to_lowercase() }
+}
+
+/// Log likelihood of `count_ab` for the abbreviations, against a probability of 0.99 of being
+/// followed by a period
+fn dunning_log_likelihood(count_a: f64, count_b: f64, count_ab: f64, n: f64) -> f64 {
+    let p1 = count_b / n;
+    let p2: f64 = 0.99;
+    let null_hypothesis = count_ab * p1.ln() + (count_a - count_ab) * (1.0 - p1).ln();
+    let alternative_hypothesis = count_ab * p2.ln() + (count_a - count_ab) * (1.0 - p2).ln();
+
+    -2.0 * (null_hypothesis - alternative_hypothesis)
+}
+
+/// Log likelihood of `a` and `b` occurring together `count_ab` times
+fn collocation_log_likelihood(count_a: f64, count_b: f64, count_ab: f64, n: f64) -> f64 {
+    let p = count_b / n;
+    let p1 = count_ab / count_a;
+    let p2 = (count_b - count_ab) / (n - count_a);
+
+    let summand1 = count_ab * p.ln() + (count_a - count_ab) * (1.0 - p).ln();
+    let summand2 = (count_b - count_ab) * p.ln() + (n - count_a - count_b + count_ab) * (1.0 - p).ln();
+    let summand3 = if count_a == count_ab { 0.0 } else { count_ab * p1.ln() + (count_a - count_ab) * (1.0 - p1).ln() };
+    let summand4 = if count_b == count_ab { 0.0 } else {
+        (count_b - count_ab) * p2.ln() + (n - count_a - count_b + count_ab) * (1.0 - p2).ln()
+    };
+
+    -2.0 * (summand1 + summand2 - summand3 - summand4)
+}
+
+impl PunktModel {
+    /// Empty model
+    pub fn new() -> PunktModel {
+        PunktModel::default()
+    }
+
+    /// Learn a model from a text
+    pub fn train(text: &str) -> PunktModel {
+        let mut tokens = text.split_whitespace().filter_map(Token::new).collect::<Vec<_>>();
+        let mut model = PunktModel::new();
+
+        let mut counts: HashMap<(String, bool), f64> = HashMap::new();
+        for token in &tokens {
+            *counts.entry((token.typ.clone(), token.period_final)).or_insert(0.0) += 1.0;
+        }
+        let count = |typ: &str, period: bool| counts.get(&(typ.to_owned(), period)).cloned().unwrap_or(0.0);
+        let type_count = |typ: &str| count(typ, true) + count(typ, false);
+        let n = tokens.len() as f64;
+        let period_tokens = tokens.iter().filter(|token| token.period_final).count() as f64;
+
+        // Abbreviations: types almost always followed by a period, short, and with inner periods
+        for &(ref typ, period) in counts.keys() {
+            if !period || typ == NUMBER || !typ.chars().any(char::is_alphabetic) {
+                continue;
+            }
+            let periods = typ.matches('.').count() + 1;
+            let non_periods = typ.chars().count() + 1 - periods;
+            let (with_period, without_period) = (count(typ, true), count(typ, false));
+
+            let likelihood = dunning_log_likelihood(with_period + without_period, period_tokens, with_period, n);
+            let score = likelihood * (-(non_periods as f64)).exp() * periods as f64 * (non_periods as f64).powf(-without_period);
+            if score >= ABBREVIATION {
+                model.abbreviations.insert(typ.clone());
+            }
+        }
+
+        for token in &mut tokens {
+            if token.period_final {
+                token.abbreviation = model.abbreviations.contains(&token.typ);
+                token.sentence_break = !token.abbreviation;
+            }
+        }
+
+        // Case of the words at the start, in the middle, or after an abbreviation
+        let mut starters: HashMap<&str, f64> = HashMap::new();
+        let mut collocations: HashMap<(&str, &str), f64> = HashMap::new();
+        for (index, token) in tokens.iter().enumerate() {
+            let previous = if index > 0 { tokens.get(index - 1) } else { None };
+            let flag = match (previous, token.upper, token.lower) {
+                (None, true, _)                                       => BEG_UC,
+                (None, _, true)                                       => BEG_LC,
+                (Some(p), true, _) if p.sentence_break                => BEG_UC,
+                (Some(p), _, true) if p.sentence_break                => BEG_LC,
+                (Some(p), true, _) if p.abbreviation || p.initial     => UNK_UC,
+                (Some(p), _, true) if p.abbreviation || p.initial     => UNK_LC,
+                (Some(_), true, _)                                    => MID_UC,
+                (Some(_), _, true)                                    => MID_LC,
+                _                                                     => 0,
+            };
+            if flag != 0 {
+                *model.ortho_context.entry(token.typ.clone()).or_insert(0) |= flag;
+            }
+
+            if let Some(previous) = previous {
+                if previous.sentence_break {
+                    *starters.entry(&token.typ).or_insert(0.0) += 1.0;
+                }
+                let candidate = previous.abbreviation || (previous.sentence_break && (previous.is_number() || previous.initial));
+                if previous.period_final && candidate {
+                    *collocations.entry((&previous.typ, &token.typ)).or_insert(0.0) += 1.0;
+                }
+            }
+        }
+
+        // Words starting sentences more often than chance
+        let breaks = tokens.iter().filter(|token| token.sentence_break).count() as f64;
+        for (&typ, &at_break) in &starters {
+            let typ_count = type_count(typ);
+            if typ == NUMBER || typ_count < at_break {
+                continue;
+            }
+            let likelihood = collocation_log_likelihood(breaks, typ_count, at_break, n);
+            if likelihood >= SENTENCE_STARTER && n / breaks > typ_count / at_break {
+                model.sentence_starters.insert(typ.to_owned());
+            }
+        }
+
+        // Pairs hiding a sentence break
+        for (&(first, second), &together) in &collocations {
+            let (first_count, second_count) = (type_count(first), type_count(second));
+            if first_count <= 1.0 || second_count <= 1.0 || together <= 1.0 || together > first_count.min(second_count) {
+                continue;
+            }
+            let likelihood = collocation_log_likelihood(first_count, second_count, together, n);
+            if likelihood >= COLLOCATION && n / first_count > second_count / together {
+                model.collocations.insert((first.to_owned(), second.to_owned()));
+            }
+        }
+
+        model
+    }
+
+    /// Add an abbreviation, without its final period
+    pub fn add_abbreviation(&mut self, abbreviation: &str) {
+        self.abbreviations.insert(word_type(abbreviation));
+    }
+
+    /// Whether the word is a known abbreviation, with or without its final period
+    pub fn is_abbreviation(&self, word: &str) -> bool {
+        self.abbreviations.contains(&word_type(word))
+    }
+
+    /// Whether the word often starts a sentence
+    pub fn is_sentence_starter(&self, word: &str) -> bool {
+        self.sentence_starters.contains(&word_type(word))
+    }
+
+    /// Whether the period after `first` is rarely a sentence break when followed by `second`
+    pub fn is_collocation(&self, first: &str, second: &str) -> bool {
+        self.collocations.contains(&(word_type(first), word_type(second)))
+    }
+
+    /// Known abbreviations, sorted
+    pub fn abbreviations(&self) -> Vec<&str> {
+        let mut abbreviations = self.abbreviations.iter().map(|a| &a[..]).collect::<Vec<_>>();
+        abbreviations.sort();
+        abbreviations
+    }
+
+    /// Whether the case of a word tells it starts a sentence, `None` when it doesn't tell
+    pub(crate) fn ortho_heuristic(&self, word: &str) -> Option<bool> {
+        let first = word.trim_start_matches(|c| OPENING.contains(c)).chars().next()?;
+        if !first.is_alphanumeric() {
+            return Some(false);
+        }
+        let ortho = self.ortho_context.get(&word_type(word)).cloned().unwrap_or(0);
+
+        if first.is_uppercase() && ortho & ORTHO_LC != 0 && ortho & MID_UC == 0 {
+            Some(true)
+        } else if first.is_lowercase() && (ortho & ORTHO_UC != 0 || ortho & BEG_LC == 0) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the word was seen in lowercase
+    pub(crate) fn occurs_lowercase(&self, word: &str) -> bool {
+        self.ortho_context.get(&word_type(word)).is_some_and(|&ortho| ortho & ORTHO_LC != 0)
+    }
+
+    /// Write the model, one tab separated entry per line
+    pub fn save<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let mut lines = Vec::new();
+        lines.extend(self.abbreviations.iter().map(|a| format!("abbreviation\t{}", a)));
+        lines.extend(self.collocations.iter().map(|(a, b)| format!("collocation\t{}\t{}", a, b)));
+        lines.extend(self.sentence_starters.iter().map(|s| format!("starter\t{}", s)));
+        lines.extend(self.ortho_context.iter().map(|(w, o)| format!("ortho\t{}\t{}", w, o)));
+        lines.sort();
+
+        writeln!(writer, "# punkt model")?;
+        for line in lines {
+            writeln!(writer, "{}", line)?;
+        }
+
+        Ok(())
+    }
+
+    /// Read a model written by `save`
+    pub fn load<R: BufRead>(reader: R) -> io::Result<PunktModel> {
+        let mut model = PunktModel::new();
+        let invalid = |line: &str| io::Error::new(io::ErrorKind::InvalidData, format!("invalid punkt model line: {}", line));
+
+        for line in reader.lines() {
+            let line = line?;
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields = line.split('\t').collect::<Vec<_>>();
+            match &fields[..] {
+                ["abbreviation", a]    => { model.abbreviations.insert((*a).to_owned()); },
+                ["collocation", a, b]  => { model.collocations.insert(((*a).to_owned(), (*b).to_owned())); },
+                ["starter", s]         => { model.sentence_starters.insert((*s).to_owned()); },
+                ["ortho", w, o]        => { model.ortho_context.insert((*w).to_owned(), o.parse().map_err(|_| invalid(&line))?); },
+                _                      => return Err(invalid(&line)),
+            }
+        }
+
+        Ok(model)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn punkt_word_type() {
+        assert_eq!("dr", word_type("Dr."));
+        assert_eq!("e.g", word_type("(e.g.,"));
+        assert_eq!(NUMBER, word_type("1,000.50"));
+        assert_eq!("end", word_type("end?\u{201D}"));
+    }
+
+    #[test]
+    fn punkt_collocations() {
+        let text = "Am 3. Oktober ist Feiertag. Der 3. Oktober ist frei. Ich komme am 3. Oktober. \
+                    Es regnet. Wir gehen. Das ist gut. Am 3. Oktober feiern wir. Es ist spät.";
+        let model = PunktModel::train(text);
+        assert!(model.is_collocation("3.", "Oktober"));
+        assert!(!model.is_abbreviation("regnet"));
+    }
+
+    #[test]
+    fn punkt_load_invalid() {
+        assert!(PunktModel::load(&b"ortho\tthe\tx\n"[..]).is_err());
+        assert!(PunktModel::load(&b"unknown\tthe\n"[..]).is_err());
+        assert!(PunktModel::load(&b"# empty\n\n"[..]).unwrap().abbreviations().is_empty());
+    }
+}
@@ -0,0 +1,300 @@
+use tokenize::punkt::{word_type, PunktModel, NUMBER};
+
+static TERMINALS: &str = ".!?\u{2026}\u{3002}\u{FF01}\u{FF1F}";
+static FULL_WIDTH_TERMINALS: &str = "\u{3002}\u{FF01}\u{FF1F}";
+static CLOSING: &str = "\"')]}\u{201D}\u{2019}\u{00BB}";
+static OPENING: &str = "\"'([{\u{201C}\u{2018}\u{00AB}";
+// Abbreviations followed by a name or a number, never at the end of a sentence
+static TITLES: &[&str] = &["mr", "mrs", "ms", "dr", "prof", "st", "mt", "gen", "col", "lt", "sgt", "capt", "rev",
+                           "hon", "fr", "sen", "rep", "gov", "pres", "messrs", "mme", "mlle", "e.g", "i.e", "cf",
+                           "vs", "viz", "al", "fig", "figs", "no", "nos", "vol", "pp", "ch", "sec", "approx", "ca",
+                           "incl", "ref", "eq", "art"];
+// Abbreviations which may end a sentence
+static ABBREVIATIONS: &[&str] = &["etc", "inc", "ltd", "co", "corp", "jr", "sr", "bros", "dept", "univ", "ave",
+                                  "blvd", "rd", "jan", "feb", "mar", "apr", "jun", "jul", "aug", "sep", "sept",
+                                  "oct", "nov", "dec", "mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+static STARTERS: &[&str] = &["a", "an", "the", "i", "he", "she", "it", "we", "they", "you", "this", "that",
+                             "these", "those", "there", "his", "her", "its", "our", "their", "my", "but", "and",
+                             "so", "however", "in", "on", "at", "as", "if", "when", "after", "then", "what", "why",
+                             "how", "who", "where"];
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+/// Sentence of a text
+pub struct Sentence<'a> {
+    /// Text of the sentence, without the surrounding white spaces
+    pub text: &'a str,
+    /// Byte offset of the sentence start in the text
+    pub start: usize,
+    /// Byte offset of the sentence end in the text
+    pub end: usize,
+    /// Char offset of the sentence start in the text
+    pub char_start: usize,
+    /// Char offset of the sentence end in the text
+    pub char_end: usize,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// Sentence splitter
+///
+/// A sentence ends at a period, a question or an exclamation mark, an ellipsis, or a blank line,
+/// the closing quotes and brackets that follow included. It doesn't end after an abbreviation
+/// ("Dr.", "e.g."), an initial ("J. Smith"), inside a number ("3.14"), nor before a lowercase word.
+/// The built-in abbreviations are English ones, a `PunktModel` trained on a text of the language
+/// brings the others.
+///
+/// The text of the sentences is a slice of the text, ready for the distance and phonetic
+/// functions of the crate.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::tokenize::sentence::SentenceSplitter;
+/// use nlp::distance::levenshtein;
+///
+/// let text = "Dr. Smith paid 3.50 dollars, e.g. for coffee... He said \"Thanks!\" and left. (He did.) Bye";
+/// let sentences = SentenceSplitter::new().split(text);
+/// let texts = sentences.iter().map(|sentence| sentence.text).collect::<Vec<_>>();
+/// assert_eq!(vec!["Dr. Smith paid 3.50 dollars, e.g. for coffee...", "He said \"Thanks!\" and left.",
+///                 "(He did.)", "Bye"], texts);
+/// assert_eq!((48, 75), (sentences[1].start, sentences[1].end));
+/// assert_eq!(1, levenshtein(sentences[3].text, "Bee"));
+/// ```
+pub struct SentenceSplitter {
+    model: PunktModel,
+    english: bool,
+}
+
+impl Default for SentenceSplitter {
+    fn default() -> SentenceSplitter {
+        SentenceSplitter::new()
+    }
+}
+
+impl SentenceSplitter {
+    /// Splitter with the built-in English abbreviations
+    pub fn new() -> SentenceSplitter {
+        SentenceSplitter::with_model(PunktModel::new())
+    }
+
+    /// Splitter with the abbreviations, collocations and sentence starters of a Punkt model, on top
+    /// of the built-in English ones
+    ///
+    /// Examples:
+    ///
+    /// ```
+    /// use nlp::tokenize::punkt::PunktModel;
+    /// use nlp::tokenize::sentence::SentenceSplitter;
+    ///
+    /// let mut model = PunktModel::new();
+    /// model.add_abbreviation("bzw.");
+    /// let splitter = SentenceSplitter::with_model(model).english(false);
+    /// assert_eq!(1, splitter.split("Rot bzw. Grün ist gut.").len());
+    /// assert_eq!(2, SentenceSplitter::new().split("Rot bzw. Grün ist gut.").len());
+    /// ```
+    pub fn with_model(model: PunktModel) -> SentenceSplitter {
+        SentenceSplitter { model, english: true }
+    }
+
+    /// Use the built-in English abbreviations and sentence starters
+    pub fn english(mut self, english: bool) -> SentenceSplitter {
+        self.english = english;
+        self
+    }
+
+    /// Add an abbreviation to the model, with or without its final period
+    pub fn abbreviation(mut self, abbreviation: &str) -> SentenceSplitter {
+        self.model.add_abbreviation(abbreviation);
+        self
+    }
+
+    /// Sentences of a text, in order
+    pub fn split<'a>(&self, text: &'a str) -> Vec<Sentence<'a>> {
+        let chars = text.char_indices().collect::<Vec<_>>();
+        let offset = |i: usize| chars.get(i).map_or(text.len(), |&(index, _)| index);
+        let mut ends = Vec::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let (index, c) = chars[i];
+
+            if c == '\n' {
+                let mut j = i + 1;
+                while j < chars.len() && chars[j].1.is_whitespace() && chars[j].1 != '\n' {
+                    j += 1;
+                }
+                if chars.get(j).is_some_and(|&(_, c)| c == '\n') {
+                    ends.push(index);
+                }
+                i = j;
+                continue;
+            }
+
+            if !TERMINALS.contains(c) {
+                i += 1;
+                continue;
+            }
+
+            let mut j = i + 1;
+            while j < chars.len() && (TERMINALS.contains(chars[j].1) || CLOSING.contains(chars[j].1)) {
+                j += 1;
+            }
+            let end = offset(j);
+            let separated = j == chars.len() || chars[j].1.is_whitespace() || FULL_WIDTH_TERMINALS.contains(c);
+            if separated && self.is_boundary(&text[..index], &text[index..end], &text[end..]) {
+                ends.push(end);
+            }
+            i = j;
+        }
+        ends.push(text.len());
+
+        let mut sentences = Vec::new();
+        let (mut start, mut char_start) = (0, 0);
+        for end in ends {
+            if end <= start {
+                continue;
+            }
+            let slice = &text[start..end];
+            let trimmed = slice.trim_start();
+            let leading = slice.len() - trimmed.len();
+            let trimmed = trimmed.trim_end();
+
+            let first = start + leading;
+            let first_char = char_start + slice[..leading].chars().count();
+            let char_count = trimmed.chars().count();
+            if !trimmed.is_empty() {
+                sentences.push(Sentence {
+                    text: trimmed,
+                    start: first,
+                    end: first + trimmed.len(),
+                    char_start: first_char,
+                    char_end: first_char + char_count,
+                });
+            }
+
+            char_start += slice.chars().count();
+            start = end;
+        }
+
+        sentences
+    }
+
+    /// Whether the terminal punctuation `marks` ends the sentence
+    fn is_boundary(&self, before: &str, marks: &str, after: &str) -> bool {
+        let next = match after.split_whitespace().next() {
+            Some(next) => next,
+            None => return true,
+        };
+        let next_first = next.trim_start_matches(|c| OPENING.contains(c)).chars().next();
+        if next_first.is_some_and(char::is_lowercase) {
+            return false;
+        }
+        if marks != "." {
+            return true;
+        }
+
+        let word = before.rsplit(char::is_whitespace).next().unwrap_or("");
+        let word = word.trim_start_matches(|c| OPENING.contains(c));
+        if word.is_empty() {
+            return true;
+        }
+        self.period_boundary(word, next)
+    }
+
+    /// Whether the period after `word` ends the sentence, the next word not being lowercase
+    fn period_boundary(&self, word: &str, next: &str) -> bool {
+        let typ = word_type(word);
+        let next_typ = word_type(next);
+        let next_upper = next.trim_start_matches(|c| OPENING.contains(c)).chars().next().is_some_and(char::is_uppercase);
+
+        if self.model.is_collocation(word, next) || (self.english && TITLES.contains(&&typ[..])) {
+            return false;
+        }
+
+        let initial = typ.chars().count() == 1 && typ.chars().all(char::is_alphabetic);
+        let number = typ == NUMBER;
+        if initial || number {
+            return match self.model.ortho_heuristic(next) {
+                Some(starts) => starts,
+                None => !(initial && next_upper && !self.model.occurs_lowercase(next)),
+            };
+        }
+
+        let dotted = typ.contains('.') && typ.split('.').all(|part| !part.is_empty() && part.chars().count() <= 2 && part.chars().all(char::is_alphabetic));
+        if dotted || self.model.is_abbreviation(word) || (self.english && ABBREVIATIONS.contains(&&typ[..])) {
+            return self.model.ortho_heuristic(next) == Some(true) ||
+                (next_upper && (self.model.is_sentence_starter(next) || (self.english && STARTERS.contains(&&next_typ[..]))));
+        }
+
+        true
+    }
+}
+
+/// Sentences of a text, with the built-in English abbreviations
+///
+/// Examples:
+///
+/// ```
+/// use nlp::tokenize::sentence::sentences;
+///
+/// let texts = sentences("It costs $2.50 at Acme Inc. in the U.S. It is cheap!").iter().map(|s| s.text).collect::<Vec<_>>();
+/// assert_eq!(vec!["It costs $2.50 at Acme Inc. in the U.S.", "It is cheap!"], texts);
+/// ```
+pub fn sentences(text: &str) -> Vec<Sentence<'_>> {
+    SentenceSplitter::new().split(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(splitter: &SentenceSplitter, text: &str) -> Vec<String> {
+        splitter.split(text).iter().map(|sentence| sentence.text.to_owned()).collect()
+    }
+
+    #[test]
+    fn sentences_abbreviations() {
+        let splitter = SentenceSplitter::new();
+        assert_eq!(vec!["Mr. J. R. Smith met Prof. Jones at 5 p.m. on Jan. 3rd."],
+                   texts(&splitter, "Mr. J. R. Smith met Prof. Jones at 5 p.m. on Jan. 3rd."));
+        assert_eq!(vec!["He works at Acme Inc.", "The pay is good."],
+                   texts(&splitter, "He works at Acme Inc. The pay is good."));
+        assert_eq!(vec!["See Fig. 2 and the U.S. Army, i.e. the army."],
+                   texts(&splitter, "See Fig. 2 and the U.S. Army, i.e. the army."));
+        assert_eq!(vec!["It ended in 1999.", "Then it began."], texts(&splitter, "It ended in 1999. Then it began."));
+    }
+
+    #[test]
+    fn sentences_punctuation() {
+        let splitter = SentenceSplitter::new();
+        assert_eq!(vec!["Really?!", "Yes\u{2026}", "Well..."], texts(&splitter, "Really?! Yes\u{2026} Well..."));
+        assert_eq!(vec!["Wait... what?"], texts(&splitter, "Wait... what?"));
+        assert_eq!(vec!["\u{201C}Go!\u{201D} he said.", "[Done.]"], texts(&splitter, "\u{201C}Go!\u{201D} he said. [Done.]"));
+        assert_eq!(vec!["\u{4ECA}\u{5929}\u{3002}", "\u{660E}\u{5929}\u{3002}"], texts(&splitter, "\u{4ECA}\u{5929}\u{3002}\u{660E}\u{5929}\u{3002}"));
+        assert_eq!(vec!["Title", "Some text"], texts(&splitter, "Title\n  \nSome text\n"));
+        assert!(splitter.split(" \n\n ").is_empty());
+    }
+
+    #[test]
+    fn sentences_offsets() {
+        let sentences = sentences("  Café au lait.  Déjà vu. ");
+        assert_eq!("Déjà vu.", sentences[1].text);
+        assert_eq!((18, 28), (sentences[1].start, sentences[1].end));
+        assert_eq!((17, 25), (sentences[1].char_start, sentences[1].char_end));
+        assert_eq!((2, 2), (sentences[0].start, sentences[0].char_start));
+    }
+
+    #[test]
+    fn sentences_trained_model() {
+        let mut text = String::new();
+        for _ in 0..20 {
+            text.push_str("Die Sitzung ist am 3. Oktober in Raum 4. Dort gibt es Kaffee usw. und Kuchen. \
+                           Der Raum ist groß. Wir kommen um 10 Uhr. Es gibt Tee usw. zum Frühstück. ");
+        }
+        let model = PunktModel::train(&text);
+        assert!(model.is_abbreviation("usw"));
+
+        let splitter = SentenceSplitter::with_model(model).english(false);
+        assert_eq!(vec!["Sie kommt am 3. Oktober.", "Es gibt Brot usw. Das ist gut."],
+                   texts(&splitter, "Sie kommt am 3. Oktober. Es gibt Brot usw. Das ist gut."));
+    }
+}
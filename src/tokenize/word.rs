@@ -0,0 +1,476 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+static URL_PREFIXES: &[&str] = &["https://", "http://", "ftp://", "www."];
+static EMOTICONS: &[&str] = &[":-)", ":)", ":-(", ":(", ":-D", ":D", ";-)", ";)", ":-P", ":P", ":-p", ":p",
+                              ":-/", ":/", ":'(", ":-|", ":|", ":-O", ":O", ":o", ":-*", ":*", "=)", "=(",
+                              "</3", "<3", "^_^", "^^", "-_-", "o_O", "O_o"];
+static CONTRACTIONS: &[&str] = &["n't", "'s", "'m", "'d", "'re", "'ve", "'ll"];
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+/// Kind of a token
+pub enum TokenKind {
+    /// Word, hyphenated words and contractions included
+    Word,
+    /// Number, with its decimal and thousands separators
+    Number,
+    /// Punctuation mark
+    Punctuation,
+    /// Symbol which is not a punctuation mark ("$", "+")
+    Symbol,
+    /// Web address
+    Url,
+    /// Email address
+    Email,
+    /// Emoticon or emoji
+    Emoticon,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+/// Token of a text
+pub struct Token<'a> {
+    /// Text of the token
+    pub text: &'a str,
+    /// Kind of the token
+    pub kind: TokenKind,
+    /// Byte offset of the token start in the text
+    pub start: usize,
+    /// Byte offset of the token end in the text
+    pub end: usize,
+    /// Char offset of the token start in the text
+    pub char_start: usize,
+    /// Char offset of the token end in the text
+    pub char_end: usize,
+}
+
+impl<'a> AsRef<str> for Token<'a> {
+    fn as_ref(&self) -> &str {
+        self.text
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Word tokenizer following the word boundaries of Unicode (UAX #29)
+///
+/// UAX #29 already keeps contractions ("don't") and numbers with separators ("1,000.50")
+/// together. On top of it, hyphenated words are joined, and URLs, email addresses and emoticons
+/// are kept in one token. White spaces are never tokens.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::tokenize::word::{WordTokenizer, TokenKind};
+///
+/// let tokens = WordTokenizer::new().tokenize("Don't pay 1,000.50$ for a state-of-the-art café :)");
+/// let texts = tokens.iter().map(|token| token.text).collect::<Vec<_>>();
+/// assert_eq!(vec!["Don't", "pay", "1,000.50", "$", "for", "a", "state-of-the-art", "café", ":)"], texts);
+/// assert_eq!(TokenKind::Number, tokens[2].kind);
+/// assert_eq!((43, 48), (tokens[7].start, tokens[7].end));
+/// assert_eq!((43, 47), (tokens[7].char_start, tokens[7].char_end));
+///
+/// let tokens = WordTokenizer::new().punctuation(false).split_contractions(true).tokenize("I can't, sorry.");
+/// let texts = tokens.iter().map(|token| token.text).collect::<Vec<_>>();
+/// assert_eq!(vec!["I", "ca", "n't", "sorry"], texts);
+/// ```
+pub struct WordTokenizer {
+    punctuation: bool,
+    split_contractions: bool,
+    split_hyphens: bool,
+    urls: bool,
+    emails: bool,
+    emoticons: bool,
+}
+
+impl Default for WordTokenizer {
+    fn default() -> WordTokenizer {
+        WordTokenizer::new()
+    }
+}
+
+impl WordTokenizer {
+    /// Tokenizer keeping punctuation, contractions and hyphenated words, and recognizing URLs,
+    /// email addresses and emoticons
+    pub fn new() -> WordTokenizer {
+        WordTokenizer {
+            punctuation: true,
+            split_contractions: false,
+            split_hyphens: false,
+            urls: true,
+            emails: true,
+            emoticons: true,
+        }
+    }
+
+    /// Keep the punctuation marks and the symbols
+    pub fn punctuation(mut self, punctuation: bool) -> WordTokenizer {
+        self.punctuation = punctuation;
+        self
+    }
+
+    /// Split the contractions the Penn Treebank way: "don't" gives "do" and "n't", "I'm" gives
+    /// "I" and "'m"
+    pub fn split_contractions(mut self, split_contractions: bool) -> WordTokenizer {
+        self.split_contractions = split_contractions;
+        self
+    }
+
+    /// Give one token per part of the hyphenated words, and one for each hyphen
+    pub fn split_hyphens(mut self, split_hyphens: bool) -> WordTokenizer {
+        self.split_hyphens = split_hyphens;
+        self
+    }
+
+    /// Keep the URLs in one token
+    pub fn urls(mut self, urls: bool) -> WordTokenizer {
+        self.urls = urls;
+        self
+    }
+
+    /// Keep the email addresses in one token
+    pub fn emails(mut self, emails: bool) -> WordTokenizer {
+        self.emails = emails;
+        self
+    }
+
+    /// Keep the ASCII emoticons (":-)", "<3") in one token
+    pub fn emoticons(mut self, emoticons: bool) -> WordTokenizer {
+        self.emoticons = emoticons;
+        self
+    }
+
+    /// Tokens of a text, in order
+    pub fn tokenize<'a>(&self, text: &'a str) -> Vec<Token<'a>> {
+        let mut spans = Vec::new();
+        let mut start = 0;
+
+        for (index, c) in text.char_indices() {
+            if index < start {
+                continue;
+            }
+            if let Some((end, kind)) = self.special_span(text, index, c) {
+                self.segment(text, start, index, &mut spans);
+                spans.push((index, end, kind));
+                start = end;
+            }
+        }
+        self.segment(text, start, text.len(), &mut spans);
+
+        if !self.split_hyphens {
+            spans = join_hyphens(text, spans);
+        }
+        if self.split_contractions {
+            spans = spans.into_iter().flat_map(|span| split_contraction(text, span)).collect();
+        }
+        if !self.punctuation {
+            spans.retain(|&(_, _, kind)| kind != TokenKind::Punctuation && kind != TokenKind::Symbol);
+        }
+
+        with_char_offsets(text, &spans)
+    }
+
+    /// URL, email address or emoticon starting at `index`, with its end
+    fn special_span(&self, text: &str, index: usize, c: char) -> Option<(usize, TokenKind)> {
+        let at_boundary = text[..index].chars().next_back().is_none_or(|c| !c.is_alphanumeric());
+
+        if self.urls && at_boundary && c.is_ascii_alphabetic() {
+            if let Some(end) = url_end(text, index) {
+                return Some((end, TokenKind::Url));
+            }
+        }
+        if self.emoticons && text[..index].chars().next_back().is_none_or(char::is_whitespace) {
+            if let Some(end) = emoticon_end(text, index) {
+                return Some((end, TokenKind::Emoticon));
+            }
+        }
+
+        None
+    }
+
+    /// Segment `text[start..end]` on the word boundaries, and push the spans which are not white
+    /// spaces
+    fn segment(&self, text: &str, start: usize, end: usize, spans: &mut Vec<(usize, usize, TokenKind)>) {
+        let mut skip_to = start;
+        for (offset, segment) in text[start..end].split_word_bound_indices() {
+            let index = start + offset;
+            if index < skip_to {
+                continue;
+            }
+            if self.emails && segment == "@" {
+                if let Some((local, domain)) = email_bounds(text, index, start, end) {
+                    while spans.last().is_some_and(|&(s, _, _)| s >= local) {
+                        spans.pop();
+                    }
+                    spans.push((local, domain, TokenKind::Email));
+                    skip_to = domain;
+                    continue;
+                }
+            }
+            if let Some(kind) = classify(segment) {
+                spans.push((index, index + segment.len(), kind));
+            }
+        }
+    }
+}
+
+/// Kind of a UAX #29 segment, `None` for white spaces
+fn classify(segment: &str) -> Option<TokenKind> {
+    let first = segment.chars().next()?;
+
+    if segment.chars().all(char::is_whitespace) {
+        None
+    } else if first.is_numeric() && segment.chars().all(|c| c.is_numeric() || is_number_separator(c)) {
+        Some(TokenKind::Number)
+    } else if segment.chars().any(char::is_alphanumeric) {
+        Some(TokenKind::Word)
+    } else if is_emoji(first) {
+        Some(TokenKind::Emoticon)
+    } else if segment.chars().all(|c| c.is_ascii_punctuation() && !"$+<=>^`|~".contains(c) || is_unicode_punctuation(c)) {
+        Some(TokenKind::Punctuation)
+    } else {
+        Some(TokenKind::Symbol)
+    }
+}
+
+fn is_number_separator(c: char) -> bool {
+    ",.'\u{2019}\u{066B}\u{066C}".contains(c)
+}
+
+fn is_unicode_punctuation(c: char) -> bool {
+    matches!(c, '\u{00A1}' | '\u{00A7}' | '\u{00AB}' | '\u{00B6}' | '\u{00B7}' | '\u{00BB}' | '\u{00BF}' |
+                '\u{2010}'..='\u{2027}' | '\u{2030}'..='\u{205E}' | '\u{3001}'..='\u{3003}' | '\u{3008}'..='\u{3011}')
+}
+
+fn is_emoji(c: char) -> bool {
+    matches!(c, '\u{1F300}'..='\u{1FAFF}' | '\u{2600}'..='\u{27BF}')
+}
+
+fn is_hyphen(text: &str) -> bool {
+    text == "-" || text == "\u{2010}" || text == "\u{2011}"
+}
+
+/// End of the URL starting at `index`, trailing punctuation excluded
+fn url_end(text: &str, index: usize) -> Option<usize> {
+    let rest = &text[index..];
+    let prefix = URL_PREFIXES.iter().find(|prefix| {
+        rest.get(..prefix.len()).is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+    })?;
+
+    let length = rest.find(|c: char| c.is_whitespace() || "<>\"".contains(c)).unwrap_or(rest.len());
+    let mut url = &rest[..length];
+    loop {
+        let trimmed = url.trim_end_matches(|c: char| ".,;:!?'\u{2019}]}".contains(c));
+        let unbalanced = trimmed.matches(')').count() > trimmed.matches('(').count();
+        let trimmed = if trimmed.ends_with(')') && unbalanced { &trimmed[..trimmed.len() - 1] } else { trimmed };
+        if trimmed.len() == url.len() {
+            break;
+        }
+        url = trimmed;
+    }
+
+    if url.len() > prefix.len() { Some(index + url.len()) } else { None }
+}
+
+/// End of the emoticon starting at `index`, which must be followed by a white space, the end of
+/// the text or a punctuation mark
+fn emoticon_end(text: &str, index: usize) -> Option<usize> {
+    let rest = &text[index..];
+    EMOTICONS
+        .iter()
+        .find(|emoticon| {
+            rest.starts_with(*emoticon) &&
+                rest[emoticon.len()..].chars().next().is_none_or(|c| c.is_whitespace() || ".,;!?".contains(c))
+        })
+        .map(|emoticon| index + emoticon.len())
+}
+
+/// Bounds of the email address around the `@` at `index`, within `text[start..end]`
+fn email_bounds(text: &str, index: usize, start: usize, end: usize) -> Option<(usize, usize)> {
+    let is_local = |c: char| c.is_alphanumeric() || "._%+-".contains(c);
+    let is_domain = |c: char| c.is_alphanumeric() || ".-".contains(c);
+
+    let local = text[start..index]
+        .char_indices()
+        .rev()
+        .take_while(|&(_, c)| is_local(c))
+        .last()
+        .map(|(offset, _)| start + offset)?;
+    let local = local + text[local..index].find(|c: char| c != '.').unwrap_or(0);
+
+    let domain = &text[index + 1..end];
+    let domain = &domain[..domain.find(|c: char| !is_domain(c)).unwrap_or(domain.len())];
+    let domain = domain.trim_end_matches(|c: char| ['.', '-'].contains(&c));
+
+    let tld = domain.rsplit('.').next().unwrap_or("");
+    if local == index || !domain.contains('.') || tld.chars().count() < 2 || !tld.chars().all(char::is_alphabetic) {
+        return None;
+    }
+
+    Some((local, index + 1 + domain.len()))
+}
+
+/// Join the words separated by a single hyphen, without spaces around it
+fn join_hyphens(text: &str, spans: Vec<(usize, usize, TokenKind)>) -> Vec<(usize, usize, TokenKind)> {
+    let is_part = |kind: TokenKind| kind == TokenKind::Word || kind == TokenKind::Number;
+    let mut joined: Vec<(usize, usize, TokenKind)> = Vec::with_capacity(spans.len());
+    let mut index = 0;
+
+    while index < spans.len() {
+        let (start, end, kind) = spans[index];
+        if let (Some(&mut (_, ref mut last_end, ref mut last_kind)), Some(&(next_start, next_end, next_kind))) = (joined.last_mut(), spans.get(index + 1)) {
+            let hyphen = *last_end == start && end == next_start && is_hyphen(&text[start..end]);
+            let has_word = *last_kind == TokenKind::Word || next_kind == TokenKind::Word;
+            if hyphen && is_part(*last_kind) && is_part(next_kind) && has_word {
+                *last_end = next_end;
+                *last_kind = TokenKind::Word;
+                index += 2;
+                continue;
+            }
+        }
+        joined.push((start, end, kind));
+        index += 1;
+    }
+
+    joined
+}
+
+/// Split a word ending with a contraction in two
+fn split_contraction(text: &str, span: (usize, usize, TokenKind)) -> Vec<(usize, usize, TokenKind)> {
+    let (start, end, kind) = span;
+    let word = &text[start..end];
+    if kind != TokenKind::Word {
+        return vec![span];
+    }
+
+    for contraction in CONTRACTIONS {
+        let position = match word.char_indices().rev().nth(contraction.chars().count() - 1) {
+            Some((position, _)) if position > 0 => position,
+            _ => continue,
+        };
+        let suffix = word[position..]
+            .chars()
+            .map(|c| if c == '\u{2019}' { '\'' } else { c.to_ascii_lowercase() });
+        if suffix.eq(contraction.chars()) {
+            return vec![(start, start + position, kind), (start + position, end, kind)];
+        }
+    }
+
+    vec![span]
+}
+
+/// Tokens of the spans, with their char offsets
+fn with_char_offsets<'a>(text: &'a str, spans: &[(usize, usize, TokenKind)]) -> Vec<Token<'a>> {
+    let mut tokens = Vec::with_capacity(spans.len());
+    let (mut byte, mut chars) = (0, 0);
+
+    for &(start, end, kind) in spans {
+        chars += text[byte..start].chars().count();
+        let char_start = chars;
+        chars += text[start..end].chars().count();
+        byte = end;
+
+        tokens.push(Token { text: &text[start..end], kind, start, end, char_start, char_end: chars });
+    }
+
+    tokens
+}
+
+/// Words and numbers of a text, punctuation dropped
+///
+/// Examples:
+///
+/// ```
+/// use nlp::tokenize::word::words;
+///
+/// assert_eq!(vec!["Mary", "Ann", "O'Brien-Smith", "isn't", "here"], words("Mary Ann O'Brien-Smith isn't here!"));
+/// ```
+pub fn words(text: &str) -> Vec<&str> {
+    WordTokenizer::new()
+        .punctuation(false)
+        .emoticons(false)
+        .tokenize(text)
+        .into_iter()
+        .filter(|token| token.kind == TokenKind::Word || token.kind == TokenKind::Number)
+        .map(|token| token.text)
+        .collect()
+}
+
+/// Tokens of a text, with the default options
+///
+/// Examples:
+///
+/// ```
+/// use nlp::tokenize::word::tokenize;
+///
+/// let texts = tokenize("Write to jane.doe@example.com, or see https://example.com/help.")
+///     .iter()
+///     .map(|token| token.text)
+///     .collect::<Vec<_>>();
+/// assert_eq!(vec!["Write", "to", "jane.doe@example.com", ",", "or", "see", "https://example.com/help", "."], texts);
+/// ```
+pub fn tokenize(text: &str) -> Vec<Token<'_>> {
+    WordTokenizer::new().tokenize(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(tokens: Vec<Token<'_>>) -> Vec<&str> {
+        tokens.iter().map(|token| token.text).collect()
+    }
+
+    #[test]
+    fn words_punctuation() {
+        assert_eq!(vec!["Hello", ",", "world", "!"], texts(tokenize("Hello, world!")));
+        assert_eq!(vec!["Hello", "world"], texts(WordTokenizer::new().punctuation(false).tokenize("Hello, world!")));
+        assert_eq!(vec!["«", "Bonjour", "»", "—", "dit-il"], texts(tokenize("« Bonjour » — dit-il")));
+    }
+
+    #[test]
+    fn words_contractions() {
+        assert_eq!(vec!["don't", "we'll", "O'Brien"], texts(tokenize("don't we'll O'Brien")));
+        let tokenizer = WordTokenizer::new().split_contractions(true);
+        assert_eq!(vec!["do", "n't", "we", "'ll", "O'Brien"], texts(tokenizer.tokenize("don't we'll O'Brien")));
+        assert_eq!(vec!["I", "’m", "John", "’s"], texts(tokenizer.tokenize("I’m John’s")));
+    }
+
+    #[test]
+    fn words_hyphens() {
+        assert_eq!(vec!["state-of-the-art", "COVID-19", "10", "-", "12"], texts(tokenize("state-of-the-art COVID-19 10-12")));
+        assert_eq!(vec!["well", "-", "known", "-", "x"], texts(tokenize("well -known - x")));
+        assert_eq!(vec!["state", "-", "of", "-", "the", "-", "art"], texts(WordTokenizer::new().split_hyphens(true).tokenize("state-of-the-art")));
+    }
+
+    #[test]
+    fn words_numbers() {
+        let tokens = tokenize("1,000.50 3.14 42nd");
+        assert_eq!(vec!["1,000.50", "3.14", "42nd"], texts(tokens.clone()));
+        assert_eq!(vec![TokenKind::Number, TokenKind::Number, TokenKind::Word], tokens.iter().map(|t| t.kind).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn words_urls_and_emails() {
+        let tokens = tokenize("(see www.example.com/a_(b)) mail: john+nlp@mail.example.org.");
+        assert_eq!(vec!["(", "see", "www.example.com/a_(b)", ")", "mail", ":", "john+nlp@mail.example.org", "."], texts(tokens.clone()));
+        assert_eq!(TokenKind::Url, tokens[2].kind);
+        assert_eq!(TokenKind::Email, tokens[6].kind);
+        assert_eq!(vec!["a", "@", "b"], texts(tokenize("a @ b")));
+        assert_eq!(vec!["user", "@", "localhost"], texts(tokenize("user@localhost")));
+        assert_eq!(TokenKind::Word, WordTokenizer::new().urls(false).tokenize("www.example.com")[0].kind);
+    }
+
+    #[test]
+    fn words_emoticons() {
+        let tokens = tokenize("Great :-) <3 see:)");
+        assert_eq!(vec!["Great", ":-)", "<3", "see", ":", ")"], texts(tokens.clone()));
+        assert_eq!(TokenKind::Emoticon, tokens[1].kind);
+        assert_eq!(TokenKind::Emoticon, tokenize("ok 👍🏽")[1].kind);
+    }
+
+    #[test]
+    fn words_offsets() {
+        let tokens = tokenize("Zoë  naïve");
+        assert_eq!((0, 4, 0, 3), (tokens[0].start, tokens[0].end, tokens[0].char_start, tokens[0].char_end));
+        assert_eq!((6, 12, 5, 10), (tokens[1].start, tokens[1].end, tokens[1].char_start, tokens[1].char_end));
+        assert!(tokenize("  ").is_empty());
+    }
+}
@@ -0,0 +1,490 @@
+use distance::StringSimilarity;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::iter::{FromIterator, Zip};
+use std::slice;
+use tokenize::word::words;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Weight of a term in a document, from the number of times it occurs there
+pub enum TfScaling {
+    /// Number of occurrences
+    Raw,
+    /// 1 when the term occurs, whatever the number of times
+    Binary,
+    /// 1 + ln(occurrences), so that a term repeated 10 times doesn't weigh 10 times more
+    Sublinear,
+    /// Occurrences divided by the number of terms of the document
+    Frequency,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+/// Number of documents a term occurs in, as a count or a share of the documents
+pub enum DocumentFrequency {
+    /// Number of documents
+    Count(usize),
+    /// Share of the documents, between 0 and 1
+    Ratio(f64),
+}
+
+impl DocumentFrequency {
+    /// Number of documents out of `documents`, not rounded
+    fn count(self, documents: usize) -> f64 {
+        match self {
+            DocumentFrequency::Count(count) => count as f64,
+            DocumentFrequency::Ratio(ratio) => ratio * documents as f64,
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+/// Sparse vector: its non null values and their indices, in increasing order
+///
+/// Examples:
+///
+/// ```
+/// use nlp::vectorize::SparseVector;
+///
+/// let vector = vec![(4, 1.0), (1, 2.0), (4, 0.5), (7, 0.0)].into_iter().collect::<SparseVector>();
+/// assert_eq!(&[1, 4], vector.indices());
+/// assert_eq!(&[2.0, 1.5], vector.values());
+/// assert_eq!(1.5, vector.get(4));
+/// assert_eq!(0.0, vector.get(7));
+/// ```
+pub struct SparseVector {
+    indices: Vec<usize>,
+    values: Vec<f64>,
+}
+
+impl SparseVector {
+    /// Vector without any non null value
+    pub fn new() -> SparseVector {
+        SparseVector::default()
+    }
+
+    /// Indices of the non null values, in increasing order
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Non null values, in the order of their indices
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Indices and values of the non null values
+    pub fn iter(&self) -> Zip<slice::Iter<'_, usize>, slice::Iter<'_, f64>> {
+        self.indices.iter().zip(self.values.iter())
+    }
+
+    /// Value at an index, 0 when it's not stored
+    pub fn get(&self, index: usize) -> f64 {
+        match self.indices.binary_search(&index) {
+            Ok(i)  => self.values[i],
+            Err(_) => 0.0,
+        }
+    }
+
+    /// Number of non null values
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Whether all the values are null
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Dot product of two vectors
+    pub fn dot(&self, other: &SparseVector) -> f64 {
+        let (mut i, mut j) = (0, 0);
+        let mut dot = 0.0;
+        while i < self.indices.len() && j < other.indices.len() {
+            if self.indices[i] < other.indices[j] {
+                i += 1;
+            } else if self.indices[i] > other.indices[j] {
+                j += 1;
+            } else {
+                dot += self.values[i] * other.values[j];
+                i += 1;
+                j += 1;
+            }
+        }
+
+        dot
+    }
+
+    /// Euclidean (L2) norm
+    pub fn norm(&self) -> f64 {
+        self.values.iter().map(|value| value * value).sum::<f64>().sqrt()
+    }
+
+    /// Vector divided by its norm, the null vector as is
+    pub fn normalized(mut self) -> SparseVector {
+        let norm = self.norm();
+        if norm > 0.0 {
+            for value in &mut self.values {
+                *value /= norm;
+            }
+        }
+
+        self
+    }
+}
+
+impl FromIterator<(usize, f64)> for SparseVector {
+    /// Vector of indices and values in any order: the values of the same index are added, and
+    /// the null ones dropped
+    fn from_iter<I: IntoIterator<Item = (usize, f64)>>(pairs: I) -> SparseVector {
+        let mut pairs = pairs.into_iter().collect::<Vec<_>>();
+        pairs.sort_by_key(|&(index, _)| index);
+
+        let mut vector = SparseVector::new();
+        for (index, value) in pairs {
+            if vector.indices.last() == Some(&index) {
+                *vector.values.last_mut().unwrap() += value;
+            } else {
+                vector.indices.push(index);
+                vector.values.push(value);
+            }
+        }
+        let (indices, values) = vector.indices.into_iter().zip(vector.values).filter(|&(_, value)| value != 0.0).unzip();
+
+        SparseVector { indices, values }
+    }
+}
+
+/// Cosine similarity of two vectors, between -1 and 1, 0 when one of them is null
+///
+/// The TF-IDF vectors have no negative value, so their similarity is between 0 and 1.
+pub fn cosine_similarity(a: &SparseVector, b: &SparseVector) -> f64 {
+    let norms = a.norm() * b.norm();
+    if norms == 0.0 {
+        0.0
+    } else {
+        (a.dot(b) / norms).clamp(-1.0, 1.0)
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+/// TF-IDF vectorizer: the documents become sparse vectors of the weights of their terms
+///
+/// The weight of a term is its scaled number of occurrences in the document (TF) times the inverse
+/// of the share of the documents it occurs in (IDF): `ln(n / df) + 1`, or `ln((1 + n) / (1 + df)) + 1`
+/// with the smoothing, as if a document had every term once. The vectors are then divided by their
+/// L2 norm, so that their dot product is their cosine similarity.
+///
+/// The documents are sequences of terms: strings, or the tokens of `WordTokenizer`, after removing
+/// the stopwords or stemming them as needed. The terms are lowercased by default.
+///
+/// Once fitted, the vectorizer compares texts split into `words` as a `StringSimilarity`: unlike
+/// the Jaro similarity, it tells long texts on the same subject from the others.
+///
+/// Examples:
+///
+/// ```
+/// use nlp::vectorize::{cosine_similarity, TfIdfVectorizer};
+/// use nlp::distance::StringSimilarity;
+///
+/// let documents = ["The cat sat on the mat", "The dog sat on the log", "Cats and dogs"];
+/// let mut vectorizer = TfIdfVectorizer::new();
+/// let vectors = vectorizer.fit_transform(documents.iter().map(|document| document.split(' ')));
+/// assert_eq!(10, vectorizer.vocabulary().len());
+/// assert!(cosine_similarity(&vectors[0], &vectors[1]) > cosine_similarity(&vectors[0], &vectors[2]));
+///
+/// assert!(vectorizer.similarity("the cat sat", "the dog sat") > 0.0);
+/// assert_eq!(0.0, vectorizer.similarity("a cat", "a dog"));
+/// ```
+pub struct TfIdfVectorizer {
+    tf: TfScaling,
+    smooth_idf: bool,
+    normalize: bool,
+    lowercase: bool,
+    min_df: DocumentFrequency,
+    max_df: DocumentFrequency,
+    max_features: Option<usize>,
+    vocabulary: HashMap<String, usize>,
+    terms: Vec<String>,
+    idf: Vec<f64>,
+}
+
+impl Default for TfIdfVectorizer {
+    fn default() -> TfIdfVectorizer {
+        TfIdfVectorizer {
+            tf: TfScaling::Raw,
+            smooth_idf: true,
+            normalize: true,
+            lowercase: true,
+            min_df: DocumentFrequency::Count(1),
+            max_df: DocumentFrequency::Ratio(1.0),
+            max_features: None,
+            vocabulary: HashMap::new(),
+            terms: Vec::new(),
+            idf: Vec::new(),
+        }
+    }
+}
+
+impl TfIdfVectorizer {
+    /// Vectorizer with raw counts, smoothed IDF, L2 normalization, lowercased terms and every
+    /// term kept
+    pub fn new() -> TfIdfVectorizer {
+        TfIdfVectorizer::default()
+    }
+
+    /// Set the scaling of the term counts
+    pub fn tf(mut self, tf: TfScaling) -> TfIdfVectorizer {
+        self.tf = tf;
+        self
+    }
+
+    /// Set whether the IDF is smoothed, which also prevents divisions by zero when transforming
+    pub fn smooth_idf(mut self, smooth_idf: bool) -> TfIdfVectorizer {
+        self.smooth_idf = smooth_idf;
+        self
+    }
+
+    /// Set whether the vectors are divided by their L2 norm
+    pub fn normalize(mut self, normalize: bool) -> TfIdfVectorizer {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Set whether the terms are lowercased
+    pub fn lowercase(mut self, lowercase: bool) -> TfIdfVectorizer {
+        self.lowercase = lowercase;
+        self
+    }
+
+    /// Set the number of documents a term must at least occur in, to drop the rare ones
+    pub fn min_df(mut self, min_df: DocumentFrequency) -> TfIdfVectorizer {
+        self.min_df = min_df;
+        self
+    }
+
+    /// Set the number of documents a term can at most occur in, to drop the ones as common as
+    /// stopwords
+    pub fn max_df(mut self, max_df: DocumentFrequency) -> TfIdfVectorizer {
+        self.max_df = max_df;
+        self
+    }
+
+    /// Set the size of the vocabulary, keeping the terms occurring the most in all the documents
+    pub fn max_features(mut self, max_features: usize) -> TfIdfVectorizer {
+        self.max_features = Some(max_features);
+        self
+    }
+
+    fn term<'a>(&self, term: &'a str) -> Cow<'a, str> {
+        if self.lowercase && term.chars().any(char::is_uppercase) {
+            term.to_lowercase().into()
+        } else {
+            term.into()
+        }
+    }
+
+    /// Learn the vocabulary and the IDF of the terms of documents, forgetting the previous ones
+    ///
+    /// The vocabulary is sorted, the index of a term in the vectors is its rank.
+    pub fn fit<I, D, T>(&mut self, documents: I)
+    where
+        I: IntoIterator<Item = D>,
+        D: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let mut n = 0;
+        let mut counts: HashMap<String, (usize, usize)> = HashMap::new();
+        for document in documents {
+            n += 1;
+            let mut terms = HashMap::new();
+            for term in document {
+                *terms.entry(self.term(term.as_ref()).into_owned()).or_insert(0) += 1;
+            }
+            for (term, count) in terms {
+                let entry = counts.entry(term).or_insert((0, 0));
+                entry.0 += 1;
+                entry.1 += count;
+            }
+        }
+
+        let (min_df, max_df) = (self.min_df.count(n), self.max_df.count(n));
+        let mut kept = counts.into_iter().filter(|&(_, (df, _))| df as f64 >= min_df && df as f64 <= max_df).collect::<Vec<_>>();
+        if let Some(max_features) = self.max_features {
+            kept.sort_by(|a, b| (b.1).1.cmp(&(a.1).1).then_with(|| a.0.cmp(&b.0)));
+            kept.truncate(max_features);
+        }
+        kept.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let smooth = if self.smooth_idf { 1.0 } else { 0.0 };
+        self.idf = kept.iter().map(|&(_, (df, _))| ((n as f64 + smooth) / (df as f64 + smooth)).ln() + 1.0).collect();
+        self.terms = kept.into_iter().map(|(term, _)| term).collect();
+        self.vocabulary = self.terms.iter().enumerate().map(|(i, term)| (term.clone(), i)).collect();
+    }
+
+    /// TF-IDF vector of a document, its terms out of the vocabulary ignored
+    pub fn transform<D, T>(&self, document: D) -> SparseVector
+    where
+        D: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let mut counts = HashMap::new();
+        let mut length = 0;
+        for term in document {
+            length += 1;
+            if let Some(&index) = self.vocabulary.get(&self.term(term.as_ref())[..]) {
+                *counts.entry(index).or_insert(0) += 1;
+            }
+        }
+
+        let vector = counts.into_iter().map(|(index, count)| {
+            let tf = match self.tf {
+                TfScaling::Raw       => count as f64,
+                TfScaling::Binary    => 1.0,
+                TfScaling::Sublinear => 1.0 + (count as f64).ln(),
+                TfScaling::Frequency => count as f64 / length as f64,
+            };
+            (index, tf * self.idf[index])
+        }).collect::<SparseVector>();
+
+        if self.normalize { vector.normalized() } else { vector }
+    }
+
+    /// Learn the vocabulary of documents, then their TF-IDF vectors
+    pub fn fit_transform<I, D, T>(&mut self, documents: I) -> Vec<SparseVector>
+    where
+        I: IntoIterator<Item = D>,
+        D: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let documents = documents.into_iter().map(|document| document.into_iter().collect::<Vec<_>>()).collect::<Vec<_>>();
+        self.fit(&documents);
+        documents.iter().map(|document| self.transform(document)).collect()
+    }
+
+    /// Terms of the vocabulary, the index of each one being its position
+    pub fn vocabulary(&self) -> &[String] {
+        &self.terms
+    }
+
+    /// Index of a term in the vectors
+    pub fn index(&self, term: &str) -> Option<usize> {
+        self.vocabulary.get(&self.term(term)[..]).cloned()
+    }
+
+    /// Inverse document frequency of a term
+    pub fn idf(&self, term: &str) -> Option<f64> {
+        self.index(term).map(|index| self.idf[index])
+    }
+}
+
+impl StringSimilarity for TfIdfVectorizer {
+    fn similarity(&self, a: &str, b: &str) -> f64 {
+        cosine_similarity(&self.transform(words(a)), &self.transform(words(b)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stem::english::English;
+    use stem::stemmer::Stemmer;
+    use stopwords::{Language, Stopwords};
+    use tokenize::word::WordTokenizer;
+
+    static DOCUMENTS: &[&str] = &["the cat sat", "the cat ran", "the dog ran far far away"];
+
+    fn split() -> Vec<Vec<&'static str>> {
+        DOCUMENTS.iter().map(|document| document.split(' ').collect()).collect()
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn vectorize_sparse_vector() {
+        let a = vec![(0, 1.0), (2, 2.0), (5, 3.0)].into_iter().collect::<SparseVector>();
+        let b = vec![(2, 4.0), (3, 1.0), (5, -1.0)].into_iter().collect::<SparseVector>();
+        assert_eq!(5.0, a.dot(&b));
+        assert_eq!(5.0, b.dot(&a));
+        assert!(close(14f64.sqrt(), a.norm()));
+        assert!(close(1.0, a.clone().normalized().norm()));
+        assert!(close(5.0 / (14f64.sqrt() * 18f64.sqrt()), cosine_similarity(&a, &b)));
+        assert!(close(1.0, cosine_similarity(&a, &a)));
+        assert_eq!(0.0, cosine_similarity(&a, &SparseVector::new()));
+        assert_eq!(vec![(&0, &1.0), (&2, &2.0), (&5, &3.0)], a.iter().collect::<Vec<_>>());
+        assert!(vec![(1, 1.0), (1, -1.0)].into_iter().collect::<SparseVector>().is_empty());
+    }
+
+    #[test]
+    fn vectorize_idf() {
+        let mut vectorizer = TfIdfVectorizer::new();
+        vectorizer.fit(split());
+        assert_eq!(vec!["away", "cat", "dog", "far", "ran", "sat", "the"], vectorizer.vocabulary());
+        assert!(close(1.0, vectorizer.idf("the").unwrap()));
+        assert!(close((4.0f64 / 3.0).ln() + 1.0, vectorizer.idf("cat").unwrap()));
+        assert!(close(2f64.ln() + 1.0, vectorizer.idf("dog").unwrap()));
+        assert_eq!(Some(1), vectorizer.index("Cat"));
+        assert_eq!(None, vectorizer.idf("bird"));
+
+        let mut vectorizer = TfIdfVectorizer::new().smooth_idf(false).lowercase(false);
+        vectorizer.fit(split());
+        assert!(close(1.5f64.ln() + 1.0, vectorizer.idf("cat").unwrap()));
+        assert!(close(3f64.ln() + 1.0, vectorizer.idf("dog").unwrap()));
+        assert_eq!(None, vectorizer.index("Cat"));
+    }
+
+    #[test]
+    fn vectorize_tf() {
+        let document = "the dog ran far far away".split(' ');
+        let idf = |vectorizer: &TfIdfVectorizer, term| vectorizer.idf(term).unwrap();
+        let scalings = [(TfScaling::Raw, 2.0), (TfScaling::Binary, 1.0), (TfScaling::Sublinear, 1.0 + 2f64.ln()),
+                        (TfScaling::Frequency, 2.0 / 6.0)];
+        for &(tf, far) in &scalings {
+            let mut vectorizer = TfIdfVectorizer::new().tf(tf).normalize(false);
+            vectorizer.fit(split());
+            let vector = vectorizer.transform(document.clone());
+            assert_eq!(5, vector.len());
+            assert!(close(far * idf(&vectorizer, "far"), vector.get(vectorizer.index("far").unwrap())), "{:?}", tf);
+        }
+
+        let mut vectorizer = TfIdfVectorizer::new();
+        let vectors = vectorizer.fit_transform(split());
+        assert!(vectors.iter().all(|vector| close(1.0, vector.norm())));
+        assert!(vectorizer.transform(vec!["bird"]).is_empty());
+    }
+
+    #[test]
+    fn vectorize_pruning() {
+        let mut vectorizer = TfIdfVectorizer::new().min_df(DocumentFrequency::Count(2));
+        vectorizer.fit(split());
+        assert_eq!(vec!["cat", "ran", "the"], vectorizer.vocabulary());
+
+        let mut vectorizer = TfIdfVectorizer::new().max_df(DocumentFrequency::Ratio(0.7));
+        vectorizer.fit(split());
+        assert_eq!(vec!["away", "cat", "dog", "far", "ran", "sat"], vectorizer.vocabulary());
+
+        let mut vectorizer = TfIdfVectorizer::new().max_features(3);
+        vectorizer.fit(split());
+        assert_eq!(vec!["cat", "far", "the"], vectorizer.vocabulary());
+        assert_eq!(Some(2), vectorizer.index("the"));
+    }
+
+    #[test]
+    fn vectorize_similarity() {
+        let documents = ["The Running of the Bulls is held in Pamplona every July.",
+                         "Every July, bulls run through the streets of Pamplona.",
+                         "The Tour de France runs through the Alps in July."];
+        let stopwords = Stopwords::for_language(Language::English);
+        let stemmer = English;
+        let terms = |text| stopwords.filter(WordTokenizer::new().punctuation(false).tokenize(text))
+                                    .map(|token| stemmer.stem(token.text))
+                                    .collect::<Vec<_>>();
+
+        let mut vectorizer = TfIdfVectorizer::new().tf(TfScaling::Sublinear);
+        let vectors = vectorizer.fit_transform(documents.iter().map(|&document| terms(document)));
+        assert!(vectorizer.index("bull").is_some() && vectorizer.index("the").is_none());
+        assert!(cosine_similarity(&vectors[0], &vectors[1]) > 0.5);
+        assert!(cosine_similarity(&vectors[0], &vectors[2]) < 0.3);
+    }
+}
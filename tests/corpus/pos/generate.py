@@ -0,0 +1,221 @@
+"""Generate english.conll, the sentences of the part of speech tagger tests
+
+The sentences are drawn from a small English grammar with a fixed seed, so the output is the same
+on every run. The last 10% of the sentences, held out by tests/pos.rs, also use the NEW_* words.
+
+Usage: python3 tests/corpus/pos/generate.py
+"""
+import os
+import random
+rng = random.Random(49)
+
+FIRST = "Adam Alan Albert Amy Andrew Angela Arthur Barbara Ben Bruno Carl Carlos Carmen Caroline Charles Diana Edward Elena Elizabeth Eric Eva Felix Frank George Hannah Harry Helen Henry Irene Isabel Jack Jacob Jane Jean Karen Karl Kate Laura Leo Linda Lisa Louis Lucy Marco Marie Max Monica Nancy Oliver Pablo Patrick Philip Rachel Ricardo Ruth Samuel Simon Stella Steven Susan Tom Ursula Vera Walter William Yuki Zoe John Mary Anna Peter Maria James Sarah David Laura Michael Emma Thomas Sophie Daniel Julia Robert Alice Martin Claire Paul Nina Lucas Olivia Hugo Ingrid Mark Will Rose Grace Victor".split()
+LAST = "Adams Allen Bailey Bell Bennett Campbell Carter Clark Collins Cook Cooper Davies Davis Edwards Evans Fernandez Foster Gonzalez Gray Hall Hughes Hunter James Johnson Kelly Kennedy Lee Lewis Martinez Mitchell Morgan Murphy Nelson Parker Perez Phillips Price Reed Richardson Roberts Robinson Rodriguez Russell Sanchez Scott Stewart Turner Ward Watson Williams Wood Becker Bauer Weber Wagner Hoffmann Lambert Laurent Leroy Bianchi Romano Ferrari Ricci Nowak Wojcik Horvath Larsen Nielsen Hansen Olsen Berg Lindberg Johansson Pereira Santos Oliveira Ivanov Smith Jones Garcia Miller Brown Wilson Moore Taylor Anderson Jackson White Harris Thompson Schmidt Dubois Rossi Novak Kowalski Svensson Jensen Costa Silva Walker Young King Wright Lopez Hill Green Baker".split()
+PLACES = "Amsterdam Athens Bangkok Barcelona Beijing Bristol Brussels Cairo Chile Denver Florida France Geneva Germany Glasgow Hamburg India Italy Kenya Lyon Manchester Mexico Milan Moscow Naples Norway Ohio Peru Portugal Sweden Seattle Spain Tokyo Turin Utah Venice Zurich Paris London Berlin Madrid Rome Boston Chicago Texas Canada Japan Oslo Lisbon Vienna Dublin Prague Warsaw Toronto Sydney Brazil Egypt".split()
+ORGS = [("Acme","Corp"),("General","Motors"),("Red","Cross"),("City","Bank"),("Global","Airlines"),("Northern","Rail")]
+MONTHS = "January February March April May June July August September October November December".split()
+DAYS = "Monday Tuesday Wednesday Thursday Friday Saturday Sunday".split()
+# held-out only
+NEW_FIRST = "Kevin Helga Akira Fatima Dmitri Chloe Oscar Priya Sven Beatriz".split()
+NEW_LAST = "Okafor Lindqvist Tanaka Moreau Petrov Haddad Nakamura Fischer Duarte Kaminski".split()
+NEW_PLACES = "Nairobi Helsinki Lima Seoul Munich Krakow Porto Quebec".split()
+
+NOUNS = [("apple","apples"),("artist","artists"),("bag","bags"),("bank","banks"),("bird","birds"),("boat","boats"),("bottle","bottles"),("box","boxes"),("bridge","bridges"),("brother","brothers"),("building","buildings"),("camera","cameras"),("chair","chairs"),("church","churches"),("cloud","clouds"),("coat","coats"),("country","countries"),("cup","cups"),("customer","customers"),("daughter","daughters"),("desk","desks"),("dinner","dinners"),("door","doors"),("driver","drivers"),("engine","engines"),("farm","farms"),("farmer","farmers"),("field","fields"),("flower","flowers"),("forest","forests"),("gift","gifts"),("glass","glasses"),("guest","guests"),("hill","hills"),("horse","horses"),("island","islands"),("key","keys"),("lake","lakes"),("lawyer","lawyers"),("machine","machines"),("manager","managers"),("map","maps"),("message","messages"),("minister","ministers"),("mountain","mountains"),("neighbor","neighbors"),("newspaper","newspapers"),("nurse","nurses"),("painter","painters"),("parent","parents"),("party","parties"),("picture","pictures"),("plane","planes"),("player","players"),("pocket","pockets"),("village","villages"),("restaurant","restaurants"),("shop","shops"),("singer","singers"),("sister","sisters"),("soldier","soldiers"),("station","stations"),("street","streets"),("teacher","teachers"),("tower","towers"),("town","towns"),("tree","trees"),("truck","trucks"),("university","universities"),("wall","walls"),("writer","writers"),("report","reports"),("book","books"),("car","cars"),("house","houses"),("city","cities"),("company","companies"),
+ ("market","markets"),("price","prices"),("plan","plans"),("letter","letters"),("meeting","meetings"),("problem","problems"),
+ ("student","students"),("teacher","teachers"),("doctor","doctors"),("river","rivers"),("dog","dogs"),("team","teams"),
+ ("game","games"),("song","songs"),("film","films"),("table","tables"),("window","windows"),("room","rooms"),("idea","ideas"),
+ ("story","stories"),("road","roads"),("child","children"),("man","men"),("woman","women"),("friend","friends"),
+ ("visit","visits"),("call","calls"),("saw","saws"),("mark","marks"),("light","lights"),("run","runs"),("train","trains"),
+ ("ticket","tickets"),("office","offices"),("garden","gardens"),("letter","letters"),("phone","phones"),("bill","bills"),
+ ("computer","computers"),("hotel","hotels"),("school","schools"),("question","questions"),("answer","answers")]
+NEW_NOUNS = [("bicycle","bicycles"),("museum","museums"),("contract","contracts"),("harbor","harbors"),("kitchen","kitchens")]
+# base, 3sg, past, participle, gerund, transitive
+VERBS = [("visit","visits","visited","visited","visiting",1),("book","books","booked","booked","booking",1),
+ ("call","calls","called","called","calling",1),("help","helps","helped","helped","helping",1),("watch","watches","watched","watched","watching",1),
+ ("sign","signs","signed","signed","signing",1),("open","opens","opened","opened","opening",1),("close","closes","closed","closed","closing",1),
+ ("move","moves","moved","moved","moving",1),("like","likes","liked","liked","liking",1),("need","needs","needed","needed","needing",1),
+ ("want","wants","wanted","wanted","wanting",1),("start","starts","started","started","starting",1),("finish","finishes","finished","finished","finishing",1),
+ ("plan","plans","planned","planned","planning",1),("stop","stops","stopped","stopped","stopping",1),("see","sees","saw","seen","seeing",1),
+ ("write","writes","wrote","written","writing",1),("buy","buys","bought","bought","buying",1),("sell","sells","sold","sold","selling",1),
+ ("take","takes","took","taken","taking",1),("give","gives","gave","given","giving",1),("find","finds","found","found","finding",1),
+ ("meet","meets","met","met","meeting",1),("read","reads","read","read","reading",1),("build","builds","built","built","building",1),
+ ("leave","leaves","left","left","leaving",1),("make","makes","made","made","making",1),("mark","marks","marked","marked","marking",1),
+ ("train","trains","trained","trained","training",1),("light","lights","lit","lit","lighting",1),
+ ("arrive","arrives","arrived","arrived","arriving",0),("sleep","sleeps","slept","slept","sleeping",0),("run","runs","ran","run","running",0),
+ ("work","works","worked","worked","working",0),("laugh","laughs","laughed","laughed","laughing",0),("wait","waits","waited","waited","waiting",0),
+ ("rise","rises","rose","risen","rising",0),("fall","falls","fell","fallen","falling",0)]
+NEW_VERBS = [("repair","repairs","repaired","repaired","repairing",1),("paint","paints","painted","painted","painting",1)]
+ADJ = [("new","newer","newest"),("old","older","oldest"),("big","bigger","biggest"),("small","smaller","smallest"),("red",None,None),
+ ("quick","quicker","quickest"),("long","longer","longest"),("short","shorter","shortest"),("good","better","best"),("bad","worse","worst"),
+ ("happy","happier","happiest"),("early","earlier","earliest"),("late","later","latest"),("local",None,None),("national",None,None),
+ ("young","younger","youngest"),("important",None,None),("difficult",None,None),("light","lighter","lightest"),("cheap","cheaper","cheapest"),
+ ("beautiful",None,None),("quiet","quieter","quietest"),("famous",None,None),("strange",None,None)]
+ADV = "quickly slowly often never always also really soon recently finally carefully already".split()
+INS = "in on at from with for about after before near under of by into during".split()
+OBJ_PRP = {"I":"me","you":"you","he":"him","she":"her","it":"it","we":"us","they":"them"}
+POSS = "my your his her its our their".split()
+MD = "will would can could should may might must".split()
+NUMS = [("two","CD"),("three","CD"),("five","CD"),("ten","CD"),("12","CD"),("100","CD"),("3.5","CD"),("1,200","CD")]
+YEARS = ["1999","2004","2010","2021","1987"]
+
+held = False
+def first(): return rng.choice(FIRST + (NEW_FIRST*2 if held else []))
+def last(): return rng.choice(LAST + (NEW_LAST*2 if held else []))
+def place(): return rng.choice(PLACES + (NEW_PLACES*2 if held else []))
+def noun(): return rng.choice(NOUNS + (NEW_NOUNS*3 if held else []))
+def verb(trans=None):
+    vs = VERBS + (NEW_VERBS*3 if held else [])
+    vs = [v for v in vs if trans is None or v[5]==trans]
+    return rng.choice(vs)
+
+def adjs():
+    out=[]
+    if rng.random()<0.35:
+        a=rng.choice(ADJ)
+        if rng.random()<0.15: out.append((rng.choice(["very","really"]),"RB"))
+        out.append((a[0],"JJ"))
+    return out
+
+def name_np():
+    r=rng.random()
+    if r<0.35: return [(first(),"NNP")]
+    if r<0.7: return [(first(),"NNP"),(last(),"NNP")]
+    if r<0.8: return [(rng.choice(["Mr.","Mrs.","Dr.","Ms."]),"NNP"),(last(),"NNP")]
+    if r<0.9: o=rng.choice(ORGS); return [(o[0],"NNP"),(o[1],"NNP")]
+    return [(place(),"NNP")]
+
+def np(plural=False, subject=True, allow_name=True):
+    """returns (tokens, person) person: '3s','3p','1s','2','1p'"""
+    r=rng.random()
+    if allow_name and r<0.3:
+        return name_np(),"3s"
+    if r<0.42:
+        p=rng.choice(list(OBJ_PRP))
+        w=p if subject else OBJ_PRP[p]
+        person={"I":"1s","you":"2","he":"3s","she":"3s","it":"3s","we":"1p","they":"3p"}[p]
+        return [(w,"PRP")],person
+    n=noun()
+    pl = plural or rng.random()<0.35
+    if r<0.55:
+        toks=[(rng.choice(POSS),"PRP$")]+adjs()
+    elif r<0.62 and allow_name:
+        toks=name_np()+[("'s","POS")]+adjs()
+    elif r<0.7 and pl:
+        c=rng.choice(NUMS); toks=[c]+adjs()
+    else:
+        if pl: det=rng.choice(["the","these","those","some","the","many"]); dt="JJ" if det=="many" else "DT"
+        else: det=rng.choice(["the","a","this","that","every","the","the"]); dt="DT"
+        if det=="a" and False: pass
+        toks=[(det,dt)]+adjs()
+    toks += [(n[1],"NNS") if pl else (n[0],"NN")]
+    # fix a/an
+    for i,(w,t) in enumerate(toks):
+        if w=="a" and i+1<len(toks) and toks[i+1][0][0] in "aeiou": toks[i]=("an","DT")
+    if rng.random()<0.08:
+        v=verb(1)
+        toks += [("that" if rng.random()<0.5 else "which","WDT"),(name_np()[0][0],"NNP"),(v[2],"VBD")]
+    return toks,("3p" if pl else "3s")
+
+def pp():
+    r=rng.random()
+    if r<0.2: return [(rng.choice(["in","during"]),"IN"),(rng.choice(MONTHS),"NNP")] + ([(rng.choice(YEARS),"CD")] if rng.random()<0.3 else [])
+    if r<0.35: return [("on","IN"),(rng.choice(DAYS),"NNP")]
+    if r<0.55: p=rng.choice(["in","from","near","to"]); return [(p,"TO" if p=="to" else "IN"),(place(),"NNP")]
+    t,_=np(subject=False)
+    return [(rng.choice(INS),"IN")]+t
+
+def fix_to(toks):
+    return [(w,"TO") if w=="to" else (w,t) for w,t in toks]
+
+def present(v, person):
+    return (v[1],"VBZ") if person=="3s" else (v[0],"VBP")
+
+def be(person, past):
+    if past: return ("was","VBD") if person in("1s","3s") else ("were","VBD")
+    return {"1s":("am","VBP"),"3s":("is","VBZ")}.get(person,("are","VBP"))
+
+def have(person, past):
+    if past: return ("had","VBD")
+    return ("has","VBZ") if person=="3s" else ("have","VBP")
+
+def obj():
+    t,_=np(subject=False); return t
+
+def vp(person):
+    r=rng.random()
+    if r<0.22:
+        v=verb(); out=[(v[2],"VBD")]+(obj() if v[5] else [])
+    elif r<0.38:
+        v=verb(); out=[(rng.choice(MD),"MD")]+([("not","RB")] if rng.random()<0.2 else [])+[(v[0],"VB")]+(obj() if v[5] else [])
+    elif r<0.52:
+        v=verb(); out=[present(v,person)]+(obj() if v[5] else [])
+    elif r<0.62:
+        v=verb(1); w=rng.choice(VERBS)
+        out=[(w[2],"VBD") if rng.random()<0.5 else present(w,person)] if False else [(rng.choice(["wanted","planned","needed","tried","decided"]),"VBD")]
+        out+=[("to","TO"),(v[0],"VB")]+obj()
+    elif r<0.72:
+        v=verb(); out=[be(person, rng.random()<0.5),(v[4],"VBG")]+(obj() if v[5] else [])
+    elif r<0.8:
+        v=verb(); out=[have(person, rng.random()<0.4)]+([(rng.choice(["already","never","recently"]),"RB")] if rng.random()<0.3 else [])+[(v[3],"VBN")]+(obj() if v[5] else [])
+    elif r<0.87:
+        v=verb(1); out=[be(person, True),(v[3],"VBN"),("by","IN")]+name_np()
+    elif r<0.93:
+        v=verb(); out=[(v[2],"VBD")]+(obj() if v[5] else [])+[(rng.choice(ADV),"RB")]
+    else:
+        a=rng.choice(ADJ)
+        if a[1] and rng.random()<0.5:
+            out=[be(person, rng.random()<0.5),(a[1],"JJR"),("than","IN")]+obj()
+        else:
+            out=[be(person, rng.random()<0.5),(a[0],"JJ")]
+    if rng.random()<0.4: out+=pp()
+    return out
+
+def clause():
+    s,person=np()
+    return s+vp(person)
+
+def sentence():
+    r=rng.random()
+    if r<0.55: toks=clause()+[(".",".")]
+    elif r<0.65: toks=clause()+[(",",","),(rng.choice(["and","but"]),"CC")]+clause()+[(".",".")]
+    elif r<0.72: toks=pp()+[(",",",")]+clause()+[(".",".")]
+    elif r<0.78:
+        n=noun(); pl=rng.random()<0.5
+        toks=[("there","EX"),(("were","VBD") if pl else ("was","VBD")) if rng.random()<0.5 else (("are","VBP") if pl else ("is","VBZ"))]
+        toks+=[(rng.choice(["two","many","some"]),"CD" if False else "DT")] if pl else [("a","DT")]
+        if pl and toks[-1][0]=="two": toks[-1]=("two","CD")
+        if pl and toks[-1][0]=="many": toks[-1]=("many","JJ")
+        toks+=[(n[1],"NNS") if pl else (n[0],"NN")]
+        if not pl and n[0][0] in "aeiou": toks[-2]=("an","DT")
+        toks+=pp()+[(".",".")]
+    elif r<0.84:
+        s,person=np(); v=verb()
+        toks=[(rng.choice(MD),"MD")]+s+[(v[0],"VB")]+(obj() if v[5] else [])+[("?",".")]
+    elif r<0.88:
+        s,person=np(); v=verb()
+        toks=[("did","VBD")]+s+[(v[0],"VB")]+(obj() if v[5] else [])+[("?",".")]
+    elif r<0.92:
+        s,person=np(); v=verb(0)
+        toks=[(rng.choice(["when","where","why"]),"WRB"),(rng.choice(MD),"MD")]+s+[(v[0],"VB")]+[("?",".")]
+    elif r<0.96:
+        s,person=name_np(),"3s"
+        v=verb(1)
+        toks=s+[(",",","),("who","WP"),(v[2],"VBD")]+obj()+[(",",",")]+vp(person)+[(".",".")]
+    else:
+        s,person=np()
+        toks=s+[(rng.choice(["said","thought","knew"]),"VBD"),("that","IN")]+clause()+[(".",".")]
+    w,t=toks[0]
+    if t not in("NNP",):
+        toks[0]=(w[0].upper()+w[1:],t)
+    return toks
+
+out=[]
+N=3000
+for i in range(N):
+    held = i >= int(N*0.9)
+    out.append(sentence())
+with open(os.path.join(os.path.dirname(os.path.abspath(__file__)), 'english.conll'), 'w') as f:
+    f.write("# Synthetic English sentences with Penn Treebank tags, see README.md\n")
+    for s in out:
+        for w,t in s: f.write(f"{w}\t{t}\n")
+        f.write("\n")
@@ -0,0 +1,25 @@
+"""Sample a vocabulary of snowball-data into tests/corpus/snowball
+
+Keeps one line out of `step` of `voc.txt` and `output.txt`, leaving out the words with uppercase
+letters, which the stemmers lowercase.
+
+Usage: python3 tests/corpus/snowball/sample.py <snowball-data> <language> <step>
+"""
+import os
+import sys
+
+data, language, step = sys.argv[1], sys.argv[2], int(sys.argv[3])
+
+def lines(name):
+    with open(os.path.join(data, language, name), encoding='utf-8') as f:
+        return f.read().split('\n')
+
+pairs = [(word, stem) for i, (word, stem) in enumerate(zip(lines('voc.txt'), lines('output.txt')))
+         if i % step == 0 and word and word == word.lower()]
+
+out = os.path.join(os.path.dirname(os.path.abspath(__file__)), language)
+os.makedirs(out, exist_ok=True)
+with open(os.path.join(out, 'voc.txt'), 'w', encoding='utf-8') as f:
+    f.write(''.join(word + '\n' for word, _ in pairs))
+with open(os.path.join(out, 'output.txt'), 'w', encoding='utf-8') as f:
+    f.write(''.join(stem + '\n' for _, stem in pairs))
@@ -1247,3 +1247,74 @@ fn double_metaphone_on_dict() {
 fn handles_wlodzimierz() {
     assert!(double_metaphone("Wlodzimierz").is_some());
 }
+
+#[test]
+fn double_metaphone_default_max_length() {
+    let default = DoubleMetaphone::new().double_metaphone("Wojciechowski").unwrap();
+    assert_eq!(double_metaphone("Wojciechowski"), Some(default));
+}
+
+#[test]
+fn double_metaphone_longer_max_length() {
+    let result = DoubleMetaphone::with_max_length(6).double_metaphone("Schwarzenegger").unwrap();
+    assert_eq!("XRSNKR", result.primary);
+    assert_eq!("XFRTSN", result.alternate);
+
+    let result = DoubleMetaphone::with_max_length(8).double_metaphone("Wojciechowski").unwrap();
+    assert_eq!("AJSXSK", result.primary);
+    assert_eq!("FJXKFSK", result.alternate);
+
+    let result = DoubleMetaphone::with_max_length(8).double_metaphone("Montgomery-Smith").unwrap();
+    assert_eq!("MNTKMRSM", result.primary);
+    assert_eq!("MNTKMRSM", result.alternate);
+}
+
+#[test]
+fn double_metaphone_unlimited_max_length() {
+    let result = DoubleMetaphone::with_max_length(0).double_metaphone("Montgomery-Smith").unwrap();
+    assert_eq!("MNTKMRSM0", result.primary);
+    assert_eq!("MNTKMRSMT", result.alternate);
+
+    let result = DoubleMetaphone::with_max_length(0).double_metaphone("Schermerhorn").unwrap();
+    assert_eq!("XRMRRN", result.primary);
+    assert_eq!("SKRMRRN", result.alternate);
+}
+
+#[test]
+fn double_metaphone_short_max_length() {
+    let result = DoubleMetaphone::with_max_length(2).double_metaphone("Christensen").unwrap();
+    assert_eq!("KR", result.primary);
+    assert_eq!("KR", result.alternate);
+}
+
+#[test]
+fn double_metaphone_trace() {
+    let (result, trace) = DoubleMetaphone::new().encode_with_trace("Thomas").unwrap();
+    assert_eq!(double_metaphone("Thomas"), Some(result));
+    assert_eq!("TH: germanic", trace.steps[0].rule);
+    assert_eq!("TH", trace.steps[0].consumed);
+
+    let (result, trace) = DoubleMetaphone::new().encode_with_trace("Gnome").unwrap();
+    assert_eq!("NM", result.primary);
+    assert_eq!(vec!["silent start: GN, KN, PN, WR, PS", "N", "vowel", "M", "vowel"], trace.rules());
+}
+
+#[test]
+fn double_metaphone_trace_alternates() {
+    let (result, trace) = DoubleMetaphone::with_max_length(0).encode_with_trace("Czerny").unwrap();
+    let primary = trace.steps.iter().map(|step| &step.primary[..]).collect::<String>();
+    let alternate = trace.steps.iter().map(|step| &step.alternate[..]).collect::<String>();
+    assert_eq!(result.primary, primary);
+    assert_eq!(result.alternate, alternate);
+    assert_eq!("CZ: Czerny", trace.steps[0].rule);
+    assert_eq!(("S", "X"), (&trace.steps[0].primary[..], &trace.steps[0].alternate[..]));
+    assert!(DoubleMetaphone::new().encode_with_trace("  ").is_none());
+}
+
+#[test]
+fn double_metaphone_short_words() {
+    for word in &["AXEAXE", "IS", "IER", "CCE", "AX"] {
+        assert!(double_metaphone(word).is_some(), "{}", word);
+    }
+    assert_eq!("AKSK", double_metaphone("AXEAXE").unwrap().primary);
+}
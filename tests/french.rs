@@ -0,0 +1,70 @@
+extern crate nlp;
+use nlp::phonetics::encoder::{encoder_by_name, PhoneticEncoder};
+use nlp::phonetics::french::soundex2::*;
+use nlp::phonetics::french::phonex::*;
+use nlp::phonetics::french::fonem::*;
+
+// (word, soundex2, phonex-fr key, fonem)
+const TEST_VALUES: &[(&str, &str, &str, &str)] = &[
+    ("purée", "PR  ", "turye", "PURE"),
+    ("putée", "PT  ", "tutye", "PUTE"),
+    ("bisous", "BS  ", "fiz3s", "BISOUS"),
+    ("Asamian", "AZMN", "ozoni1", "ASAMIAN"),
+    ("MacKenzie", "MKNZ", "nok1zie", "MACKENZIE"),
+    ("Philippe", "FLP ", "filite", "FILIPE"),
+    ("Chateau", "CHT ", "5oto", "CHATO"),
+    ("Habitat", "HBT ", "ofito", "ABITAT"),
+    ("Téhéran", "TRN ", "tyr1", "TEERAN"),
+    ("Crayon", "CRYN", "kryon", "KRAYON"),
+    ("Gauthier", "KTR ", "kotiyr", "GOTIER"),
+    ("Bernard", "BRNR", "fyrnor", "BERNAR"),
+    ("Dupond", "DPN ", "tuton", "DUPON"),
+    ("Beaulieu", "BL  ", "folie", "BOLIEU"),
+    ("Bergeron", "BRGR", "fyrkyron", "BERJERON"),
+    ("Lévesque", "LVSK", "lyfeske", "LEVESKE"),
+    ("Chamberland", "CHMB", "51fyrl1", "CHENBERLEN"),
+    ("Thibaudeau", "TBD ", "tifoto", "TIBODO"),
+    ("Arsenault", "ARSN", "orsenol", "ARSENO"),
+    ("Gaillard", "KLR ", "kylor", "GAYAR"),
+    ("Guillaume", "KLM ", "kilone", "GUYOME"),
+    ("Sainte Marie", "SNTM", "s4tenorie", "STE-MARIE"),
+];
+
+#[test]
+fn french_on_dict() {
+    for &(word, s2, key, fon) in TEST_VALUES {
+        assert_eq!(s2, soundex2(word), "word: {}", word);
+        assert_eq!(key, phonex_fr_key(word), "word: {}", word);
+        assert_eq!(fon, fonem(word), "word: {}", word);
+    }
+}
+
+#[test]
+fn french_silent_endings() {
+    assert_eq!(soundex2("Dupont"), soundex2("Dupond"));
+    assert_eq!(phonex_fr_key("Dupont"), phonex_fr_key("Dupond"));
+    assert_eq!(fonem("Dupont"), fonem("Dupond"));
+    assert_eq!(fonem("Arsenault"), fonem("Arseneault"));
+    assert_eq!(phonex_fr_key("Thibodeau"), phonex_fr_key("Thibaudeau"));
+}
+
+#[test]
+fn french_accents_and_liaison() {
+    assert_eq!(fonem("Côté"), fonem("Cote"));
+    assert_eq!(soundex2("Côté"), soundex2("Cote"));
+    assert_eq!(soundex2("D'Artagnan"), soundex2("Dartagnan"));
+    assert_eq!(phonex_fr_key("D'Artagnan"), phonex_fr_key("Dartagnan"));
+    assert_eq!(fonem("St-Pierre"), fonem("Saint-Pierre"));
+}
+
+#[test]
+fn french_encoders_by_name() {
+    for name in &["soundex2", "phonex_fr", "fonem"] {
+        let encoder = encoder_by_name(name).unwrap();
+        assert!(encoder.is_match("Rousseau", "Rousso"), "encoder: {}", name);
+        assert!(!encoder.is_match("Rousseau", "Martin"), "encoder: {}", name);
+    }
+    assert!(Soundex2::new().encode("").is_empty());
+    assert!(PhonexFr.encode("").is_empty());
+    assert!(Fonem.encode("").is_empty());
+}
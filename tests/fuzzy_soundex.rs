@@ -0,0 +1,57 @@
+extern crate nlp;
+use nlp::phonetics::fuzzy_soundex::*;
+
+// Holmes & McCabe examples
+const TEST_VALUES: &[(&str, &str)] = &[
+    ("", "00000"),
+    ("Kristen", "K6935"),
+    ("Krissy", "K6900"),
+    ("Christen", "K6935"),
+    ("Wight", "W3000"),
+    ("Hardt", "H6000"),
+    ("Hart", "H6000"),
+    ("Knight", "N3000"),
+    ("Czech", "S7000"),
+    ("Tsech", "S7000"),
+    ("gnomic", "N5900"),
+    ("Wright", "R3000"),
+    ("Hrothgar", "R3760"),
+    ("Hwaet", "W3000"),
+    ("Grant", "G6300"),
+];
+
+const TEST_VALUES_4: &[(&str, &str)] = &[
+    ("peter", "P360"),
+    ("pete", "P300"),
+    ("pedro", "P360"),
+    ("stephen", "S315"),
+    ("steve", "S310"),
+    ("smith", "S530"),
+    ("smythe", "S530"),
+    ("gail", "G400"),
+    ("gayle", "G400"),
+    ("christine", "K693"),
+    ("christina", "K693"),
+    ("kristina", "K693"),
+];
+
+#[test]
+fn fuzzy_soundex_on_dict() {
+    for &(word, code) in TEST_VALUES {
+        assert_eq!(code, fuzzy_soundex(word), "word: {}", word);
+    }
+}
+
+#[test]
+fn fuzzy_soundex_4_on_dict() {
+    let encoder = FuzzySoundex::with_max_length(4);
+    for &(word, code) in TEST_VALUES_4 {
+        assert_eq!(code, encoder.fuzzy_soundex(word), "word: {}", word);
+    }
+}
+
+#[test]
+fn fuzzy_soundex_unlimited() {
+    assert_eq!("K6935", FuzzySoundex::with_max_length(0).fuzzy_soundex("Kristen"));
+    assert_eq!("0", FuzzySoundex::with_max_length(0).fuzzy_soundex(""));
+}
@@ -0,0 +1,60 @@
+extern crate nlp;
+use nlp::lemma::{Lemmatizer, Pos};
+
+static ENGLISH_DICTIONARY: &str = include_str!("corpus/lemma/english.tsv");
+
+fn english() -> Lemmatizer {
+    Lemmatizer::load(ENGLISH_DICTIONARY.as_bytes()).unwrap()
+}
+
+#[test]
+fn dictionary_entries() {
+    let lemmatizer = english();
+    let mut count = 0;
+    for line in ENGLISH_DICTIONARY.lines().filter(|line| !line.starts_with('#')) {
+        let fields = line.split('\t').collect::<Vec<_>>();
+        match fields[..] {
+            [form, lemma, pos] => {
+                assert_eq!(lemma, lemmatizer.lemmatize(form, Pos::from_tag(pos)), "lemma of {:?}", form);
+                count += 1;
+            },
+            [lemma] => assert!(lemmatizer.is_lemma(lemma), "{:?} is a lemma", lemma),
+            _ => panic!("invalid line {:?}", line),
+        }
+    }
+    assert!(count > 150);
+}
+
+#[test]
+fn part_of_speech() {
+    let lemmatizer = english();
+    let tagged = [("better", "JJR", "good"), ("better", "RBR", "well"), ("left", "VBD", "leave"),
+                  ("left", "NN", "left"), ("saw", "VBD", "see"), ("saw", "NN", "saw"), ("lay", "VBD", "lie"),
+                  ("laid", "VBN", "lay"), ("meeting", "VBG", "meet"), ("meeting", "NN", "meeting"),
+                  ("stronger", "JJR", "strong"), ("wider", "JJR", "wide")];
+    for &(word, tag, lemma) in &tagged {
+        assert_eq!(lemma, lemmatizer.lemmatize(word, Pos::from_tag(tag)), "lemma of {:?}/{}", word, tag);
+    }
+}
+
+#[test]
+fn without_part_of_speech() {
+    let lemmatizer = english();
+    let words = [("Mice", "mouse"), ("better", "good"), ("children", "child"), ("buses", "bus"), ("glasses", "glass"),
+                 ("news", "news"), ("studies", "study"), ("running", "run"), ("went", "go"), ("wolves", "wolf"),
+                 ("analyses", "analysis"), ("dashboards", "dashboard")];
+    for &(word, lemma) in &words {
+        assert_eq!(lemma, lemmatizer.lemmatize(word, None), "lemma of {:?}", word);
+    }
+}
+
+#[test]
+fn known_lemmas() {
+    let mut lemmatizer = Lemmatizer::new();
+    assert_eq!("gase", lemmatizer.lemmatize("gases", Some(Pos::Noun)));
+    assert_eq!("lense", lemmatizer.lemmatize("lenses", Some(Pos::Noun)));
+    lemmatizer.add_lemma("gas");
+    lemmatizer.add_lemma("lens");
+    assert_eq!("gas", lemmatizer.lemmatize("gases", Some(Pos::Noun)));
+    assert_eq!("lens", lemmatizer.lemmatize("lenses", Some(Pos::Noun)));
+}
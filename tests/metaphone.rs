@@ -253,3 +253,58 @@ fn metaphone_11() {
 fn metaphone_12() {
     assert_eq!(metaphone("éréction"), "RKXN")
 }
+
+#[test]
+fn metaphone_default_max_length() {
+    assert_eq!(metaphone("Montgomery-Smith"), Metaphone::new().metaphone("Montgomery-Smith"));
+    assert_eq!("MNTKMRSM0", metaphone("Montgomery-Smith"));
+}
+
+#[test]
+fn metaphone_max_length() {
+    assert_eq!("SKRM", Metaphone::with_max_length(4).metaphone("Schermerhorn"));
+    assert_eq!("SKRMRH", Metaphone::with_max_length(6).metaphone("Schermerhorn"));
+    assert_eq!("SKRMRHRN", Metaphone::with_max_length(0).metaphone("Schermerhorn"));
+}
+
+#[test]
+fn metaphone_max_length_truncates_x() {
+    assert_eq!("AKSK", Metaphone::with_max_length(4).metaphone("AXEAXE"));
+    assert_eq!("AKSKS", Metaphone::with_max_length(0).metaphone("AXEAXE"));
+}
+
+#[test]
+fn metaphone_trace() {
+    let (code, trace) = Metaphone::new().encode_with_trace("Thompson");
+    assert_eq!(metaphone("Thompson"), code);
+    assert_eq!(vec!["TH", "silent", "silent", "F, J, L, M, N, R", "P", "S", "silent", "F, J, L, M, N, R"], trace.rules());
+    let appended = trace.steps.iter().map(|step| &step.primary[..]).collect::<String>();
+    assert_eq!(code, appended);
+}
+
+#[test]
+fn metaphone_trace_positions() {
+    let (_, trace) = Metaphone::new().encode_with_trace("Whitney");
+    assert_eq!("initial WH: H silent", trace.steps[0].rule);
+    assert_eq!((1, "H"), (trace.steps[0].position, &trace.steps[0].consumed[..]));
+    assert_eq!((2, "I"), (trace.steps[2].position, &trace.steps[2].consumed[..]));
+
+    let (_, trace) = Metaphone::new().encode_with_trace("Dodge");
+    assert_eq!("DGE, DGI, DGY", trace.steps[2].rule);
+    assert_eq!("DGE", trace.steps[2].consumed);
+    assert_eq!("J", trace.steps[2].primary);
+}
+
+#[test]
+fn metaphone_postgres_compatibility() {
+    let postgres = Metaphone::new().compatibility(MetaphoneCompatibility::Postgres);
+    assert_eq!("HTN", postgres.metaphone("Whitney"));
+    assert_eq!("SNK", postgres.metaphone("Xiong"));
+    assert_eq!("NFT", postgres.metaphone("Knight"));
+    assert_eq!("ABK", postgres.metaphone("123abc"));
+    assert_eq!("", postgres.metaphone("W"));
+
+    let (code, trace) = postgres.encode_with_trace("Schwartz");
+    assert_eq!("XWRTS", code);
+    assert_eq!(("SCHW", "SCH"), (trace.steps[0].rule, &trace.steps[0].consumed[..]));
+}
@@ -0,0 +1,87 @@
+extern crate nlp;
+use nlp::distance::levenshtein;
+use nlp::phonetics::soundex::{Soundex, SoundexVariant};
+use nlp::phonetics::metaphone::metaphone::{Metaphone, MetaphoneCompatibility};
+use nlp::phonetics::metaphone::double_metaphone::DoubleMetaphone;
+
+static POSTGRES_PHONETICS: &str = include_str!("corpus/postgres_phonetics.tsv");
+static POSTGRES_LEVENSHTEIN: &str = include_str!("corpus/postgres_levenshtein.tsv");
+static COMMONS_CODEC: &str = include_str!("corpus/commons_codec.tsv");
+
+/// Intentional deviations from the reference codes: encoder, input, and the code of this crate
+///
+/// They are documented in `tests/corpus/README.md`.
+static DEVIATIONS: &[(&str, &str, &str)] = &[
+    ("soundex", "ç", "Ç000"),
+];
+
+fn rows(corpus: &str) -> Vec<Vec<&str>> {
+    corpus
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| line.split('\t').collect())
+        .collect()
+}
+
+fn expected<'a>(encoder: &str, input: &str, reference: &'a str) -> &'a str {
+    match DEVIATIONS.iter().find(|&&(e, i, _)| e == encoder && i == input) {
+        Some(&(_, _, code)) => code,
+        None => reference,
+    }
+}
+
+#[test]
+fn postgres_soundex() {
+    let soundex = Soundex::new().variant(SoundexVariant::Simplified);
+    for row in rows(POSTGRES_PHONETICS) {
+        assert_eq!(expected("soundex", row[0], row[1]), soundex.soundex(row[0]), "{:?}", row[0]);
+    }
+}
+
+#[test]
+fn postgres_metaphone() {
+    let metaphone = Metaphone::new().compatibility(MetaphoneCompatibility::Postgres);
+    for row in rows(POSTGRES_PHONETICS) {
+        assert_eq!(expected("metaphone", row[0], row[2]), metaphone.metaphone(row[0]), "{:?}", row[0]);
+    }
+}
+
+#[test]
+fn postgres_double_metaphone() {
+    let double_metaphone = DoubleMetaphone::new().compatibility(MetaphoneCompatibility::Postgres);
+    for row in rows(POSTGRES_PHONETICS) {
+        let (primary, alternate) = double_metaphone
+            .double_metaphone(row[0])
+            .map_or((String::new(), String::new()), |result| (result.primary, result.alternate));
+        assert_eq!(expected("dmetaphone", row[0], row[3]), primary, "{:?}", row[0]);
+        assert_eq!(expected("dmetaphone_alt", row[0], row[4]), alternate, "{:?}", row[0]);
+    }
+}
+
+#[test]
+fn postgres_levenshtein() {
+    for row in rows(POSTGRES_LEVENSHTEIN) {
+        assert_eq!(row[2].parse::<usize>().unwrap(), levenshtein(row[0], row[1]), "{:?}", row);
+    }
+}
+
+#[test]
+fn commons_codec() {
+    for row in rows(COMMONS_CODEC) {
+        let code = match row[0] {
+            "soundex"   => Soundex::new().soundex(row[1]),
+            "metaphone" => Metaphone::with_max_length(4).metaphone(row[1]),
+            encoder     => panic!("unknown encoder {}", encoder),
+        };
+        assert_eq!(expected(row[0], row[1], row[2]), code, "{:?}", row);
+    }
+}
+
+#[test]
+fn commons_codec_and_postgres_disagree() {
+    let postgres = Metaphone::new().compatibility(MetaphoneCompatibility::Postgres);
+    assert_eq!(("WTN", "HTN"), (&Metaphone::new().metaphone("Whitney")[..], &postgres.metaphone("Whitney")[..]));
+    assert_eq!(("XNK", "SNK"), (&Metaphone::new().metaphone("Xiong")[..], &postgres.metaphone("Xiong")[..]));
+    assert_eq!("A261", Soundex::new().soundex("Ashcraft"));
+    assert_eq!("A226", Soundex::new().variant(SoundexVariant::Simplified).soundex("Ashcraft"));
+}
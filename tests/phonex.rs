@@ -0,0 +1,53 @@
+extern crate nlp;
+use nlp::phonetics::phonex::*;
+
+// Lait & Randell examples
+const TEST_VALUES: &[(&str, &str)] = &[
+    ("", "0000"),
+    ("Ewell", "A400"),
+    ("Ule", "A400"),
+    ("Yule", "A400"),
+    ("Filp", "F100"),
+    ("Philp", "F100"),
+    ("Heames", "A500"),
+    ("Eames", "A500"),
+    ("Kneves", "N100"),
+    ("Neves", "N100"),
+    ("River", "R160"),
+    ("Rivers", "R160"),
+    ("Corley", "C400"),
+    ("Coley", "C400"),
+    ("Carton", "C350"),
+    ("Carlton", "C350"),
+    ("Cachpole", "C214"),
+    ("Catchpole", "C214"),
+];
+
+#[test]
+fn phonex_on_dict() {
+    for &(word, code) in TEST_VALUES {
+        assert_eq!(code, phonex(word), "word: {}", word);
+    }
+}
+
+#[test]
+fn phonex_first_letters() {
+    assert_eq!(phonex("Bryant"), phonex("Pryant"));
+    assert_eq!(phonex("Qualls"), phonex("Kualls"));
+    assert_eq!(phonex("Zimmer"), phonex("Simmer"));
+    assert_eq!(phonex("Wright"), phonex("Right"));
+    assert_eq!(phonex("Jarvis"), phonex("Garvis"));
+}
+
+#[test]
+fn phonex_m_n_before_d_g() {
+    assert_eq!(phonex("Hand"), phonex("Han"));
+    assert_eq!(phonex("Lang"), phonex("Lan"));
+}
+
+#[test]
+fn phonex_max_length() {
+    assert_eq!("C2", Phonex::with_max_length(2).phonex("Catchpole"));
+    assert_eq!("C214", Phonex::with_max_length(0).phonex("Catchpole"));
+    assert_eq!("C21400", Phonex::with_max_length(6).phonex("Catchpole"));
+}
@@ -0,0 +1,51 @@
+extern crate nlp;
+use nlp::phonetics::phonix::*;
+
+const TEST_VALUES: &[(&str, &str)] = &[
+    ("", "0000"),
+    ("Müller", "M400"),
+    ("schneider", "S530"),
+    ("fischer", "F800"),
+    ("weber", "W100"),
+    ("meyer", "M000"),
+    ("wagner", "W250"),
+    ("schulz", "S480"),
+    ("becker", "B200"),
+    ("hoffmann", "H755"),
+    ("schäfer", "S700"),
+    ("schmidt", "S530"),
+    ("GAYLE", "G400"),
+    ("Snyder", "S530"),
+];
+
+#[test]
+fn phonix_on_dict() {
+    for &(word, code) in TEST_VALUES {
+        assert_eq!(code, phonix(word), "word: {}", word);
+    }
+}
+
+#[test]
+fn phonix_substitutions() {
+    // PH is F
+    assert_eq!(phonix("Philips"), phonix("Filips"));
+    // KN at the start is N
+    assert_eq!(phonix("Knight"), phonix("Night"));
+    // GHT is T
+    assert_eq!(phonix("Wright"), phonix("Rite"));
+    // final E is silent
+    assert_eq!(phonix("Browne"), phonix("Brown"));
+}
+
+#[test]
+fn phonix_vowel_start() {
+    assert_eq!("v475", phonix("Alphonse"));
+    assert_eq!(phonix("Ellis"), phonix("Allis"));
+}
+
+#[test]
+fn phonix_max_length() {
+    assert_eq!("H7", Phonix::with_max_length(2).phonix("hoffmann"));
+    assert_eq!("H755", Phonix::with_max_length(0).phonix("hoffmann"));
+    assert_eq!("v4758", Phonix::with_max_length(0).phonix("Alphonse"));
+}
@@ -0,0 +1,83 @@
+extern crate nlp;
+use nlp::lemma::Pos;
+use nlp::phonetics::metaphone::double_metaphone::double_metaphone;
+use nlp::pos::corpus::{read_conll, read_conllu, TaggedSentence};
+use nlp::pos::perceptron::PerceptronTagger;
+use nlp::tokenize::word::WordTokenizer;
+
+static ENGLISH_CORPUS: &str = include_str!("corpus/pos/english.conll");
+static OZ_CORPUS: &str = include_str!("corpus/pos/oz.conllu");
+
+/// Training and held-out sentences: the last tenth is held out, with names and nouns never seen
+/// in the training ones
+fn split() -> (Vec<TaggedSentence>, Vec<TaggedSentence>) {
+    let mut train = read_conll(ENGLISH_CORPUS.as_bytes(), 1).unwrap();
+    let held_out = train.split_off(train.len() * 9 / 10);
+    (train, held_out)
+}
+
+fn english() -> PerceptronTagger {
+    PerceptronTagger::train(&split().0, 5)
+}
+
+#[test]
+fn held_out_accuracy() {
+    let (train, held_out) = split();
+    assert_eq!(3000, train.len() + held_out.len());
+    let tagger = PerceptronTagger::train(&train, 5);
+
+    let train_accuracy = tagger.accuracy(&train);
+    let accuracy = tagger.accuracy(&held_out);
+    assert!(train_accuracy > 0.99, "training accuracy {:.4}", train_accuracy);
+    assert!(accuracy > 0.97, "held-out accuracy {:.4}", accuracy);
+}
+
+#[test]
+fn real_text_accuracy() {
+    let oz = read_conllu(OZ_CORPUS.as_bytes()).unwrap();
+    assert_eq!(22, oz.len());
+
+    let accuracy = english().accuracy(&oz);
+    assert!(accuracy > 0.65, "real text accuracy {:.4}", accuracy);
+}
+
+#[test]
+fn save_load() {
+    let tagger = english();
+    let mut model = Vec::new();
+    tagger.save(&mut model).unwrap();
+    let loaded = PerceptronTagger::load(&model[..]).unwrap();
+    assert_eq!(tagger, loaded);
+
+    let held_out = split().1;
+    assert_eq!(tagger.accuracy(&held_out), loaded.accuracy(&held_out));
+}
+
+#[test]
+fn ambiguous_words() {
+    let tagger = english();
+    let tokenizer = WordTokenizer::new().split_contractions(true);
+    let sentences = [("They will book a room.", vec!["PRP", "MD", "VB", "DT", "NN", "."]),
+                     ("The book was old.", vec!["DT", "NN", "VBD", "JJ", "."]),
+                     ("They saw Will Smith in May.", vec!["PRP", "VBD", "NNP", "NNP", "IN", "NNP", "."]),
+                     ("Will they finish the report?", vec!["MD", "PRP", "VB", "DT", "NN", "."])];
+    for &(text, ref tags) in &sentences {
+        assert_eq!(*tags, tagger.tag(&tokenizer.tokenize(text)), "tags of {:?}", text);
+    }
+
+    let tags = tagger.tag(&tokenizer.tokenize("Her friends visited Paris."));
+    let pos = tags.iter().map(|tag| Pos::from_tag(tag)).collect::<Vec<_>>();
+    assert_eq!(vec![None, Some(Pos::Noun), Some(Pos::Verb), Some(Pos::Noun), None], pos);
+}
+
+#[test]
+fn proper_nouns_metaphone() {
+    let tagger = english();
+    let tokens = WordTokenizer::new().split_contractions(true)
+                                     .tokenize("Kevin Okafor met the doctor near the old harbor in Nairobi.");
+    let names = tagger.proper_nouns(&tokens).into_iter().map(|token| token.text).collect::<Vec<_>>();
+    assert_eq!(vec!["Kevin", "Okafor", "Nairobi"], names);
+
+    let codes = names.iter().map(|name| double_metaphone(name).unwrap().primary).collect::<Vec<_>>();
+    assert_eq!(vec!["KFN", "AKFR", "NRP"], codes);
+}
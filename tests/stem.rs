@@ -0,0 +1,112 @@
+extern crate nlp;
+use nlp::stem::stemmer::Stemmer;
+use nlp::stem::english::English;
+use nlp::stem::porter::Porter;
+use nlp::stem::french::French;
+use nlp::stem::german::German;
+use nlp::stem::spanish::Spanish;
+use nlp::stem::italian::Italian;
+use nlp::stem::portuguese::Portuguese;
+use nlp::stem::dutch::Dutch;
+use nlp::stem::swedish::Swedish;
+use nlp::stem::norwegian::Norwegian;
+use nlp::stem::danish::Danish;
+use nlp::stem::russian::Russian;
+use nlp::stem::finnish::Finnish;
+
+static ENGLISH_VOCABULARY: &str = include_str!("corpus/snowball/english/voc.txt");
+static ENGLISH_OUTPUT: &str = include_str!("corpus/snowball/english/output.txt");
+
+fn check<S: Stemmer>(stemmer: S, vocabulary: &str, output: &str) {
+    let mut count = 0;
+    for (word, expected) in vocabulary.lines().zip(output.lines()) {
+        assert_eq!(expected, stemmer.stem(word), "stem of {:?}", word);
+        count += 1;
+    }
+    assert_eq!(vocabulary.lines().count(), count);
+    assert_eq!(output.lines().count(), count);
+}
+
+#[test]
+fn english_vocabulary() {
+    check(English, ENGLISH_VOCABULARY, ENGLISH_OUTPUT);
+}
+
+#[test]
+fn porter_vocabulary() {
+    check(Porter, include_str!("corpus/snowball/porter/voc.txt"), include_str!("corpus/snowball/porter/output.txt"));
+}
+
+#[test]
+fn french_vocabulary() {
+    check(French, include_str!("corpus/snowball/french/voc.txt"), include_str!("corpus/snowball/french/output.txt"));
+}
+
+#[test]
+fn german_vocabulary() {
+    check(German, include_str!("corpus/snowball/german/voc.txt"), include_str!("corpus/snowball/german/output.txt"));
+}
+
+#[test]
+fn spanish_vocabulary() {
+    check(Spanish, include_str!("corpus/snowball/spanish/voc.txt"), include_str!("corpus/snowball/spanish/output.txt"));
+}
+
+#[test]
+fn italian_vocabulary() {
+    check(Italian, include_str!("corpus/snowball/italian/voc.txt"), include_str!("corpus/snowball/italian/output.txt"));
+}
+
+#[test]
+fn portuguese_vocabulary() {
+    check(Portuguese, include_str!("corpus/snowball/portuguese/voc.txt"), include_str!("corpus/snowball/portuguese/output.txt"));
+}
+
+#[test]
+fn dutch_vocabulary() {
+    check(Dutch, include_str!("corpus/snowball/dutch/voc.txt"), include_str!("corpus/snowball/dutch/output.txt"));
+}
+
+#[test]
+fn swedish_vocabulary() {
+    check(Swedish, include_str!("corpus/snowball/swedish/voc.txt"), include_str!("corpus/snowball/swedish/output.txt"));
+}
+
+#[test]
+fn norwegian_vocabulary() {
+    check(Norwegian, include_str!("corpus/snowball/norwegian/voc.txt"), include_str!("corpus/snowball/norwegian/output.txt"));
+}
+
+#[test]
+fn danish_vocabulary() {
+    check(Danish, include_str!("corpus/snowball/danish/voc.txt"), include_str!("corpus/snowball/danish/output.txt"));
+}
+
+#[test]
+fn russian_vocabulary() {
+    check(Russian, include_str!("corpus/snowball/russian/voc.txt"), include_str!("corpus/snowball/russian/output.txt"));
+}
+
+#[test]
+fn finnish_vocabulary() {
+    check(Finnish, include_str!("corpus/snowball/finnish/voc.txt"), include_str!("corpus/snowball/finnish/output.txt"));
+}
+
+#[test]
+fn stemmers_lowercase() {
+    assert_eq!("run", English.stem("RUNNING"));
+    assert_eq!("connect", Porter.stem("Connections"));
+    assert_eq!("", English.stem(""));
+    assert_eq!("", Porter.stem(""));
+
+    let stemmers: Vec<Box<dyn Stemmer>> = vec![
+        Box::new(French), Box::new(German), Box::new(Spanish), Box::new(Italian), Box::new(Portuguese), Box::new(Dutch),
+        Box::new(Swedish), Box::new(Norwegian), Box::new(Danish), Box::new(Russian), Box::new(Finnish),
+    ];
+    for stemmer in &stemmers {
+        assert_eq!("", stemmer.stem(""));
+        assert_eq!(stemmer.stem("ÉCOLES"), stemmer.stem("écoles"));
+    }
+    assert_eq!("книг", Russian.stem("КНИГАМИ"));
+    assert_eq!("haus", German.stem("HÄUSER"));
+}